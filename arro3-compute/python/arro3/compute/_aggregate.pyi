@@ -1,6 +1,11 @@
-from arro3.core import Scalar
+from typing import Sequence, overload
+
+from arro3.core import Array, Scalar
 from arro3.core.types import ArrayInput, ArrowStreamExportable
 
+from .enums import CountMode, QuantileMethod
+from .types import CountModeT, QuantileMethodT
+
 def max(input: ArrayInput | ArrowStreamExportable) -> Scalar:
     """
     Returns the max of values in the array.
@@ -11,7 +16,139 @@ def min(input: ArrayInput | ArrowStreamExportable) -> Scalar:
     Returns the min of values in the array.
     """
 
+def arg_min(input: ArrayInput | ArrowStreamExportable) -> Scalar:
+    """
+    Returns the index of the first minimum value in the array, as a `UInt64`.
+
+    Nulls are ignored, and the result is null if there are no valid values. For
+    `ChunkedArray` and stream input, the index is global across all chunks.
+    """
+
+def arg_max(input: ArrayInput | ArrowStreamExportable) -> Scalar:
+    """
+    Returns the index of the first maximum value in the array, as a `UInt64`.
+
+    Nulls are ignored, and the result is null if there are no valid values. For
+    `ChunkedArray` and stream input, the index is global across all chunks.
+    """
+
 def sum(input: ArrayInput | ArrowStreamExportable) -> Scalar:
     """
     Returns the sum of values in the array.
     """
+
+def mean(input: ArrayInput | ArrowStreamExportable) -> Scalar:
+    """
+    Returns the arithmetic mean of valid values in the array.
+
+    Decimal input returns a decimal with the same scale and maximum precision, computed
+    exactly and rounded half away from zero. All other numeric input returns a
+    `Float64`. For `ChunkedArray` and stream input, partial state is merged across
+    chunks without materializing the input.
+    """
+
+def variance(input: ArrayInput | ArrowStreamExportable, *, ddof: int = 0) -> Scalar:
+    """
+    Returns the variance of valid values in the array, as a `Float64`.
+
+    This uses Welford's algorithm within each chunk, with a numerically stable merge of
+    partial state across chunks.
+
+    Args:
+        input: Numeric input data.
+        ddof: Delta degrees of freedom. The divisor is `N - ddof`, where `N` is the
+            number of valid values. If `N <= ddof`, the result is null.
+    """
+
+def stddev(input: ArrayInput | ArrowStreamExportable, *, ddof: int = 0) -> Scalar:
+    """
+    Returns the standard deviation of valid values in the array, as a `Float64`.
+
+    Args:
+        input: Numeric input data.
+        ddof: Delta degrees of freedom. The divisor is `N - ddof`, where `N` is the
+            number of valid values. If `N <= ddof`, the result is null.
+    """
+
+def product(input: ArrayInput | ArrowStreamExportable) -> Scalar:
+    """
+    Returns the product of valid values in the array.
+
+    Signed integers return an `Int64`, unsigned integers a `UInt64` and floats a
+    `Float64`. Integer overflow raises an error. If there are no valid values, the
+    result is null.
+    """
+
+def count(
+    input: ArrayInput | ArrowStreamExportable,
+    *,
+    mode: CountMode | CountModeT = "valid",
+) -> Scalar:
+    """
+    Returns the number of values in the array, as an `Int64`.
+
+    Args:
+        input: Input data.
+        mode: Whether to count all values, only valid values or only null values.
+    """
+
+@overload
+def quantile(
+    input: ArrayInput | ArrowStreamExportable,
+    q: float = 0.5,
+    *,
+    method: QuantileMethod | QuantileMethodT = "exact",
+    delta: int = 100,
+) -> Scalar: ...
+@overload
+def quantile(
+    input: ArrayInput | ArrowStreamExportable,
+    q: Sequence[float],
+    *,
+    method: QuantileMethod | QuantileMethodT = "exact",
+    delta: int = 100,
+) -> Array: ...
+def quantile(
+    input: ArrayInput | ArrowStreamExportable,
+    q: float | Sequence[float] = 0.5,
+    *,
+    method: QuantileMethod | QuantileMethodT = "exact",
+    delta: int = 100,
+) -> Scalar | Array:
+    """
+    Returns one or more quantiles of valid values in the array, as `Float64`.
+
+    With `method="exact"`, all valid values are collected and each quantile is linearly
+    interpolated between the two nearest ranks. With `method="tdigest"`, each chunk of
+    a `ChunkedArray` or stream is summarized into a t-digest and the digests are
+    merged, so memory use is bounded by `delta` regardless of the input size. NaN
+    values are ignored by both methods.
+
+    Args:
+        input: Numeric input data.
+        q: A quantile or sequence of quantiles, each between 0 and 1.
+        method: Whether to compute exact or approximate quantiles.
+        delta: The compression parameter of the t-digest. Larger values are more
+            accurate but use more memory. Ignored for `method="exact"`.
+
+    Returns:
+        A `Float64` scalar if `q` is a single number, otherwise a `Float64` array with
+        one value per quantile. Quantiles of input with no valid values are null.
+    """
+
+def median(
+    input: ArrayInput | ArrowStreamExportable,
+    *,
+    method: QuantileMethod | QuantileMethodT = "exact",
+    delta: int = 100,
+) -> Scalar:
+    """
+    Returns the median of valid values in the array, as a `Float64`.
+
+    This is equivalent to [`quantile`][arro3.compute.quantile] with `q=0.5`.
+
+    Args:
+        input: Numeric input data.
+        method: Whether to compute the exact or approximate median.
+        delta: The compression parameter of the t-digest.
+    """
@@ -0,0 +1,35 @@
+from arro3.core import Array
+from arro3.core.types import ArrayInput
+
+def bit_count(array: ArrayInput) -> Array:
+    """Count the set bits of each element of an integer array, returning `uint32`."""
+
+def bitwise_and(lhs: ArrayInput, rhs: ArrayInput) -> Array:
+    """
+    Perform `lhs & rhs` on integers, casting `rhs` to the type of `lhs`.
+
+    An error is raised if a value of `rhs` does not fit in the type of `lhs`.
+    """
+
+def bitwise_not(array: ArrayInput) -> Array:
+    """Perform `~array` on integers."""
+
+def bitwise_or(lhs: ArrayInput, rhs: ArrayInput) -> Array:
+    """
+    Perform `lhs | rhs` on integers, casting `rhs` to the type of `lhs`.
+
+    An error is raised if a value of `rhs` does not fit in the type of `lhs`.
+    """
+
+def bitwise_xor(lhs: ArrayInput, rhs: ArrayInput) -> Array:
+    """
+    Perform `lhs ^ rhs` on integers, casting `rhs` to the type of `lhs`.
+
+    An error is raised if a value of `rhs` does not fit in the type of `lhs`.
+    """
+
+def shift_left(lhs: ArrayInput, rhs: ArrayInput) -> Array:
+    """Perform `lhs << rhs` on integers, wrapping the shift amount at the bit width."""
+
+def shift_right(lhs: ArrayInput, rhs: ArrayInput) -> Array:
+    """Perform `lhs >> rhs` on integers, wrapping the shift amount at the bit width."""
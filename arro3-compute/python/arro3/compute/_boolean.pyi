@@ -40,3 +40,183 @@ def is_not_null(
     Returns:
         Output
     """
+
+@overload
+def and_(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def and_(lhs: ArrowStreamExportable, rhs: ArrowStreamExportable) -> ArrayReader: ...
+def and_(
+    lhs: ArrayInput | ArrowStreamExportable,
+    rhs: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Performs `AND` on two boolean arrays.
+
+    If either side is null, the result is null.
+
+    If `lhs` and `rhs` are Arrays, an `Array` will be returned. If they are `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and both inputs are combined chunk-by-chunk.
+
+    Args:
+        lhs: Left-hand boolean input
+        rhs: Right-hand boolean input
+
+    Returns:
+        Output
+    """
+
+@overload
+def and_kleene(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def and_kleene(lhs: ArrowStreamExportable, rhs: ArrowStreamExportable) -> ArrayReader: ...
+def and_kleene(
+    lhs: ArrayInput | ArrowStreamExportable,
+    rhs: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Performs `AND` on two boolean arrays using Kleene (SQL) logic.
+
+    A null is treated as "unknown": `false AND null` is `false`, while `true AND null` is null.
+
+    If `lhs` and `rhs` are Arrays, an `Array` will be returned. If they are `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and both inputs are combined chunk-by-chunk.
+
+    Args:
+        lhs: Left-hand boolean input
+        rhs: Right-hand boolean input
+
+    Returns:
+        Output
+    """
+
+@overload
+def and_not(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def and_not(lhs: ArrowStreamExportable, rhs: ArrowStreamExportable) -> ArrayReader: ...
+def and_not(
+    lhs: ArrayInput | ArrowStreamExportable,
+    rhs: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Performs `AND NOT` (`lhs AND (NOT rhs)`) on two boolean arrays.
+
+    If either side is null, the result is null.
+
+    If `lhs` and `rhs` are Arrays, an `Array` will be returned. If they are `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and both inputs are combined chunk-by-chunk.
+
+    Args:
+        lhs: Left-hand boolean input
+        rhs: Right-hand boolean input
+
+    Returns:
+        Output
+    """
+
+@overload
+def and_not_kleene(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def and_not_kleene(lhs: ArrowStreamExportable, rhs: ArrowStreamExportable) -> ArrayReader: ...
+def and_not_kleene(
+    lhs: ArrayInput | ArrowStreamExportable,
+    rhs: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Performs `AND NOT` (`lhs AND (NOT rhs)`) on two boolean arrays using Kleene (SQL) logic.
+
+    A null is treated as "unknown": `false AND NOT null` and `null AND NOT true` are `false`.
+
+    If `lhs` and `rhs` are Arrays, an `Array` will be returned. If they are `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and both inputs are combined chunk-by-chunk.
+
+    Args:
+        lhs: Left-hand boolean input
+        rhs: Right-hand boolean input
+
+    Returns:
+        Output
+    """
+
+@overload
+def or_(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def or_(lhs: ArrowStreamExportable, rhs: ArrowStreamExportable) -> ArrayReader: ...
+def or_(
+    lhs: ArrayInput | ArrowStreamExportable,
+    rhs: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Performs `OR` on two boolean arrays.
+
+    If either side is null, the result is null.
+
+    If `lhs` and `rhs` are Arrays, an `Array` will be returned. If they are `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and both inputs are combined chunk-by-chunk.
+
+    Args:
+        lhs: Left-hand boolean input
+        rhs: Right-hand boolean input
+
+    Returns:
+        Output
+    """
+
+@overload
+def or_kleene(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def or_kleene(lhs: ArrowStreamExportable, rhs: ArrowStreamExportable) -> ArrayReader: ...
+def or_kleene(
+    lhs: ArrayInput | ArrowStreamExportable,
+    rhs: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Performs `OR` on two boolean arrays using Kleene (SQL) logic.
+
+    A null is treated as "unknown": `true OR null` is `true`, while `false OR null` is null.
+
+    If `lhs` and `rhs` are Arrays, an `Array` will be returned. If they are `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and both inputs are combined chunk-by-chunk.
+
+    Args:
+        lhs: Left-hand boolean input
+        rhs: Right-hand boolean input
+
+    Returns:
+        Output
+    """
+
+@overload
+def xor(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def xor(lhs: ArrowStreamExportable, rhs: ArrowStreamExportable) -> ArrayReader: ...
+def xor(
+    lhs: ArrayInput | ArrowStreamExportable,
+    rhs: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Performs `XOR` on two boolean arrays.
+
+    If either side is null, the result is null. Under Kleene logic, `XOR` with an unknown value is always unknown, so there is no separate Kleene variant.
+
+    If `lhs` and `rhs` are Arrays, an `Array` will be returned. If they are `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and both inputs are combined chunk-by-chunk.
+
+    Args:
+        lhs: Left-hand boolean input
+        rhs: Right-hand boolean input
+
+    Returns:
+        Output
+    """
+
+@overload
+def invert(input: ArrayInput) -> Array: ...
+@overload
+def invert(input: ArrowStreamExportable) -> ArrayReader: ...
+def invert(
+    input: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Performs `NOT` on a boolean array. Null values remain null.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Boolean input
+
+    Returns:
+        Output
+    """
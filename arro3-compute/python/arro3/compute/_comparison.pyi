@@ -0,0 +1,103 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+@overload
+def eq(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def eq(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def eq(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> Array | ArrayReader:
+    """Perform `lhs == rhs`, returning a boolean-typed array.
+
+    Supports numeric, temporal, string, binary, boolean and dictionary-encoded inputs.
+    Both sides must have the same data type. A null on either side produces a null.
+
+    A length-1 Array is treated as a scalar and broadcast against the other side.
+
+    If both `lhs` and `rhs` are Arrays, an `Array` will be returned. If either is a
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, where a
+    scalar or Array is compared against each chunk. If both are streams, they are
+    compared chunk-by-chunk and must have the same chunk lengths.
+
+    Args:
+        lhs: Left-hand side of the comparison.
+        rhs: Right-hand side of the comparison.
+
+    Returns:
+        A boolean-typed array.
+    """
+
+@overload
+def neq(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def neq(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def neq(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> Array | ArrayReader:
+    """Perform `lhs != rhs`, returning a boolean-typed array.
+
+    See [`eq`][arro3.compute.eq] for supported input types and broadcasting rules.
+    """
+
+@overload
+def lt(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def lt(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def lt(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> Array | ArrayReader:
+    """Perform `lhs < rhs`, returning a boolean-typed array.
+
+    See [`eq`][arro3.compute.eq] for supported input types and broadcasting rules.
+    """
+
+@overload
+def lt_eq(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def lt_eq(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def lt_eq(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> Array | ArrayReader:
+    """Perform `lhs <= rhs`, returning a boolean-typed array.
+
+    See [`eq`][arro3.compute.eq] for supported input types and broadcasting rules.
+    """
+
+@overload
+def gt(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def gt(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def gt(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> Array | ArrayReader:
+    """Perform `lhs > rhs`, returning a boolean-typed array.
+
+    See [`eq`][arro3.compute.eq] for supported input types and broadcasting rules.
+    """
+
+@overload
+def gt_eq(lhs: ArrayInput, rhs: ArrayInput) -> Array: ...
+@overload
+def gt_eq(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def gt_eq(
+    lhs: ArrayInput | ArrowStreamExportable, rhs: ArrayInput | ArrowStreamExportable
+) -> Array | ArrayReader:
+    """Perform `lhs >= rhs`, returning a boolean-typed array.
+
+    See [`eq`][arro3.compute.eq] for supported input types and broadcasting rules.
+    """
@@ -1,6 +1,15 @@
+from arro3.compute._aggregate import arg_max as arg_max
+from arro3.compute._aggregate import arg_min as arg_min
+from arro3.compute._aggregate import count as count
 from arro3.compute._aggregate import max as max
+from arro3.compute._aggregate import mean as mean
+from arro3.compute._aggregate import median as median
 from arro3.compute._aggregate import min as min
+from arro3.compute._aggregate import product as product
+from arro3.compute._aggregate import quantile as quantile
+from arro3.compute._aggregate import stddev as stddev
 from arro3.compute._aggregate import sum as sum
+from arro3.compute._aggregate import variance as variance
 from arro3.compute._arith import add as add
 from arro3.compute._arith import add_wrapping as add_wrapping
 from arro3.compute._arith import div as div
@@ -11,11 +20,131 @@ from arro3.compute._arith import neg_wrapping as neg_wrapping
 from arro3.compute._arith import rem as rem
 from arro3.compute._arith import sub as sub
 from arro3.compute._arith import sub_wrapping as sub_wrapping
+from arro3.compute._bitwise import bit_count as bit_count
+from arro3.compute._bitwise import bitwise_and as bitwise_and
+from arro3.compute._bitwise import bitwise_not as bitwise_not
+from arro3.compute._bitwise import bitwise_or as bitwise_or
+from arro3.compute._bitwise import bitwise_xor as bitwise_xor
+from arro3.compute._bitwise import shift_left as shift_left
+from arro3.compute._bitwise import shift_right as shift_right
+from arro3.compute._boolean import and_ as and_
+from arro3.compute._boolean import and_kleene as and_kleene
+from arro3.compute._boolean import and_not as and_not
+from arro3.compute._boolean import and_not_kleene as and_not_kleene
+from arro3.compute._boolean import invert as invert
 from arro3.compute._boolean import is_not_null as is_not_null
 from arro3.compute._boolean import is_null as is_null
+from arro3.compute._boolean import or_ as or_
+from arro3.compute._boolean import or_kleene as or_kleene
+from arro3.compute._boolean import xor as xor
 from arro3.compute._cast import can_cast_types as can_cast_types
 from arro3.compute._cast import cast as cast
+from arro3.compute._comparison import eq as eq
+from arro3.compute._comparison import gt as gt
+from arro3.compute._comparison import gt_eq as gt_eq
+from arro3.compute._comparison import lt as lt
+from arro3.compute._comparison import lt_eq as lt_eq
+from arro3.compute._comparison import neq as neq
+from arro3.compute._conditional import case_when as case_when
+from arro3.compute._conditional import choose as choose
+from arro3.compute._conditional import if_else as if_else
+from arro3.compute._cumulative import cumulative_max as cumulative_max
+from arro3.compute._cumulative import cumulative_min as cumulative_min
+from arro3.compute._cumulative import cumulative_prod as cumulative_prod
+from arro3.compute._cumulative import (
+    cumulative_prod_wrapping as cumulative_prod_wrapping,
+)
+from arro3.compute._cumulative import cumulative_sum as cumulative_sum
+from arro3.compute._cumulative import (
+    cumulative_sum_wrapping as cumulative_sum_wrapping,
+)
 from arro3.compute._dictionary import dictionary_encode as dictionary_encode
+from arro3.compute._distinct import count_distinct as count_distinct
+from arro3.compute._distinct import unique as unique
+from arro3.compute._distinct import value_counts as value_counts
 from arro3.compute._filter import filter as filter
+from arro3.compute._group_by import group_by as group_by
+from arro3.compute._join import join as join
+from arro3.compute._math import abs as abs
+from arro3.compute._math import acos as acos
+from arro3.compute._math import asin as asin
+from arro3.compute._math import atan as atan
+from arro3.compute._math import ceil as ceil
+from arro3.compute._math import cos as cos
+from arro3.compute._math import exp as exp
+from arro3.compute._math import floor as floor
+from arro3.compute._math import ln as ln
+from arro3.compute._math import log10 as log10
+from arro3.compute._math import log2 as log2
+from arro3.compute._math import pow as pow
+from arro3.compute._math import round as round
+from arro3.compute._math import sign as sign
+from arro3.compute._math import sin as sin
+from arro3.compute._math import sqrt as sqrt
+from arro3.compute._math import tan as tan
+from arro3.compute._math import trunc as trunc
+from arro3.compute._null import coalesce as coalesce
+from arro3.compute._null import drop_null as drop_null
+from arro3.compute._null import fill_null as fill_null
+from arro3.compute._null import fill_null_backward as fill_null_backward
+from arro3.compute._null import fill_null_forward as fill_null_forward
+from arro3.compute._pattern import contains as contains
+from arro3.compute._pattern import ends_with as ends_with
+from arro3.compute._pattern import ilike as ilike
+from arro3.compute._pattern import like as like
+from arro3.compute._pattern import regex_match as regex_match
+from arro3.compute._pattern import starts_with as starts_with
+from arro3.compute._rank import dense_rank as dense_rank
+from arro3.compute._rank import percent_rank as percent_rank
+from arro3.compute._rank import rank as rank
+from arro3.compute._rank import row_number as row_number
+from arro3.compute._regexp import regex_extract as regex_extract
+from arro3.compute._regexp import regex_extract_all as regex_extract_all
+from arro3.compute._regexp import regex_extract_groups as regex_extract_groups
+from arro3.compute._regexp import regex_replace as regex_replace
+from arro3.compute._rolling import rolling_max as rolling_max
+from arro3.compute._rolling import rolling_mean as rolling_mean
+from arro3.compute._rolling import rolling_min as rolling_min
+from arro3.compute._rolling import rolling_sum as rolling_sum
+from arro3.compute._set_lookup import index_in as index_in
+from arro3.compute._set_lookup import is_in as is_in
+from arro3.compute._shift import diff as diff
+from arro3.compute._shift import pct_change as pct_change
+from arro3.compute._shift import shift as shift
+from arro3.compute._sort import sort as sort
+from arro3.compute._sort import sort_indices as sort_indices
+from arro3.compute._strings import concat_ws as concat_ws
+from arro3.compute._strings import lower as lower
+from arro3.compute._strings import lstrip as lstrip
+from arro3.compute._strings import pad as pad
+from arro3.compute._strings import reverse as reverse
+from arro3.compute._strings import rstrip as rstrip
+from arro3.compute._strings import strip as strip
+from arro3.compute._strings import substring as substring
+from arro3.compute._strings import upper as upper
+from arro3.compute._strings import utf8_length as utf8_length
 from arro3.compute._take import take as take
+from arro3.compute._temporal import add_interval as add_interval
+from arro3.compute._temporal import assume_timezone as assume_timezone
+from arro3.compute._temporal import ceil_temporal as ceil_temporal
+from arro3.compute._temporal import convert_timezone as convert_timezone
 from arro3.compute._temporal import date_part as date_part
+from arro3.compute._temporal import date_trunc as date_trunc
+from arro3.compute._temporal import days_between as days_between
+from arro3.compute._temporal import floor_temporal as floor_temporal
+from arro3.compute._temporal import hours_between as hours_between
+from arro3.compute._temporal import local_timestamp as local_timestamp
+from arro3.compute._temporal import microseconds_between as microseconds_between
+from arro3.compute._temporal import milliseconds_between as milliseconds_between
+from arro3.compute._temporal import minutes_between as minutes_between
+from arro3.compute._temporal import months_between as months_between
+from arro3.compute._temporal import nanoseconds_between as nanoseconds_between
+from arro3.compute._temporal import quarters_between as quarters_between
+from arro3.compute._temporal import round_temporal as round_temporal
+from arro3.compute._temporal import seconds_between as seconds_between
+from arro3.compute._temporal import strftime as strftime
+from arro3.compute._temporal import strptime as strptime
+from arro3.compute._temporal import weeks_between as weeks_between
+from arro3.compute._temporal import years_between as years_between
+from arro3.compute._top_k import bottom_k as bottom_k
+from arro3.compute._top_k import top_k as top_k
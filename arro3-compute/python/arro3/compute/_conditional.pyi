@@ -0,0 +1,98 @@
+from typing import Sequence, overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+@overload
+def if_else(cond: ArrayInput, left: ArrayInput, right: ArrayInput) -> Array: ...
+@overload
+def if_else(
+    cond: ArrayInput | ArrowStreamExportable,
+    left: ArrayInput | ArrowStreamExportable,
+    right: ArrayInput | ArrowStreamExportable,
+) -> ArrayReader: ...
+def if_else(
+    cond: ArrayInput | ArrowStreamExportable,
+    left: ArrayInput | ArrowStreamExportable,
+    right: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Select from `left` where `cond` is true and from `right` where it is false.
+
+    `left` and `right` are cast to the narrowest type that holds both (for example
+    int64 for int8 and int64), and an error is raised if there is no such type.
+    Scalars and length-1 Arrays are broadcast. A null condition produces a null.
+    Nested types are supported.
+
+    If every input is an Array, an `Array` will be returned. If any input is a
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and streams
+    are re-sliced to common chunk boundaries.
+
+    Args:
+        cond: A boolean condition.
+        left: Values to select where `cond` is true.
+        right: Values to select where `cond` is false.
+
+    Returns:
+        The selected values.
+    """
+
+@overload
+def case_when(
+    conditions: Sequence[ArrayInput],
+    values: Sequence[ArrayInput],
+    default: ArrayInput | None = None,
+) -> Array: ...
+@overload
+def case_when(
+    conditions: Sequence[ArrayInput | ArrowStreamExportable],
+    values: Sequence[ArrayInput | ArrowStreamExportable],
+    default: ArrayInput | ArrowStreamExportable | None = None,
+) -> ArrayReader: ...
+def case_when(
+    conditions: Sequence[ArrayInput | ArrowStreamExportable],
+    values: Sequence[ArrayInput | ArrowStreamExportable],
+    default: ArrayInput | ArrowStreamExportable | None = None,
+) -> Array | ArrayReader:
+    """
+    For each row, select the value paired with the first true condition.
+
+    Null conditions are treated as false. Rows where no condition is true take
+    `default`, or null if no default is provided. Values are cast to the narrowest
+    type that holds all of them, raising if there is none, and scalars are
+    broadcast.
+
+    Args:
+        conditions: Boolean conditions, evaluated in order.
+        values: One value for each condition.
+        default: The value for rows where no condition is true.
+
+    Returns:
+        An `Array` if every input is an Array, otherwise an `ArrayReader`.
+    """
+
+@overload
+def choose(indices: ArrayInput, *values: ArrayInput) -> Array: ...
+@overload
+def choose(
+    indices: ArrayInput | ArrowStreamExportable,
+    *values: ArrayInput | ArrowStreamExportable,
+) -> ArrayReader: ...
+def choose(
+    indices: ArrayInput | ArrowStreamExportable,
+    *values: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    For each row `i`, select the value of `values[indices[i]]` at that row.
+
+    A null index produces a null, and an out-of-range index raises an error. Values
+    are cast to the narrowest type that holds all of them, raising if there is none,
+    and scalars are broadcast.
+
+    Args:
+        indices: Integer indices into `values`.
+        values: The values to choose from.
+
+    Returns:
+        An `Array` if every input is an Array, otherwise an `ArrayReader`.
+    """
@@ -0,0 +1,123 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+@overload
+def cumulative_sum(input: ArrayInput, *, skip_nulls: bool = False) -> Array: ...
+@overload
+def cumulative_sum(
+    input: ArrowStreamExportable, *, skip_nulls: bool = False
+) -> ArrayReader: ...
+def cumulative_sum(
+    input: ArrayInput | ArrowStreamExportable, *, skip_nulls: bool = False
+) -> Array | ArrayReader:
+    """
+    Compute the running sum of the input, returning an error on overflow.
+
+    The running value is carried across chunks of a `ChunkedArray` or `ArrayReader`,
+    so the output is the same as for the concatenated input. The output has the same
+    type as the input, and decimal sums that exceed its precision raise an error.
+
+    Args:
+        input: Numeric or decimal input data.
+        skip_nulls: If `False`, the first null is propagated to every following value.
+            If `True`, nulls produce a null output but do not reset the running value.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def cumulative_sum_wrapping(
+    input: ArrayInput, *, skip_nulls: bool = False
+) -> Array: ...
+@overload
+def cumulative_sum_wrapping(
+    input: ArrowStreamExportable, *, skip_nulls: bool = False
+) -> ArrayReader: ...
+def cumulative_sum_wrapping(
+    input: ArrayInput | ArrowStreamExportable, *, skip_nulls: bool = False
+) -> Array | ArrayReader:
+    """
+    Compute the running sum of the input, wrapping on overflow for integer data types.
+
+    See [`cumulative_sum`][arro3.compute.cumulative_sum] for the meaning of each
+    parameter.
+    """
+
+@overload
+def cumulative_prod(input: ArrayInput, *, skip_nulls: bool = False) -> Array: ...
+@overload
+def cumulative_prod(
+    input: ArrowStreamExportable, *, skip_nulls: bool = False
+) -> ArrayReader: ...
+def cumulative_prod(
+    input: ArrayInput | ArrowStreamExportable, *, skip_nulls: bool = False
+) -> Array | ArrayReader:
+    """
+    Compute the running product of the input, returning an error on overflow.
+
+    Only integer and floating-point input is supported.
+
+    See [`cumulative_sum`][arro3.compute.cumulative_sum] for the meaning of each
+    parameter.
+    """
+
+@overload
+def cumulative_prod_wrapping(
+    input: ArrayInput, *, skip_nulls: bool = False
+) -> Array: ...
+@overload
+def cumulative_prod_wrapping(
+    input: ArrowStreamExportable, *, skip_nulls: bool = False
+) -> ArrayReader: ...
+def cumulative_prod_wrapping(
+    input: ArrayInput | ArrowStreamExportable, *, skip_nulls: bool = False
+) -> Array | ArrayReader:
+    """
+    Compute the running product of the input, wrapping on overflow for integer data
+    types.
+
+    Only integer and floating-point input is supported.
+
+    See [`cumulative_sum`][arro3.compute.cumulative_sum] for the meaning of each
+    parameter.
+    """
+
+@overload
+def cumulative_min(input: ArrayInput, *, skip_nulls: bool = False) -> Array: ...
+@overload
+def cumulative_min(
+    input: ArrowStreamExportable, *, skip_nulls: bool = False
+) -> ArrayReader: ...
+def cumulative_min(
+    input: ArrayInput | ArrowStreamExportable, *, skip_nulls: bool = False
+) -> Array | ArrayReader:
+    """
+    Compute the running minimum of the input.
+
+    Numeric, decimal, date, time, timestamp and duration input is supported.
+
+    See [`cumulative_sum`][arro3.compute.cumulative_sum] for the meaning of each
+    parameter.
+    """
+
+@overload
+def cumulative_max(input: ArrayInput, *, skip_nulls: bool = False) -> Array: ...
+@overload
+def cumulative_max(
+    input: ArrowStreamExportable, *, skip_nulls: bool = False
+) -> ArrayReader: ...
+def cumulative_max(
+    input: ArrayInput | ArrowStreamExportable, *, skip_nulls: bool = False
+) -> Array | ArrayReader:
+    """
+    Compute the running maximum of the input.
+
+    Numeric, decimal, date, time, timestamp and duration input is supported.
+
+    See [`cumulative_sum`][arro3.compute.cumulative_sum] for the meaning of each
+    parameter.
+    """
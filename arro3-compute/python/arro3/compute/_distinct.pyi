@@ -0,0 +1,48 @@
+from arro3.core import Array, Scalar
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+def unique(input: ArrayInput | ArrowStreamExportable) -> Array:
+    """
+    Return the distinct values of the input.
+
+    Values are returned in order of first appearance, and null is included once if
+    present. For `ChunkedArray` and stream input, values are distinct across all
+    chunks.
+
+    Dictionary-encoded input is counted by dictionary key, so each dictionary value is
+    only hashed once per chunk. The output is dictionary-encoded with the same type as
+    the input.
+
+    Args:
+        input: Input data.
+
+    Returns:
+        An array of distinct values, with the same type as the input.
+    """
+
+def value_counts(input: ArrayInput | ArrowStreamExportable) -> Array:
+    """
+    Count the occurrences of each distinct value of the input.
+
+    Values are returned in order of first appearance, and nulls are counted as a
+    single value. For `ChunkedArray` and stream input, counts are accumulated across
+    all chunks.
+
+    Args:
+        input: Input data.
+
+    Returns:
+        A `Struct<values, counts>` array, where `values` has the same type as the input
+        and `counts` is `Int64`.
+    """
+
+def count_distinct(input: ArrayInput | ArrowStreamExportable) -> Scalar:
+    """
+    Count the number of distinct non-null values of the input.
+
+    Args:
+        input: Input data.
+
+    Returns:
+        An `Int64` scalar.
+    """
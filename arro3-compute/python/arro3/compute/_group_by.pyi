@@ -0,0 +1,55 @@
+from typing import Mapping, Sequence
+
+from arro3.core import Table
+from arro3.core.types import ArrowArrayExportable, ArrowStreamExportable
+
+from .enums import Aggregation
+from .types import AggregationT
+
+def group_by(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    keys: Sequence[str | int],
+    aggs: Mapping[
+        str | int,
+        Aggregation | AggregationT | Sequence[Aggregation | AggregationT],
+    ]
+    | None = None,
+) -> Table:
+    """
+    Group tabular data by one or more key columns and aggregate within each group.
+
+    Key columns are hashed by value, so any type can be used as a key, including
+    strings and dictionary-encoded columns. Null keys form their own group.
+
+    A `RecordBatchReader` is consumed one batch at a time, without materializing the
+    whole stream: only the distinct keys and the running state of each aggregation are
+    held in memory.
+
+    The output columns of aggregations are named `{column}_{aggregation}`. The types of
+    the output columns are:
+
+    - `count` and `count_all`: `Int64`.
+    - `sum`: `Int64` for signed integer input, `UInt64` for unsigned integer input,
+      `Decimal128(38, scale)` for decimal input and `Float64` otherwise. Integer
+      overflow raises an error.
+    - `mean`: `Float64`.
+    - `min` and `max`: the type of the input column.
+
+    Examples:
+
+    ```py
+    import arro3.compute as ac
+
+    ac.group_by(table, ["city"], {"sales": ["sum", "mean"], "id": "count"})
+    ```
+
+    Args:
+        input: A `RecordBatch`, `Table` or `RecordBatchReader`.
+        keys: The names or indices of the columns to group by.
+        aggs: A mapping from a column name or index to one or more aggregations to
+            compute for that column. If `None`, only the distinct keys are returned.
+
+    Returns:
+        A Table with the key columns followed by one column per aggregation, with one
+        row per group in order of first appearance.
+    """
@@ -0,0 +1,77 @@
+from typing import Literal, Sequence, Tuple, overload
+
+from arro3.core import RecordBatchReader, Table
+from arro3.core.types import ArrowArrayExportable, ArrowStreamExportable
+
+from .enums import JoinType
+from .types import JoinTypeT
+
+@overload
+def join(
+    left: ArrowArrayExportable | ArrowStreamExportable,
+    right: ArrowArrayExportable | ArrowStreamExportable,
+    on: str | int | Sequence[str | int],
+    how: JoinType | JoinTypeT = "inner",
+    *,
+    right_on: str | int | Sequence[str | int] | None = None,
+    suffixes: Tuple[str, str] = ("", "_right"),
+    lazy: Literal[False] = False,
+) -> Table: ...
+@overload
+def join(
+    left: ArrowArrayExportable | ArrowStreamExportable,
+    right: ArrowArrayExportable | ArrowStreamExportable,
+    on: str | int | Sequence[str | int],
+    how: JoinType | JoinTypeT = "inner",
+    *,
+    right_on: str | int | Sequence[str | int] | None = None,
+    suffixes: Tuple[str, str] = ("", "_right"),
+    lazy: Literal[True],
+) -> RecordBatchReader: ...
+def join(
+    left: ArrowArrayExportable | ArrowStreamExportable,
+    right: ArrowArrayExportable | ArrowStreamExportable,
+    on: str | int | Sequence[str | int],
+    how: JoinType | JoinTypeT = "inner",
+    *,
+    right_on: str | int | Sequence[str | int] | None = None,
+    suffixes: Tuple[str, str] = ("", "_right"),
+    lazy: bool = False,
+) -> Table | RecordBatchReader:
+    """
+    Join two tabular inputs on one or more key columns.
+
+    A hash table is built from the `right` input, which is fully materialized. The
+    `left` input is then streamed one batch at a time and probed against the hash
+    table, so `left` may be a large `RecordBatchReader`.
+
+    Null keys never match, following SQL semantics. Each pair of left and right key
+    columns is compared in a common type that holds the values of both, e.g. `int64` for
+    `int8` and `int64` keys, so `1.5` never matches `1`. Keys of incompatible types,
+    such as strings and integers, raise an error.
+
+    The output contains every left column followed by every right column that is not a
+    key column. Left key columns hold the key values of all output rows, including
+    right rows without a match in `right` and `full` joins, so for these joins they
+    have the common key type. Other column names present on both sides are
+    disambiguated with `suffixes`. `semi` and `anti` joins only output the left
+    columns.
+
+    Output rows are ordered by the left input. For `right` and `full` joins, right rows
+    without a match are emitted at the end.
+
+    Args:
+        left: The left `RecordBatch`, `Table` or `RecordBatchReader`.
+        right: The right `RecordBatch`, `Table` or `RecordBatchReader`.
+        on: The name or index of the key column(s) in `left`.
+        how: The type of join to perform.
+        right_on: The name or index of the key column(s) in `right`. If `None`, the
+            names of the left key columns are used.
+        suffixes: Suffixes to append to the names of non-key columns that exist in both
+            inputs, as `(left_suffix, right_suffix)`.
+        lazy: If `True`, return a `RecordBatchReader` that probes each left batch as it
+            is consumed, instead of a materialized `Table`.
+
+    Returns:
+        The joined data.
+    """
@@ -0,0 +1,373 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+from .enums import RoundMode
+from .types import RoundModeT
+
+@overload
+def abs(input: ArrayInput) -> Array: ...
+@overload
+def abs(input: ArrowStreamExportable) -> ArrayReader: ...
+def abs(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the absolute value of each value, returning an error on overflow.
+
+    The output has the same type as the input, including decimal precision and scale.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def acos(input: ArrayInput) -> Array: ...
+@overload
+def acos(input: ArrowStreamExportable) -> ArrayReader: ...
+def acos(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the arccosine of each value, in radians.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+    Values outside `[-1, 1]` return NaN.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def asin(input: ArrayInput) -> Array: ...
+@overload
+def asin(input: ArrowStreamExportable) -> ArrayReader: ...
+def asin(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the arcsine of each value, in radians.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+    Values outside `[-1, 1]` return NaN.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def atan(input: ArrayInput) -> Array: ...
+@overload
+def atan(input: ArrowStreamExportable) -> ArrayReader: ...
+def atan(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the arctangent of each value, in radians.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def ceil(input: ArrayInput) -> Array: ...
+@overload
+def ceil(input: ArrowStreamExportable) -> ArrayReader: ...
+def ceil(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Round each value up to the nearest integer.
+
+    Integer input is returned unchanged, and decimal input keeps its precision and
+    scale.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def cos(input: ArrayInput) -> Array: ...
+@overload
+def cos(input: ArrowStreamExportable) -> ArrayReader: ...
+def cos(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the cosine of each value, given in radians.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def exp(input: ArrayInput) -> Array: ...
+@overload
+def exp(input: ArrowStreamExportable) -> ArrayReader: ...
+def exp(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute Euler's number raised to the power of each value.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def floor(input: ArrayInput) -> Array: ...
+@overload
+def floor(input: ArrowStreamExportable) -> ArrayReader: ...
+def floor(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Round each value down to the nearest integer.
+
+    Integer input is returned unchanged, and decimal input keeps its precision and
+    scale.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def ln(input: ArrayInput) -> Array: ...
+@overload
+def ln(input: ArrowStreamExportable) -> ArrayReader: ...
+def ln(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the natural logarithm of each value.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+    Negative values return NaN, and zero returns negative infinity.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def log10(input: ArrayInput) -> Array: ...
+@overload
+def log10(input: ArrowStreamExportable) -> ArrayReader: ...
+def log10(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the base-10 logarithm of each value.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+    Negative values return NaN, and zero returns negative infinity.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def log2(input: ArrayInput) -> Array: ...
+@overload
+def log2(input: ArrowStreamExportable) -> ArrayReader: ...
+def log2(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the base-2 logarithm of each value.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+    Negative values return NaN, and zero returns negative infinity.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def pow(base: ArrayInput, exponent: ArrayInput) -> Array: ...
+@overload
+def pow(
+    base: ArrayInput | ArrowStreamExportable,
+    exponent: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def pow(
+    base: ArrayInput | ArrowStreamExportable,
+    exponent: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Raise `base` to the power of `exponent`, element-wise.
+
+    If both inputs are integers, the output is `int64` and an error is returned on
+    overflow, for a negative exponent, or for a `uint64` input that does not fit in
+    `int64`. Otherwise both inputs are cast to `float64`.
+
+    Length-1 arrays and scalars are broadcast against the other input. If either input
+    is a stream, the output is a stream, where two streams are zipped chunk-by-chunk.
+
+    Args:
+        base: Numeric or decimal input data.
+        exponent: Numeric or decimal input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def round(
+    input: ArrayInput,
+    ndigits: int = 0,
+    *,
+    mode: RoundMode | RoundModeT = "half_to_even",
+) -> Array: ...
+@overload
+def round(
+    input: ArrowStreamExportable,
+    ndigits: int = 0,
+    *,
+    mode: RoundMode | RoundModeT = "half_to_even",
+) -> ArrayReader: ...
+def round(
+    input: ArrayInput | ArrowStreamExportable,
+    ndigits: int = 0,
+    *,
+    mode: RoundMode | RoundModeT = "half_to_even",
+) -> Array | ArrayReader:
+    """
+    Round each value to `ndigits` decimal places.
+
+    The output has the same type as the input. Decimal input keeps its precision and
+    scale, and an error is returned if a rounded value no longer fits in the precision.
+    Integer input is only changed by a negative `ndigits`.
+
+    Args:
+        input: Numeric or decimal input data.
+        ndigits: The number of decimal places to round to. A negative value rounds to
+            a power of ten, e.g. `-2` rounds to the nearest hundred.
+        mode: How to round values, including how to break ties between the two
+            nearest candidates.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def sign(input: ArrayInput) -> Array: ...
+@overload
+def sign(input: ArrowStreamExportable) -> ArrayReader: ...
+def sign(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the sign of each value: -1, 0 or 1.
+
+    Integer and decimal input returns `int8`. Float input keeps its type, and NaN
+    stays NaN.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def sin(input: ArrayInput) -> Array: ...
+@overload
+def sin(input: ArrowStreamExportable) -> ArrayReader: ...
+def sin(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the sine of each value, given in radians.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def sqrt(input: ArrayInput) -> Array: ...
+@overload
+def sqrt(input: ArrowStreamExportable) -> ArrayReader: ...
+def sqrt(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the square root of each value.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+    Negative values return NaN.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def tan(input: ArrayInput) -> Array: ...
+@overload
+def tan(input: ArrowStreamExportable) -> ArrayReader: ...
+def tan(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Compute the tangent of each value, given in radians.
+
+    Float input keeps its type, and integer and decimal input returns `float64`.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def trunc(input: ArrayInput) -> Array: ...
+@overload
+def trunc(input: ArrowStreamExportable) -> ArrayReader: ...
+def trunc(input: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Round each value towards zero to the nearest integer.
+
+    Integer input is returned unchanged, and decimal input keeps its precision and
+    scale.
+
+    Args:
+        input: Numeric or decimal input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
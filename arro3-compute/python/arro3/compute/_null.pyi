@@ -0,0 +1,125 @@
+from typing import Sequence, overload
+
+from arro3.core import Array, ArrayReader, RecordBatch, Table
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+from .enums import DropNullHow
+from .types import DropNullHowT
+
+@overload
+def fill_null(input: ArrayInput, value: ArrayInput) -> Array: ...
+@overload
+def fill_null(
+    input: ArrayInput | ArrowStreamExportable,
+    value: ArrayInput | ArrowStreamExportable,
+) -> ArrayReader: ...
+def fill_null(
+    input: ArrayInput | ArrowStreamExportable,
+    value: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Replace nulls of the input with `value`.
+
+    `value` is cast to the type of `input`, and an error is raised if a value does not
+    fit, e.g. `1000` for `int8` input or `1.5` for integer input. A scalar or length-1
+    Array is broadcast; otherwise `value` must have the same length as `input`, and two
+    streams are combined chunk-by-chunk.
+
+    Args:
+        input: Input data with nulls to replace.
+        value: The replacement value or values.
+
+    Returns:
+        An `Array` if both inputs are Arrays, otherwise an `ArrayReader`.
+    """
+
+@overload
+def fill_null_forward(input: ArrayInput) -> Array: ...
+@overload
+def fill_null_forward(input: ArrowStreamExportable) -> ArrayReader: ...
+def fill_null_forward(
+    input: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Replace each null of the input with the last preceding valid value.
+
+    The last valid value is carried across chunks of a `ChunkedArray` or
+    `ArrayReader`, so the output is the same as for the concatenated input. Leading
+    nulls stay null.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def fill_null_backward(input: ArrayInput) -> Array: ...
+@overload
+def fill_null_backward(input: ArrowStreamExportable) -> ArrayReader: ...
+def fill_null_backward(
+    input: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Replace each null of the input with the next following valid value.
+
+    For `ChunkedArray` and stream input, a chunk that ends in nulls is held back
+    until a later chunk provides a valid value, so the output is the same as for the
+    concatenated input. Trailing nulls stay null.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def coalesce(*values: ArrayInput) -> Array: ...
+@overload
+def coalesce(*values: ArrayInput | ArrowStreamExportable) -> ArrayReader: ...
+def coalesce(*values: ArrayInput | ArrowStreamExportable) -> Array | ArrayReader:
+    """
+    Return the first non-null value among the inputs at each position.
+
+    Every input is cast to the narrowest type that holds all of them (for example
+    `int64` for `int8` and `int64`), and an error is raised if there is no such type.
+    Scalars and length-1 Arrays are broadcast. If any input is a `ChunkedArray` or
+    `ArrayReader`, the streams are re-sliced to common chunk boundaries.
+
+    Args:
+        values: One or more inputs.
+
+    Returns:
+        An `Array` if every input is an Array, otherwise an `ArrayReader`.
+    """
+
+@overload
+def drop_null(input: ArrayInput) -> Array | RecordBatch: ...
+@overload
+def drop_null(
+    input: ArrowStreamExportable,
+    columns: Sequence[str | int] | None = None,
+    *,
+    how: DropNullHow | DropNullHowT = "any",
+) -> ArrayReader | Table: ...
+def drop_null(
+    input: ArrayInput | ArrowStreamExportable,
+    columns: Sequence[str | int] | None = None,
+    *,
+    how: DropNullHow | DropNullHowT = "any",
+) -> Array | ArrayReader | RecordBatch | Table:
+    """
+    Drop nulls of an array, or rows with nulls of tabular input.
+
+    If `input` is a `RecordBatch`, `Table` or `RecordBatchReader` (or any other
+    struct-typed input), rows are dropped based on the nulls in `columns`.
+
+    Args:
+        input: Input data.
+        columns: Columns to check for nulls, by name or index, for tabular input. If
+            `None`, all columns are checked.
+        how: Whether to drop rows with a null in any or in all of the selected columns.
+
+    Returns:
+        A `RecordBatch` for a `RecordBatch`, a `Table` for a `Table` or
+        `RecordBatchReader`, an `Array` for an `Array`, or an `ArrayReader` for a
+        `ChunkedArray` or `ArrayReader`.
+    """
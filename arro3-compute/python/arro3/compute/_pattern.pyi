@@ -0,0 +1,205 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+@overload
+def starts_with(input: ArrayInput, pattern: str | ArrayInput) -> Array: ...
+@overload
+def starts_with(
+    input: ArrowStreamExportable, pattern: str | ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def starts_with(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str | ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Return whether each value starts with `pattern`.
+
+    Supports Utf8, LargeUtf8, Utf8View, Binary, LargeBinary and BinaryView input, as
+    well as dictionary-encoded strings.
+
+    `pattern` may be a Python `str` or a Scalar, which is matched against every row,
+    or an Array or stream with one pattern per row. A null on either side produces a
+    null.
+
+    If `input` is an Array, an `Array` will be returned. If `input` or `pattern` is a
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input data.
+        pattern: The pattern to match.
+
+    Returns:
+        A boolean-typed array.
+    """
+
+@overload
+def ends_with(input: ArrayInput, pattern: str | ArrayInput) -> Array: ...
+@overload
+def ends_with(
+    input: ArrowStreamExportable, pattern: str | ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def ends_with(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str | ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Return whether each value ends with `pattern`.
+
+    Supports Utf8, LargeUtf8, Utf8View, Binary, LargeBinary and BinaryView input, as
+    well as dictionary-encoded strings.
+
+    `pattern` may be a Python `str` or a Scalar, which is matched against every row,
+    or an Array or stream with one pattern per row. A null on either side produces a
+    null.
+
+    If `input` is an Array, an `Array` will be returned. If `input` or `pattern` is a
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input data.
+        pattern: The pattern to match.
+
+    Returns:
+        A boolean-typed array.
+    """
+
+@overload
+def contains(input: ArrayInput, pattern: str | ArrayInput) -> Array: ...
+@overload
+def contains(
+    input: ArrowStreamExportable, pattern: str | ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def contains(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str | ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Return whether each value contains `pattern` as a substring.
+
+    Supports Utf8, LargeUtf8, Utf8View, Binary, LargeBinary and BinaryView input, as
+    well as dictionary-encoded strings.
+
+    `pattern` may be a Python `str` or a Scalar, which is matched against every row,
+    or an Array or stream with one pattern per row. A null on either side produces a
+    null.
+
+    If `input` is an Array, an `Array` will be returned. If `input` or `pattern` is a
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input data.
+        pattern: The pattern to match.
+
+    Returns:
+        A boolean-typed array.
+    """
+
+@overload
+def like(input: ArrayInput, pattern: str | ArrayInput) -> Array: ...
+@overload
+def like(
+    input: ArrowStreamExportable, pattern: str | ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def like(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str | ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Return whether each string matches the SQL `LIKE` pattern `pattern`.
+
+    In `pattern`, `%` matches any sequence of characters and `_` matches any single
+    character. Use `\\` to escape either.
+
+    Supports Utf8, LargeUtf8 and Utf8View input, as well as dictionary-encoded strings.
+
+    `pattern` may be a Python `str` or a Scalar, which is matched against every row,
+    or an Array or stream with one pattern per row. A null on either side produces a
+    null.
+
+    If `input` is an Array, an `Array` will be returned. If `input` or `pattern` is a
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input data.
+        pattern: The pattern to match.
+
+    Returns:
+        A boolean-typed array.
+    """
+
+@overload
+def ilike(input: ArrayInput, pattern: str | ArrayInput) -> Array: ...
+@overload
+def ilike(
+    input: ArrowStreamExportable, pattern: str | ArrayInput | ArrowStreamExportable
+) -> ArrayReader: ...
+def ilike(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str | ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Return whether each string matches the SQL `ILIKE` (case-insensitive `LIKE`)
+    pattern `pattern`.
+
+    See [`like`][arro3.compute.like] for pattern syntax.
+
+    Supports Utf8, LargeUtf8 and Utf8View input, as well as dictionary-encoded strings.
+
+    `pattern` may be a Python `str` or a Scalar, which is matched against every row,
+    or an Array or stream with one pattern per row. A null on either side produces a
+    null.
+
+    If `input` is an Array, an `Array` will be returned. If `input` or `pattern` is a
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input data.
+        pattern: The pattern to match.
+
+    Returns:
+        A boolean-typed array.
+    """
+
+@overload
+def regex_match(
+    input: ArrayInput, pattern: str | ArrayInput, *, ignore_case: bool = False
+) -> Array: ...
+@overload
+def regex_match(
+    input: ArrowStreamExportable,
+    pattern: str | ArrayInput | ArrowStreamExportable,
+    *,
+    ignore_case: bool = False,
+) -> ArrayReader: ...
+def regex_match(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str | ArrayInput | ArrowStreamExportable,
+    *,
+    ignore_case: bool = False,
+) -> Array | ArrayReader:
+    """
+    Return whether each string contains a match of the regular expression `pattern`.
+
+    Uses the syntax of the Rust [`regex`](https://docs.rs/regex) crate. A single
+    pattern is compiled once per call, and each distinct per-row pattern is compiled
+    once per chunk.
+
+    Supports Utf8, LargeUtf8 and Utf8View input, as well as dictionary-encoded strings.
+
+    `pattern` may be a Python `str` or a Scalar, which is matched against every row,
+    or an Array or stream with one pattern per row. A null on either side produces a
+    null.
+
+    If `input` is an Array, an `Array` will be returned. If `input` or `pattern` is a
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input data.
+        pattern: The pattern to match.
+        ignore_case: Whether to match case-insensitively.
+
+    Returns:
+        A boolean-typed array.
+    """
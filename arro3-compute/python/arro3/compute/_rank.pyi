@@ -0,0 +1,86 @@
+from typing import Sequence
+
+from arro3.core import Array
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+from .enums import NullPlacement, SortOrder, Tiebreaker
+from .types import NullPlacementT, SortKeyT, SortOrderT, TiebreakerT
+
+def rank(
+    input: ArrayInput | ArrowStreamExportable,
+    sort_keys: Sequence[SortKeyT] | None = None,
+    *,
+    order: SortOrder | SortOrderT = "ascending",
+    null_placement: NullPlacement | NullPlacementT = "at_end",
+    tiebreaker: Tiebreaker | TiebreakerT = "min",
+) -> Array:
+    """
+    Compute the 1-based rank of each row of the input.
+
+    If `input` is a `RecordBatch`, `Table` or `RecordBatchReader` (or any other
+    struct-typed input), rows are ranked by the columns selected in `sort_keys`.
+    Otherwise they are ranked by their own values and `sort_keys` must be `None`.
+
+    For `ChunkedArray`, `Table` and stream input, ranks are global across all chunks.
+    Nulls are ranked according to `null_placement`, and are tied with each other.
+
+    Args:
+        input: Input data to rank.
+        sort_keys: Columns to rank by, for tabular input. See
+            [`sort_indices`][arro3.compute.sort_indices].
+        order: The default sort order.
+        null_placement: The default placement of nulls.
+        tiebreaker: How to rank tied rows.
+
+    Returns:
+        A `UInt64` array with one rank per input row.
+    """
+
+def dense_rank(
+    input: ArrayInput | ArrowStreamExportable,
+    sort_keys: Sequence[SortKeyT] | None = None,
+    *,
+    order: SortOrder | SortOrderT = "ascending",
+    null_placement: NullPlacement | NullPlacementT = "at_end",
+) -> Array:
+    """
+    Compute the dense rank of each row of the input.
+
+    This is equivalent to [`rank`][arro3.compute.rank] with `tiebreaker="dense"`.
+
+    Returns:
+        A `UInt64` array with one rank per input row.
+    """
+
+def row_number(
+    input: ArrayInput | ArrowStreamExportable,
+    sort_keys: Sequence[SortKeyT] | None = None,
+    *,
+    order: SortOrder | SortOrderT = "ascending",
+    null_placement: NullPlacement | NullPlacementT = "at_end",
+) -> Array:
+    """
+    Compute the 1-based position of each row of the input in sorted order.
+
+    This is equivalent to [`rank`][arro3.compute.rank] with `tiebreaker="first"`.
+
+    Returns:
+        A `UInt64` array with one row number per input row.
+    """
+
+def percent_rank(
+    input: ArrayInput | ArrowStreamExportable,
+    sort_keys: Sequence[SortKeyT] | None = None,
+    *,
+    order: SortOrder | SortOrderT = "ascending",
+    null_placement: NullPlacement | NullPlacementT = "at_end",
+) -> Array:
+    """
+    Compute the relative rank of each row of the input, between 0 and 1.
+
+    This is `(rank - 1) / (N - 1)`, where `rank` uses the `min` tiebreaker and `N` is
+    the number of rows.
+
+    Returns:
+        A `Float64` array with one value per input row.
+    """
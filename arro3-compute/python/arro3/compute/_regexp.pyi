@@ -0,0 +1,143 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+@overload
+def regex_extract(input: ArrayInput, pattern: str, group: int | str = 0) -> Array: ...
+@overload
+def regex_extract(
+    input: ArrowStreamExportable, pattern: str, group: int | str = 0
+) -> ArrayReader: ...
+def regex_extract(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str,
+    group: int | str = 0,
+) -> Array | ArrayReader:
+    """
+    Extract a capture group from the first match of a regular expression in each string.
+
+    Strings without a match, or where the group did not participate in the match,
+    become null. Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded
+    strings are transformed without decoding, by applying the function to the
+    dictionary values.
+
+    The pattern is compiled once per call. If `input` is an Array, an `Array` will be
+    returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be
+    returned.
+
+    Args:
+        input: Input string data.
+        pattern: A regular expression, using the syntax of the Rust
+            [`regex`](https://docs.rs/regex) crate.
+        group: The index or name of the capture group to extract. Group `0` is the
+            entire match.
+
+    Returns:
+        The extracted strings, with the same type as the input.
+    """
+
+@overload
+def regex_extract_all(
+    input: ArrayInput, pattern: str, group: int | str = 0
+) -> Array: ...
+@overload
+def regex_extract_all(
+    input: ArrowStreamExportable, pattern: str, group: int | str = 0
+) -> ArrayReader: ...
+def regex_extract_all(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str,
+    group: int | str = 0,
+) -> Array | ArrayReader:
+    """
+    Extract a capture group from every non-overlapping match of a regular expression in
+    each string.
+
+    Null strings produce a null list; strings without a match produce an empty list.
+
+    The pattern is compiled once per call. If `input` is an Array, an `Array` will be
+    returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be
+    returned.
+
+    Args:
+        input: Input string data.
+        pattern: A regular expression.
+        group: The index or name of the capture group to extract. Group `0` is the
+            entire match.
+
+    Returns:
+        A `List<Utf8>`-typed array.
+    """
+
+@overload
+def regex_extract_groups(input: ArrayInput, pattern: str) -> Array: ...
+@overload
+def regex_extract_groups(input: ArrowStreamExportable, pattern: str) -> ArrayReader: ...
+def regex_extract_groups(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str,
+) -> Array | ArrayReader:
+    """
+    Extract all named capture groups from the first match of a regular expression in
+    each string.
+
+    For example, the pattern `(?P<key>\\w+)=(?P<value>\\w+)` produces a struct with
+    `key` and `value` children. Strings without a match produce a null struct.
+
+    The pattern is compiled once per call. If `input` is an Array, an `Array` will be
+    returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be
+    returned.
+
+    Args:
+        input: Input string data.
+        pattern: A regular expression with at least one named capture group.
+
+    Returns:
+        A Struct-typed array with one Utf8 child per named group.
+    """
+
+@overload
+def regex_replace(
+    input: ArrayInput,
+    pattern: str,
+    replacement: str,
+    *,
+    max_replacements: int | None = None,
+) -> Array: ...
+@overload
+def regex_replace(
+    input: ArrowStreamExportable,
+    pattern: str,
+    replacement: str,
+    *,
+    max_replacements: int | None = None,
+) -> ArrayReader: ...
+def regex_replace(
+    input: ArrayInput | ArrowStreamExportable,
+    pattern: str,
+    replacement: str,
+    *,
+    max_replacements: int | None = None,
+) -> Array | ArrayReader:
+    """
+    Replace matches of a regular expression in each string.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are
+    transformed without decoding, by applying the function to the dictionary values.
+
+    The pattern is compiled once per call. If `input` is an Array, an `Array` will be
+    returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be
+    returned.
+
+    Args:
+        input: Input string data.
+        pattern: A regular expression.
+        replacement: The replacement string. Capture groups may be referenced with
+            `$1` or `$name`.
+        max_replacements: The maximum number of matches to replace in each string. If
+            `None`, all matches are replaced.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
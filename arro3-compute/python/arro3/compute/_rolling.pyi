@@ -0,0 +1,146 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+@overload
+def rolling_sum(
+    input: ArrayInput,
+    window: int | str,
+    *,
+    by: ArrayInput | None = None,
+    min_periods: int | None = None,
+) -> Array: ...
+@overload
+def rolling_sum(
+    input: ArrowStreamExportable,
+    window: int | str,
+    *,
+    by: ArrowStreamExportable | None = None,
+    min_periods: int | None = None,
+) -> ArrayReader: ...
+def rolling_sum(
+    input: ArrayInput | ArrowStreamExportable,
+    window: int | str,
+    *,
+    by: ArrayInput | ArrowStreamExportable | None = None,
+    min_periods: int | None = None,
+) -> Array | ArrayReader:
+    """
+    Compute the sum of each rolling window of the input, as `Float64`.
+
+    Each output value aggregates the valid values of the window ending at the same row
+    of the input, so the output is aligned 1:1 with the input. The window is either a
+    fixed number of rows, or a duration such as `"5m"` over the Timestamp column `by`,
+    in which case a row is in the window of a later row at time `t` if its timestamp is
+    greater than `t - window`.
+
+    For `ChunkedArray` and stream input, the tail of each chunk is carried forward, so
+    windows span chunk boundaries. When `by` is provided, `input` and `by` must both be
+    arrays or both be streams with chunks of the same lengths.
+
+    Args:
+        input: Numeric input data.
+        window: The number of rows in each window, or a duration string made of an
+            integer and one of the units `ns`, `us`, `ms`, `s`, `m`, `h`, `d` or `w`.
+        by: The Timestamp values of each row, sorted in ascending order. Required for
+            a duration window.
+        min_periods: The minimum number of valid values in a window for the output to
+            be non-null. Defaults to the window size for a row-count window, and 1 for
+            a duration window.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def rolling_mean(
+    input: ArrayInput,
+    window: int | str,
+    *,
+    by: ArrayInput | None = None,
+    min_periods: int | None = None,
+) -> Array: ...
+@overload
+def rolling_mean(
+    input: ArrowStreamExportable,
+    window: int | str,
+    *,
+    by: ArrowStreamExportable | None = None,
+    min_periods: int | None = None,
+) -> ArrayReader: ...
+def rolling_mean(
+    input: ArrayInput | ArrowStreamExportable,
+    window: int | str,
+    *,
+    by: ArrayInput | ArrowStreamExportable | None = None,
+    min_periods: int | None = None,
+) -> Array | ArrayReader:
+    """
+    Compute the mean of each rolling window of the input, as `Float64`.
+
+    See [`rolling_sum`][arro3.compute.rolling_sum] for the meaning of each parameter.
+    """
+
+@overload
+def rolling_min(
+    input: ArrayInput,
+    window: int | str,
+    *,
+    by: ArrayInput | None = None,
+    min_periods: int | None = None,
+) -> Array: ...
+@overload
+def rolling_min(
+    input: ArrowStreamExportable,
+    window: int | str,
+    *,
+    by: ArrowStreamExportable | None = None,
+    min_periods: int | None = None,
+) -> ArrayReader: ...
+def rolling_min(
+    input: ArrayInput | ArrowStreamExportable,
+    window: int | str,
+    *,
+    by: ArrayInput | ArrowStreamExportable | None = None,
+    min_periods: int | None = None,
+) -> Array | ArrayReader:
+    """
+    Compute the minimum of each rolling window of the input, as `Float64`.
+
+    A window containing NaN has a NaN result, as with `rolling_sum`.
+
+    See [`rolling_sum`][arro3.compute.rolling_sum] for the meaning of each parameter.
+    """
+
+@overload
+def rolling_max(
+    input: ArrayInput,
+    window: int | str,
+    *,
+    by: ArrayInput | None = None,
+    min_periods: int | None = None,
+) -> Array: ...
+@overload
+def rolling_max(
+    input: ArrowStreamExportable,
+    window: int | str,
+    *,
+    by: ArrowStreamExportable | None = None,
+    min_periods: int | None = None,
+) -> ArrayReader: ...
+def rolling_max(
+    input: ArrayInput | ArrowStreamExportable,
+    window: int | str,
+    *,
+    by: ArrayInput | ArrowStreamExportable | None = None,
+    min_periods: int | None = None,
+) -> Array | ArrayReader:
+    """
+    Compute the maximum of each rolling window of the input, as `Float64`.
+
+    A window containing NaN has a NaN result, as with `rolling_sum`.
+
+    See [`rolling_sum`][arro3.compute.rolling_sum] for the meaning of each parameter.
+    """
@@ -0,0 +1,83 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+@overload
+def is_in(
+    values: ArrayInput,
+    value_set: ArrayInput | ArrowStreamExportable,
+    *,
+    skip_nulls: bool = False,
+) -> Array: ...
+@overload
+def is_in(
+    values: ArrowStreamExportable,
+    value_set: ArrayInput | ArrowStreamExportable,
+    *,
+    skip_nulls: bool = False,
+) -> ArrayReader: ...
+def is_in(
+    values: ArrayInput | ArrowStreamExportable,
+    value_set: ArrayInput | ArrowStreamExportable,
+    *,
+    skip_nulls: bool = False,
+) -> Array | ArrayReader:
+    """
+    Return whether each value is contained in `value_set`.
+
+    A hash table is built once from `value_set` and applied to every chunk of
+    `values`. Any hashable type is supported, including strings, binary, nested
+    types and dictionary-encoded input. Values and `value_set` are compared in the
+    narrowest type that holds both (for example float64 for int64 and float64), and
+    an error is raised if there is no such type.
+
+    Args:
+        values: Values to look up.
+        value_set: The set of values to look for. A `ChunkedArray` or stream is
+            concatenated.
+        skip_nulls: If `False`, a null value matches a null in `value_set`. If `True`,
+            nulls never match.
+
+    Returns:
+        A non-null boolean `Array` for array input, or an `ArrayReader` for
+        `ChunkedArray` and stream input.
+    """
+
+@overload
+def index_in(
+    values: ArrayInput,
+    value_set: ArrayInput | ArrowStreamExportable,
+    *,
+    skip_nulls: bool = False,
+) -> Array: ...
+@overload
+def index_in(
+    values: ArrowStreamExportable,
+    value_set: ArrayInput | ArrowStreamExportable,
+    *,
+    skip_nulls: bool = False,
+) -> ArrayReader: ...
+def index_in(
+    values: ArrayInput | ArrowStreamExportable,
+    value_set: ArrayInput | ArrowStreamExportable,
+    *,
+    skip_nulls: bool = False,
+) -> Array | ArrayReader:
+    """
+    Return the position of each value in `value_set`.
+
+    The position of the first occurrence is returned if a value occurs more than once
+    in `value_set`, and null if it does not occur. See
+    [`is_in`][arro3.compute.is_in] for the handling of types and nulls.
+
+    Args:
+        values: Values to look up.
+        value_set: The set of values to look for.
+        skip_nulls: If `False`, a null value matches a null in `value_set`. If `True`,
+            nulls never match.
+
+    Returns:
+        An `Int32` `Array` for array input, or an `ArrayReader` for `ChunkedArray` and
+        stream input.
+    """
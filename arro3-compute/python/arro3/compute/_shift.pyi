@@ -0,0 +1,86 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader, Scalar
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+@overload
+def diff(input: ArrayInput, periods: int = 1) -> Array: ...
+@overload
+def diff(input: ArrowStreamExportable, periods: int = 1) -> ArrayReader: ...
+def diff(
+    input: ArrayInput | ArrowStreamExportable, periods: int = 1
+) -> Array | ArrayReader:
+    """
+    Compute the difference between each value and the value `periods` rows earlier.
+
+    Values are carried across chunks of a `ChunkedArray` or `ArrayReader`, so the
+    output is the same as for the concatenated input. The first `periods` rows are
+    null. The output type is that of subtracting two input values, e.g. a duration for
+    timestamp input. An error is returned on overflow.
+
+    Args:
+        input: Numeric, decimal or temporal input data.
+        periods: The number of rows to look back. A negative value looks ahead
+            instead, so that the last `-periods` rows are null.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def pct_change(input: ArrayInput, periods: int = 1) -> Array: ...
+@overload
+def pct_change(input: ArrowStreamExportable, periods: int = 1) -> ArrayReader: ...
+def pct_change(
+    input: ArrayInput | ArrowStreamExportable, periods: int = 1
+) -> Array | ArrayReader:
+    """
+    Compute the relative change between each value and the value `periods` rows
+    earlier, as `float64`.
+
+    This is `(x - previous) / previous`, where values are carried across chunks as in
+    [`diff`][arro3.compute.diff]. A previous value of zero gives an infinite or NaN
+    output.
+
+    Args:
+        input: Numeric or decimal input data.
+        periods: The number of rows to look back. A negative value looks ahead
+            instead.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def shift(
+    input: ArrayInput, periods: int = 1, fill_value: Scalar | None = None
+) -> Array: ...
+@overload
+def shift(
+    input: ArrowStreamExportable, periods: int = 1, fill_value: Scalar | None = None
+) -> ArrayReader: ...
+def shift(
+    input: ArrayInput | ArrowStreamExportable,
+    periods: int = 1,
+    fill_value: Scalar | None = None,
+) -> Array | ArrayReader:
+    """
+    Shift the values of the input by `periods` rows.
+
+    A positive `periods` shifts values towards the end (lag), and a negative `periods`
+    shifts values towards the start (lead). Values are carried across chunks of a
+    `ChunkedArray` or `ArrayReader`, so the output is the same as for the concatenated
+    input, with one output chunk per input chunk.
+
+    Args:
+        input: Input data.
+        periods: The number of rows to shift by.
+        fill_value: A scalar for the vacated rows, cast to the type of the input. If
+            `None`, vacated rows are null.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
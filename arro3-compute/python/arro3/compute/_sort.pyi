@@ -0,0 +1,64 @@
+from typing import Sequence
+
+from arro3.core import Array, Table
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+from .enums import NullPlacement, SortOrder
+from .types import NullPlacementT, SortKeyT, SortOrderT
+
+def sort_indices(
+    input: ArrayInput | ArrowStreamExportable,
+    sort_keys: Sequence[SortKeyT] | None = None,
+    *,
+    order: SortOrder | SortOrderT = "ascending",
+    null_placement: NullPlacement | NullPlacementT = "at_end",
+) -> Array:
+    """
+    Return the indices that would sort the input.
+
+    If `input` is a `RecordBatch`, `Table` or `RecordBatchReader` (or any other
+    struct-typed input), it is sorted by the columns selected in `sort_keys`. Otherwise
+    it is sorted by its own values and `sort_keys` must be `None`.
+
+    For `ChunkedArray`, `Table` and stream input, the sort is global across all
+    chunks, and the returned indices refer to positions in the concatenated input.
+    The sort is stable: equal rows keep their relative order.
+
+    Args:
+        input: Input data to sort.
+        sort_keys: Columns to sort by, for tabular input. Each key is a column name or
+            index, or a tuple of `(column, order)` or `(column, order, null_placement)`
+            that overrides the defaults for that key. If `None`, all columns are used
+            in order.
+        order: The default sort order.
+        null_placement: The default placement of nulls.
+
+    Returns:
+        A `UInt32` array of indices, or a `UInt64` array if the input has more than
+        `2^32 - 1` rows.
+    """
+
+def sort(
+    input: ArrayInput | ArrowStreamExportable,
+    sort_keys: Sequence[SortKeyT] | None = None,
+    *,
+    order: SortOrder | SortOrderT = "ascending",
+    null_placement: NullPlacement | NullPlacementT = "at_end",
+) -> Array | Table:
+    """
+    Sort the input.
+
+    This computes [`sort_indices`][arro3.compute.sort_indices] and then
+    [`take`][arro3.compute.take]s from the input. See `sort_indices` for the
+    semantics of each parameter.
+
+    Args:
+        input: Input data to sort.
+        sort_keys: Columns to sort by, for tabular input.
+        order: The default sort order.
+        null_placement: The default placement of nulls.
+
+    Returns:
+        A sorted `Table` for tabular input, otherwise a sorted `Array`. Chunked input
+        is returned as a single chunk.
+    """
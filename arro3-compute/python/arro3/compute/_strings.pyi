@@ -0,0 +1,262 @@
+from typing import overload
+
+from arro3.core import Array, ArrayReader
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+from .enums import PadSide
+from .types import PadSideT
+
+@overload
+def utf8_length(input: ArrayInput) -> Array: ...
+@overload
+def utf8_length(input: ArrowStreamExportable) -> ArrayReader: ...
+def utf8_length(
+    input: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Return the number of characters (not bytes) in each string.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+
+    Returns:
+        An Int32 array, or an Int64 array for LargeUtf8 input. Dictionary input returns a dictionary with integer values.
+    """
+
+@overload
+def upper(input: ArrayInput) -> Array: ...
+@overload
+def upper(input: ArrowStreamExportable) -> ArrayReader: ...
+def upper(
+    input: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Convert each string to uppercase.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
+
+@overload
+def lower(input: ArrayInput) -> Array: ...
+@overload
+def lower(input: ArrowStreamExportable) -> ArrayReader: ...
+def lower(
+    input: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Convert each string to lowercase.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
+
+@overload
+def reverse(input: ArrayInput) -> Array: ...
+@overload
+def reverse(input: ArrowStreamExportable) -> ArrayReader: ...
+def reverse(
+    input: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Reverse the characters of each string.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
+
+@overload
+def substring(input: ArrayInput, start: int, length: int | None = None) -> Array: ...
+@overload
+def substring(
+    input: ArrowStreamExportable, start: int, length: int | None = None
+) -> ArrayReader: ...
+def substring(
+    input: ArrayInput | ArrowStreamExportable,
+    start: int,
+    length: int | None = None,
+) -> Array | ArrayReader:
+    """
+    Slice each string by character positions.
+
+    A negative `start` counts from the end of the string.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+        start: Index of the first character to include.
+        length: Maximum number of characters to include. If `None`, slices to the end of each string.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
+
+@overload
+def strip(input: ArrayInput, characters: str | None = None) -> Array: ...
+@overload
+def strip(
+    input: ArrowStreamExportable, characters: str | None = None
+) -> ArrayReader: ...
+def strip(
+    input: ArrayInput | ArrowStreamExportable,
+    characters: str | None = None,
+) -> Array | ArrayReader:
+    """
+    Remove leading and trailing characters from each string.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+        characters: The set of characters to remove. If `None`, removes whitespace.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
+
+@overload
+def lstrip(input: ArrayInput, characters: str | None = None) -> Array: ...
+@overload
+def lstrip(
+    input: ArrowStreamExportable, characters: str | None = None
+) -> ArrayReader: ...
+def lstrip(
+    input: ArrayInput | ArrowStreamExportable,
+    characters: str | None = None,
+) -> Array | ArrayReader:
+    """
+    Remove leading characters from each string.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+        characters: The set of characters to remove. If `None`, removes whitespace.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
+
+@overload
+def rstrip(input: ArrayInput, characters: str | None = None) -> Array: ...
+@overload
+def rstrip(
+    input: ArrowStreamExportable, characters: str | None = None
+) -> ArrayReader: ...
+def rstrip(
+    input: ArrayInput | ArrowStreamExportable,
+    characters: str | None = None,
+) -> Array | ArrayReader:
+    """
+    Remove trailing characters from each string.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+        characters: The set of characters to remove. If `None`, removes whitespace.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
+
+@overload
+def pad(
+    input: ArrayInput,
+    width: int,
+    *,
+    side: PadSide | PadSideT = "left",
+    padding: str = " ",
+) -> Array: ...
+@overload
+def pad(
+    input: ArrowStreamExportable,
+    width: int,
+    *,
+    side: PadSide | PadSideT = "left",
+    padding: str = " ",
+) -> ArrayReader: ...
+def pad(
+    input: ArrayInput | ArrowStreamExportable,
+    width: int,
+    *,
+    side: PadSide | PadSideT = "left",
+    padding: str = " ",
+) -> Array | ArrayReader:
+    """
+    Pad each string to at least `width` characters.
+
+    Strings that are already at least `width` characters long are left unchanged.
+
+    Supports Utf8, LargeUtf8 and Utf8View input. Dictionary-encoded strings are transformed without decoding, by applying the function to the dictionary values.
+
+    If `input` is an Array, an `Array` will be returned. If `input` is a `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned.
+
+    Args:
+        input: Input string data.
+        width: The minimum number of characters in each output string.
+        side: Which side to pad on. When padding both sides, any odd padding character goes on the right.
+        padding: The single character to pad with.
+
+    Returns:
+        The transformed strings, with the same type as the input.
+    """
+
+@overload
+def concat_ws(separator: str, *inputs: ArrayInput) -> Array: ...
+@overload
+def concat_ws(separator: str, *inputs: ArrowStreamExportable) -> ArrayReader: ...
+def concat_ws(
+    separator: str, *inputs: ArrayInput | ArrowStreamExportable
+) -> Array | ArrayReader:
+    """
+    Concatenate strings element-wise, separated by `separator`.
+
+    Null values are skipped, so the output is never null. Supports Utf8, LargeUtf8,
+    Utf8View and dictionary-encoded string input.
+
+    If all `inputs` are Arrays, an `Array` will be returned. If all `inputs` are
+    `ChunkedArray` or `ArrayReader`, an `ArrayReader` will be returned, and the inputs
+    are combined chunk-by-chunk.
+
+    Args:
+        separator: The string to place between values.
+        inputs: Input string data.
+
+    Returns:
+        A LargeUtf8 array if any input is LargeUtf8, otherwise a Utf8 array.
+    """
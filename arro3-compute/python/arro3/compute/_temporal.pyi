@@ -1,10 +1,16 @@
-from typing import overload
+from typing import Literal, overload
 
 from arro3.core import Array, ArrayReader
-from arro3.core.types import ArrowArrayExportable, ArrowStreamExportable
+from arro3.core.types import ArrayInput, ArrowArrayExportable, ArrowStreamExportable
 
-from .enums import DatePart
-from .types import DatePartT
+from .enums import AmbiguousTime, DatePart, NonexistentTime, OnError, TemporalUnit
+from .types import (
+    AmbiguousTimeT,
+    DatePartT,
+    NonexistentTimeT,
+    OnErrorT,
+    TemporalUnitT,
+)
 
 # # Examples
 
@@ -52,3 +58,701 @@ def date_part(
     Returns:
         The extracted date part.
     """
+
+@overload
+def date_trunc(
+    input: ArrowArrayExportable,
+    unit: TemporalUnit | TemporalUnitT,
+    *,
+    week_starts_monday: bool = True,
+) -> Array: ...
+@overload
+def date_trunc(
+    input: ArrowStreamExportable,
+    unit: TemporalUnit | TemporalUnitT,
+    *,
+    week_starts_monday: bool = True,
+) -> ArrayReader: ...
+def date_trunc(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    unit: TemporalUnit | TemporalUnitT,
+    *,
+    week_starts_monday: bool = True,
+) -> Array | ArrayReader:
+    """
+    Truncate each value to the start of its `unit`, e.g. the first instant of its month.
+
+    This is equivalent to [`floor_temporal`][arro3.compute.floor_temporal] with
+    `multiple=1`.
+
+    Args:
+        input: Date32, Date64 or Timestamp input data.
+        unit: The unit to truncate to.
+        week_starts_monday: Whether weeks start on Monday. If `False`, weeks start on
+            Sunday.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input, with the same type and time zone as the input.
+    """
+
+@overload
+def ceil_temporal(
+    input: ArrowArrayExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> Array: ...
+@overload
+def ceil_temporal(
+    input: ArrowStreamExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> ArrayReader: ...
+def ceil_temporal(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> Array | ArrayReader:
+    """
+    Round each value up to a multiple of `multiple` `unit`s.
+
+    Fixed-length units are counted from the Unix epoch, so that e.g.
+    `multiple=15, unit="minute"` rounds to quarter hours, and weeks are counted from
+    the first Monday (or Sunday) after the epoch. Months, quarters and years are
+    counted from January 1970.
+
+    Time zone-aware timestamps are rounded in local time. A result that is ambiguous in
+    the time zone resolves to the instant with the same UTC offset as the input, or
+    otherwise the earliest instant at or after the input. A result that does not exist
+    in the time zone is an error.
+
+    Values already on a boundary are unchanged.
+
+    Args:
+        input: Date32, Date64 or Timestamp input data. Date32 input can only be
+            rounded to a multiple of a day or longer.
+        multiple: The number of units to round to a multiple of.
+        unit: The unit to round to.
+        week_starts_monday: Whether weeks start on Monday. If `False`, weeks start on
+            Sunday.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input, with the same type and time zone as the input.
+    """
+
+@overload
+def floor_temporal(
+    input: ArrowArrayExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> Array: ...
+@overload
+def floor_temporal(
+    input: ArrowStreamExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> ArrayReader: ...
+def floor_temporal(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> Array | ArrayReader:
+    """
+    Round each value down to a multiple of `multiple` `unit`s.
+
+    Fixed-length units are counted from the Unix epoch, so that e.g.
+    `multiple=15, unit="minute"` rounds to quarter hours, and weeks are counted from
+    the first Monday (or Sunday) after the epoch. Months, quarters and years are
+    counted from January 1970.
+
+    Time zone-aware timestamps are rounded in local time. A result that is ambiguous in
+    the time zone resolves to the instant with the same UTC offset as the input, or
+    otherwise the latest instant at or before the input. A result that does not exist in
+    the time zone is an error.
+
+    Args:
+        input: Date32, Date64 or Timestamp input data. Date32 input can only be
+            rounded to a multiple of a day or longer.
+        multiple: The number of units to round to a multiple of.
+        unit: The unit to round to.
+        week_starts_monday: Whether weeks start on Monday. If `False`, weeks start on
+            Sunday.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input, with the same type and time zone as the input.
+    """
+
+@overload
+def round_temporal(
+    input: ArrowArrayExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> Array: ...
+@overload
+def round_temporal(
+    input: ArrowStreamExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> ArrayReader: ...
+def round_temporal(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    multiple: int = 1,
+    unit: TemporalUnit | TemporalUnitT = "day",
+    *,
+    week_starts_monday: bool = True,
+) -> Array | ArrayReader:
+    """
+    Round each value to the nearest multiple of `multiple` `unit`s.
+
+    Fixed-length units are counted from the Unix epoch, so that e.g.
+    `multiple=15, unit="minute"` rounds to quarter hours, and weeks are counted from
+    the first Monday (or Sunday) after the epoch. Months, quarters and years are
+    counted from January 1970.
+
+    Time zone-aware timestamps are rounded in local time. A result that is ambiguous in
+    the time zone resolves to the instant with the same UTC offset as the input, or
+    otherwise the instant nearest the input. A result that does not exist in the time
+    zone is an error.
+
+    Values halfway between two boundaries round up.
+
+    Args:
+        input: Date32, Date64 or Timestamp input data. Date32 input can only be
+            rounded to a multiple of a day or longer.
+        multiple: The number of units to round to a multiple of.
+        unit: The unit to round to.
+        week_starts_monday: Whether weeks start on Monday. If `False`, weeks start on
+            Sunday.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input, with the same type and time zone as the input.
+    """
+
+@overload
+def assume_timezone(
+    input: ArrowArrayExportable,
+    tz: str,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+) -> Array: ...
+@overload
+def assume_timezone(
+    input: ArrowStreamExportable,
+    tz: str,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+) -> ArrayReader: ...
+def assume_timezone(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    tz: str,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+) -> Array | ArrayReader:
+    """
+    Interpret naive timestamps as wall-clock times in `tz`.
+
+    The output has the same unit as the input and time zone `tz`, and represents the
+    instants at which clocks in `tz` showed the input times.
+
+    Args:
+        input: Timestamp input data without a time zone.
+        tz: A time zone name such as `"America/New_York"`, or a fixed offset such as
+            `"+05:30"`.
+        ambiguous: How to resolve a local time that occurs twice, e.g. when clocks go
+            back.
+        nonexistent: How to resolve a local time that is skipped, e.g. when clocks go
+            forward.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def convert_timezone(input: ArrowArrayExportable, tz: str) -> Array: ...
+@overload
+def convert_timezone(input: ArrowStreamExportable, tz: str) -> ArrayReader: ...
+def convert_timezone(
+    input: ArrowArrayExportable | ArrowStreamExportable, tz: str
+) -> Array | ArrayReader:
+    """
+    Change the time zone of timestamps, keeping the instants they represent.
+
+    Timestamps are stored in UTC, so only the time zone of the data type changes. Use
+    [`assume_timezone`][arro3.compute.assume_timezone] to localize naive timestamps.
+
+    Args:
+        input: Timestamp input data with a time zone.
+        tz: A time zone name such as `"America/New_York"`, or a fixed offset such as
+            `"+05:30"`.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def local_timestamp(input: ArrowArrayExportable) -> Array: ...
+@overload
+def local_timestamp(input: ArrowStreamExportable) -> ArrayReader: ...
+def local_timestamp(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Convert timestamps to naive timestamps of their wall-clock time in their time zone.
+
+    This is the inverse of [`assume_timezone`][arro3.compute.assume_timezone]. Naive
+    timestamps are returned unchanged.
+
+    Args:
+        input: Timestamp input data.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def strftime(input: ArrowArrayExportable, format: str) -> Array: ...
+@overload
+def strftime(input: ArrowStreamExportable, format: str) -> ArrayReader: ...
+def strftime(
+    input: ArrowArrayExportable | ArrowStreamExportable, format: str
+) -> Array | ArrayReader:
+    """
+    Format dates and timestamps as strings.
+
+    Timestamps with a time zone are formatted in local time, so `%H` is the local
+    hour and `%z` is the local UTC offset.
+
+    Args:
+        input: Date32, Date64 or Timestamp input data.
+        format: A strftime-style format string such as `"%Y-%m-%d %H:%M:%S"`, using
+            the syntax of the Rust [`chrono`](https://docs.rs/chrono) crate.
+
+    Returns:
+        A `utf8` `Array` for array input, or an `ArrayReader` for `ChunkedArray` and
+        stream input.
+    """
+
+@overload
+def strptime(
+    input: ArrowArrayExportable,
+    format: str,
+    unit: Literal["s", "ms", "us", "ns"],
+    tz: str | None = None,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+    error: OnError | OnErrorT = "raise",
+) -> Array: ...
+@overload
+def strptime(
+    input: ArrowStreamExportable,
+    format: str,
+    unit: Literal["s", "ms", "us", "ns"],
+    tz: str | None = None,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+    error: OnError | OnErrorT = "raise",
+) -> ArrayReader: ...
+def strptime(
+    input: ArrowArrayExportable | ArrowStreamExportable,
+    format: str,
+    unit: Literal["s", "ms", "us", "ns"],
+    tz: str | None = None,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+    error: OnError | OnErrorT = "raise",
+) -> Array | ArrayReader:
+    """
+    Parse strings into timestamps.
+
+    If `format` includes a UTC offset such as `%z`, each value is converted to UTC and
+    the output has time zone `tz`, or `"UTC"` if `tz` is not given. Otherwise values
+    are wall-clock times, which are localized to `tz` if given. A format without time
+    fields, such as `"%Y-%m-%d"`, parses to midnight.
+
+    Args:
+        input: `utf8`, `large_utf8` or `utf8_view` input data, or a dictionary of these.
+        format: A strftime-style format string such as `"%Y-%m-%d %H:%M:%S"`, using
+            the syntax of the Rust [`chrono`](https://docs.rs/chrono) crate.
+        unit: The unit of the output timestamps.
+        tz: The time zone of the output timestamps.
+        ambiguous: How to resolve a local time that occurs twice in `tz`, e.g. when
+            clocks go back. Ignored if `format` includes a UTC offset.
+        nonexistent: How to resolve a local time that is skipped in `tz`, e.g. when
+            clocks go forward. Ignored if `format` includes a UTC offset.
+        error: Whether to raise an error naming the first row that cannot be parsed,
+            or return null for such rows.
+
+    Returns:
+        An `Array` for array input, or an `ArrayReader` for `ChunkedArray` and stream
+        input.
+    """
+
+@overload
+def add_interval(
+    input: ArrayInput,
+    interval: ArrayInput,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+) -> Array: ...
+@overload
+def add_interval(
+    input: ArrayInput | ArrowStreamExportable,
+    interval: ArrayInput | ArrowStreamExportable,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+) -> Array | ArrayReader: ...
+def add_interval(
+    input: ArrayInput | ArrowStreamExportable,
+    interval: ArrayInput | ArrowStreamExportable,
+    *,
+    ambiguous: AmbiguousTime | AmbiguousTimeT = "raise",
+    nonexistent: NonexistentTime | NonexistentTimeT = "raise",
+) -> Array | ArrayReader:
+    """
+    Add calendar intervals or durations to dates and timestamps.
+
+    Each interval is applied as months, then days, then nanoseconds. Adding months
+    clamps to the end of the month, so one month after January 31 is the last day of
+    February. For timestamps with a time zone, months and days are added to the local
+    wall-clock time, so adding a day keeps the time of day across a DST transition,
+    while nanoseconds are added as elapsed time.
+
+    Scalars are broadcast against the other input. If either input is a stream, the
+    output is a stream, where two streams are zipped chunk-by-chunk.
+
+    Args:
+        input: Date32, Date64 or Timestamp input data.
+        interval: Interval or Duration input data. For Date32 input, intervals must be
+            whole days.
+        ambiguous: How to resolve a local time that occurs twice, e.g. when clocks go
+            back.
+        nonexistent: How to resolve a local time that is skipped, e.g. when clocks go
+            forward.
+
+    Returns:
+        Data of the same type as `input`: an `Array` if both inputs are arrays or
+        scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def years_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def years_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def years_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the year boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the year,
+    so it is negative if `end` is before `start`. For timestamps with a time zone, years
+    are counted on the local wall clock.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def quarters_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def quarters_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def quarters_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the quarter boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the
+    quarter, so it is negative if `end` is before `start`. For timestamps with a time
+    zone, quarters are counted on the local wall clock.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def months_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def months_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def months_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the month boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the month,
+    so it is negative if `end` is before `start`. For timestamps with a time zone,
+    months are counted on the local wall clock.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def weeks_between(
+    start: ArrayInput, end: ArrayInput, *, week_starts_monday: bool = True
+) -> Array: ...
+@overload
+def weeks_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+    *,
+    week_starts_monday: bool = True,
+) -> Array | ArrayReader: ...
+def weeks_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+    *,
+    week_starts_monday: bool = True,
+) -> Array | ArrayReader:
+    """
+    Count the week boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the week,
+    so it is negative if `end` is before `start`. For timestamps with a time zone, weeks
+    are counted on the local wall clock.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+        week_starts_monday: Whether weeks start on Monday rather than Sunday.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def days_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def days_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def days_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the day boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the day,
+    so it is negative if `end` is before `start`. For timestamps with a time zone, days
+    are counted on the local wall clock, so a day across a DST transition is one day.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def hours_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def hours_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def hours_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the hour boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the hour,
+    so it is negative if `end` is before `start`. Hours are counted in UTC, so a day
+    across a DST transition may have more or fewer than 24 hours.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def minutes_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def minutes_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def minutes_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the minute boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the
+    minute, so it is negative if `end` is before `start`. Minutes are counted in UTC, so
+    a day across a DST transition may have more or fewer than 24 hours.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def seconds_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def seconds_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def seconds_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the second boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the
+    second, so it is negative if `end` is before `start`. Seconds are counted in UTC, so
+    a day across a DST transition may have more or fewer than 24 hours.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def milliseconds_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def milliseconds_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def milliseconds_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the millisecond boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the
+    millisecond, so it is negative if `end` is before `start`. Milliseconds are counted
+    in UTC, so a day across a DST transition may have more or fewer than 24 hours.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def microseconds_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def microseconds_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def microseconds_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the microsecond boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the
+    microsecond, so it is negative if `end` is before `start`. Microseconds are counted
+    in UTC, so a day across a DST transition may have more or fewer than 24 hours.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
+
+@overload
+def nanoseconds_between(start: ArrayInput, end: ArrayInput) -> Array: ...
+@overload
+def nanoseconds_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader: ...
+def nanoseconds_between(
+    start: ArrayInput | ArrowStreamExportable,
+    end: ArrayInput | ArrowStreamExportable,
+) -> Array | ArrayReader:
+    """
+    Count the nanosecond boundaries crossed from `start` to `end`, as `int64`.
+
+    This is the difference between `start` and `end` after truncating both to the
+    nanosecond, so it is negative if `end` is before `start`. Nanoseconds are counted in
+    UTC, so a day across a DST transition may have more or fewer than 24 hours.
+
+    Args:
+        start: Date32, Date64 or Timestamp input data.
+        end: Date32, Date64 or Timestamp input data.
+
+    Returns:
+        An `Array` if both inputs are arrays or scalars, otherwise an `ArrayReader`.
+    """
@@ -0,0 +1,55 @@
+from typing import Sequence
+
+from arro3.core import Array, Table
+from arro3.core.types import ArrayInput, ArrowStreamExportable
+
+from .enums import NullPlacement, SortOrder
+from .types import NullPlacementT, SortKeyT, SortOrderT
+
+def top_k(
+    input: ArrayInput | ArrowStreamExportable,
+    k: int,
+    sort_keys: Sequence[SortKeyT] | None = None,
+    *,
+    order: SortOrder | SortOrderT = "descending",
+    null_placement: NullPlacement | NullPlacementT = "at_end",
+) -> Array | Table:
+    """
+    Select the first `k` rows of the input in sort order, without a full sort.
+
+    With the default descending order, this selects the `k` largest rows. Stream input
+    is consumed one chunk at a time, keeping a bounded heap of the best `k` rows seen
+    so far, so at most max(2k, 1024) rows plus one chunk are held in memory. Ties are
+    broken in favor of rows that appear first.
+
+    Args:
+        input: Input data to select from.
+        k: The number of rows to select. Fewer rows are returned if the input is
+            shorter.
+        sort_keys: Columns to sort by, for tabular input. See
+            [`sort_indices`][arro3.compute.sort_indices].
+        order: The default sort order.
+        null_placement: The default placement of nulls.
+
+    Returns:
+        A `Table` of the selected rows in sort order for tabular input, otherwise an
+        `Array`.
+    """
+
+def bottom_k(
+    input: ArrayInput | ArrowStreamExportable,
+    k: int,
+    sort_keys: Sequence[SortKeyT] | None = None,
+    *,
+    order: SortOrder | SortOrderT = "ascending",
+    null_placement: NullPlacement | NullPlacementT = "at_end",
+) -> Array | Table:
+    """
+    Select the `k` smallest rows of the input, without a full sort.
+
+    This is equivalent to [`top_k`][arro3.compute.top_k] with `order="ascending"`.
+
+    Returns:
+        A `Table` of the selected rows in sort order for tabular input, otherwise an
+        `Array`.
+    """
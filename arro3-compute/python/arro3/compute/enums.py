@@ -63,3 +63,279 @@ class DatePart(StrEnum):
 
     Nanosecond = auto()
     """Nanosecond of the second"""
+
+
+class SortOrder(StrEnum):
+    """Valid orderings for sort kernels.
+
+    See [`sort_indices`][arro3.compute.sort_indices].
+    """
+
+    Ascending = auto()
+    """Sort from smallest to largest"""
+
+    Descending = auto()
+    """Sort from largest to smallest"""
+
+
+class NullPlacement(StrEnum):
+    """Valid placements of null values for sort kernels.
+
+    See [`sort_indices`][arro3.compute.sort_indices].
+    """
+
+    AtStart = "at_start"
+    """Nulls are ordered before all valid values"""
+
+    AtEnd = "at_end"
+    """Nulls are ordered after all valid values"""
+
+
+class PadSide(StrEnum):
+    """Valid sides to pad strings on.
+
+    See [`pad`][arro3.compute.pad].
+    """
+
+    Left = auto()
+    """Pad on the left, right-aligning the string"""
+
+    Right = auto()
+    """Pad on the right, left-aligning the string"""
+
+    Both = auto()
+    """Pad on both sides, centering the string"""
+
+
+class Aggregation(StrEnum):
+    """Valid aggregations for grouped data.
+
+    See [`group_by`][arro3.compute.group_by].
+    """
+
+    Count = auto()
+    """The number of valid values in each group"""
+
+    CountAll = "count_all"
+    """The number of rows in each group, including nulls"""
+
+    Sum = auto()
+    """The sum of valid values in each group"""
+
+    Mean = auto()
+    """The arithmetic mean of valid values in each group"""
+
+    Min = auto()
+    """The smallest valid value in each group"""
+
+    Max = auto()
+    """The largest valid value in each group"""
+
+
+class JoinType(StrEnum):
+    """Valid join types.
+
+    See [`join`][arro3.compute.join].
+    """
+
+    Inner = auto()
+    """Only rows with a matching key on both sides"""
+
+    Left = auto()
+    """All left rows, with nulls for right columns where there is no match"""
+
+    Right = auto()
+    """All right rows, with nulls for left columns where there is no match"""
+
+    Full = auto()
+    """All rows from both sides"""
+
+    Semi = auto()
+    """Left rows that have a match on the right, with only the left columns"""
+
+    Anti = auto()
+    """Left rows that have no match on the right, with only the left columns"""
+
+
+class CountMode(StrEnum):
+    """Valid modes for counting values.
+
+    See [`count`][arro3.compute.count].
+    """
+
+    All = auto()
+    """Count all values, including nulls"""
+
+    Valid = auto()
+    """Count only valid values"""
+
+    Null = auto()
+    """Count only null values"""
+
+
+class QuantileMethod(StrEnum):
+    """Valid methods for computing quantiles.
+
+    See [`quantile`][arro3.compute.quantile].
+    """
+
+    Exact = auto()
+    """Collect all valid values and interpolate between the two nearest ranks"""
+
+    TDigest = "tdigest"
+    """Estimate quantiles with a t-digest, in bounded memory"""
+
+
+class Tiebreaker(StrEnum):
+    """Valid ways to rank tied values.
+
+    See [`rank`][arro3.compute.rank].
+    """
+
+    Min = auto()
+    """Ties get the smallest rank of the tied group"""
+
+    Max = auto()
+    """Ties get the largest rank of the tied group"""
+
+    First = auto()
+    """Ties get distinct ranks, in order of appearance in the input"""
+
+    Dense = auto()
+    """Ties get the same rank, and ranks of successive groups increase by one"""
+
+
+class DropNullHow(StrEnum):
+    """Valid ways to decide which rows to drop.
+
+    See [`drop_null`][arro3.compute.drop_null].
+    """
+
+    Any = auto()
+    """Drop rows with a null in any of the selected columns"""
+
+    All = auto()
+    """Drop rows with a null in all of the selected columns"""
+
+
+class RoundMode(StrEnum):
+    """Valid ways to round values.
+
+    See [`round`][arro3.compute.round].
+    """
+
+    Down = auto()
+    """Round towards negative infinity"""
+
+    Up = auto()
+    """Round towards positive infinity"""
+
+    TowardsZero = "towards_zero"
+    """Round towards zero, i.e. truncate"""
+
+    TowardsInfinity = "towards_infinity"
+    """Round away from zero"""
+
+    HalfDown = "half_down"
+    """Round to nearest, with ties towards negative infinity"""
+
+    HalfUp = "half_up"
+    """Round to nearest, with ties towards positive infinity"""
+
+    HalfTowardsZero = "half_towards_zero"
+    """Round to nearest, with ties towards zero"""
+
+    HalfTowardsInfinity = "half_towards_infinity"
+    """Round to nearest, with ties away from zero"""
+
+    HalfToEven = "half_to_even"
+    """Round to nearest, with ties to the nearest even value"""
+
+    HalfToOdd = "half_to_odd"
+    """Round to nearest, with ties to the nearest odd value"""
+
+
+class TemporalUnit(StrEnum):
+    """Valid units to truncate or round temporal values to.
+
+    See [`date_trunc`][arro3.compute.date_trunc] and
+    [`floor_temporal`][arro3.compute.floor_temporal].
+    """
+
+    Year = auto()
+    """Calendar year"""
+
+    Quarter = auto()
+    """Calendar quarter, starting in January, April, July or October"""
+
+    Month = auto()
+    """Calendar month"""
+
+    Week = auto()
+    """Week, starting on Monday or Sunday"""
+
+    Day = auto()
+    """Day"""
+
+    Hour = auto()
+    """Hour"""
+
+    Minute = auto()
+    """Minute"""
+
+    Second = auto()
+    """Second"""
+
+    Millisecond = auto()
+    """Millisecond"""
+
+    Microsecond = auto()
+    """Microsecond"""
+
+    Nanosecond = auto()
+    """Nanosecond"""
+
+
+class AmbiguousTime(StrEnum):
+    """Valid ways to resolve a local time that occurs twice in a time zone.
+
+    See [`assume_timezone`][arro3.compute.assume_timezone].
+    """
+
+    Raise = auto()
+    """Raise an error"""
+
+    Earliest = auto()
+    """Use the earlier of the two instants"""
+
+    Latest = auto()
+    """Use the later of the two instants"""
+
+
+class NonexistentTime(StrEnum):
+    """Valid ways to resolve a local time that does not exist in a time zone.
+
+    See [`assume_timezone`][arro3.compute.assume_timezone].
+    """
+
+    Raise = auto()
+    """Raise an error"""
+
+    Earliest = auto()
+    """Use the last instant before the skipped interval"""
+
+    Latest = auto()
+    """Use the first instant after the skipped interval"""
+
+
+class OnError(StrEnum):
+    """Valid ways to handle a value that cannot be parsed.
+
+    See [`strptime`][arro3.compute.strptime].
+    """
+
+    Raise = auto()
+    """Raise an error naming the first row that cannot be parsed"""
+
+    Null = auto()
+    """Return null for values that cannot be parsed"""
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import Literal
+from typing import Literal, Tuple, Union
 
 DatePartT = Literal[
     "quarter",
@@ -22,3 +22,122 @@ DatePartT = Literal[
 Acceptable strings to be passed into the `part` parameter for
 [`date_part`][arro3.compute.date_part].
 """
+
+SortOrderT = Literal["ascending", "descending"]
+"""
+Acceptable strings to be passed into the `order` parameter for
+[`sort_indices`][arro3.compute.sort_indices].
+"""
+
+NullPlacementT = Literal["at_start", "at_end"]
+"""
+Acceptable strings to be passed into the `null_placement` parameter for
+[`sort_indices`][arro3.compute.sort_indices].
+"""
+
+SortKeyT = Union[
+    str,
+    int,
+    Tuple[Union[str, int], SortOrderT],
+    Tuple[Union[str, int], SortOrderT, NullPlacementT],
+]
+"""
+A sort key for tabular input: a column name or index, optionally paired with a sort
+order and a null placement.
+"""
+
+PadSideT = Literal["left", "right", "both"]
+"""
+Acceptable strings to be passed into the `side` parameter for
+[`pad`][arro3.compute.pad].
+"""
+
+AggregationT = Literal["count", "count_all", "sum", "mean", "min", "max"]
+"""
+Acceptable strings to be passed into the `aggs` parameter for
+[`group_by`][arro3.compute.group_by].
+"""
+
+JoinTypeT = Literal["inner", "left", "right", "full", "outer", "semi", "anti"]
+"""
+Acceptable strings to be passed into the `how` parameter for
+[`join`][arro3.compute.join].
+"""
+
+CountModeT = Literal["all", "valid", "null"]
+"""
+Acceptable strings to be passed into the `mode` parameter for
+[`count`][arro3.compute.count].
+"""
+
+QuantileMethodT = Literal["exact", "tdigest"]
+"""
+Acceptable strings to be passed into the `method` parameter for
+[`quantile`][arro3.compute.quantile].
+"""
+
+TiebreakerT = Literal["min", "max", "first", "dense"]
+"""
+Acceptable strings to be passed into the `tiebreaker` parameter for
+[`rank`][arro3.compute.rank].
+"""
+
+DropNullHowT = Literal["any", "all"]
+"""
+Acceptable strings to be passed into the `how` parameter for
+[`drop_null`][arro3.compute.drop_null].
+"""
+
+RoundModeT = Literal[
+    "down",
+    "up",
+    "towards_zero",
+    "towards_infinity",
+    "half_down",
+    "half_up",
+    "half_towards_zero",
+    "half_towards_infinity",
+    "half_to_even",
+    "half_to_odd",
+]
+"""
+Acceptable strings to be passed into the `mode` parameter for
+[`round`][arro3.compute.round].
+"""
+
+TemporalUnitT = Literal[
+    "year",
+    "quarter",
+    "month",
+    "week",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "millisecond",
+    "microsecond",
+    "nanosecond",
+]
+"""
+Acceptable strings to be passed into the `unit` parameter for
+[`date_trunc`][arro3.compute.date_trunc] and
+[`floor_temporal`][arro3.compute.floor_temporal].
+"""
+
+AmbiguousTimeT = Literal["raise", "earliest", "latest"]
+"""
+Acceptable strings to be passed into the `ambiguous` parameter for
+[`assume_timezone`][arro3.compute.assume_timezone].
+"""
+
+NonexistentTimeT = Literal["raise", "earliest", "latest"]
+"""
+Acceptable strings to be passed into the `nonexistent` parameter for
+[`assume_timezone`][arro3.compute.assume_timezone].
+"""
+
+OnErrorT = Literal["raise", "null"]
+"""
+Acceptable strings to be passed into the `error` parameter for
+[`strptime`][arro3.compute.strptime].
+"""
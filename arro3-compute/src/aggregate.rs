@@ -6,18 +6,29 @@ use arrow_array::builder::{
     PrimitiveBuilder, StringViewBuilder,
 };
 use arrow_array::cast::AsArray;
+use arrow_array::types::{Decimal128Type, Decimal256Type, Float64Type, Int64Type, UInt64Type};
 use arrow_array::{
-    downcast_primitive_array, Array, ArrayRef, ArrowPrimitiveType, BinaryViewArray, BooleanArray,
-    GenericBinaryArray, GenericStringArray, OffsetSizeTrait, PrimitiveArray, StringViewArray,
+    downcast_primitive_array, make_array, Array, ArrayRef, ArrowNativeTypeOp, ArrowPrimitiveType,
+    BinaryViewArray, BooleanArray, Decimal128Array, Decimal256Array, Float64Array,
+    GenericBinaryArray, GenericStringArray, Int64Array, OffsetSizeTrait, PrimitiveArray,
+    StringViewArray, UInt64Array,
+};
+use arrow_buffer::i256;
+use arrow_row::{OwnedRow, RowConverter, SortField};
+use arrow_schema::{
+    ArrowError, DataType, Field, FieldRef, DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
 };
-use arrow_schema::{ArrowError, DataType};
 use arrow_select::concat;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
 use pyo3_arrow::error::PyArrowResult;
-use pyo3_arrow::export::Arro3Scalar;
+use pyo3_arrow::export::{Arro3Array, Arro3Scalar};
 use pyo3_arrow::input::AnyArray;
 use pyo3_arrow::PyScalar;
 
+use crate::tdigest::TDigest;
+
 #[pyfunction]
 pub fn max(input: AnyArray) -> PyArrowResult<Arro3Scalar> {
     match input {
@@ -258,3 +269,468 @@ fn sum_primitive<T: ArrowPrimitiveType>(array: &PrimitiveArray<T>) -> ArrayRef {
     builder.append_option(compute::sum(array));
     Arc::new(builder.finish())
 }
+
+/// Fold `update` over every chunk of `input`, starting from `init`.
+///
+/// Each call to `update` merges the partial state of one chunk into the running state, so that
+/// stream input never needs to be materialized.
+fn fold_chunks<S>(
+    input: AnyArray,
+    init: S,
+    update: impl Fn(S, &dyn Array) -> Result<S, ArrowError>,
+) -> PyArrowResult<(S, FieldRef)> {
+    match input {
+        AnyArray::Array(array) => {
+            let (array, field) = array.into_inner();
+            Ok((update(init, array.as_ref())?, field))
+        }
+        AnyArray::Stream(stream) => {
+            let reader = stream.into_reader()?;
+            let field = reader.field();
+            let mut state = init;
+            for array in reader {
+                state = update(state, array?.as_ref())?;
+            }
+            Ok((state, field))
+        }
+    }
+}
+
+/// Return the global index of the first minimum (or maximum) valid value of the input.
+///
+/// Values are row-encoded so that any orderable type can be compared, including across chunks.
+fn arg_extreme(input: AnyArray, max: bool) -> PyArrowResult<Arro3Scalar> {
+    let data_type = input.field()?.data_type().clone();
+    let converter = RowConverter::new(vec![SortField::new(data_type)])?;
+    let init: (Option<(OwnedRow, u64)>, u64) = (None, 0);
+    let ((best, _len), _field) = fold_chunks(input, init, |(mut best, offset), chunk| {
+        let rows = converter.convert_columns(&[make_array(chunk.to_data())])?;
+        // Logical nulls also cover arrays without a null buffer, such as NullArray
+        let nulls = chunk.logical_nulls();
+        for i in 0..chunk.len() {
+            if nulls.as_ref().is_some_and(|nulls| nulls.is_null(i)) {
+                continue;
+            }
+            let row = rows.row(i);
+            let replace = best.as_ref().map_or(true, |(best_row, _)| {
+                if max {
+                    row > best_row.row()
+                } else {
+                    row < best_row.row()
+                }
+            });
+            if replace {
+                best = Some((row.owned(), offset + i as u64));
+            }
+        }
+        Ok((best, offset + chunk.len() as u64))
+    })?;
+    let index = best.map(|(_, index)| index);
+    to_scalar(Arc::new(UInt64Array::from(vec![index])))
+}
+
+/// Return the index of the first minimum value of the input as UInt64, or null if the input
+/// has no valid values.
+///
+/// For ChunkedArray and stream input, the index is global across all chunks.
+#[pyfunction]
+pub fn arg_min(input: AnyArray) -> PyArrowResult<Arro3Scalar> {
+    arg_extreme(input, false)
+}
+
+/// Return the index of the first maximum value of the input as UInt64, or null if the input
+/// has no valid values.
+///
+/// For ChunkedArray and stream input, the index is global across all chunks.
+#[pyfunction]
+pub fn arg_max(input: AnyArray) -> PyArrowResult<Arro3Scalar> {
+    arg_extreme(input, true)
+}
+
+/// Wrap a length-1 array into a scalar.
+fn to_scalar(array: ArrayRef) -> PyArrowResult<Arro3Scalar> {
+    let field = Arc::new(Field::new("", array.data_type().clone(), true));
+    Ok(PyScalar::try_new(array, field)?.into())
+}
+
+fn check_numeric(data_type: &DataType, name: &str) -> Result<(), ArrowError> {
+    if data_type.is_numeric() {
+        Ok(())
+    } else {
+        Err(ArrowError::ComputeError(format!(
+            "{data_type} not supported in {name}"
+        )))
+    }
+}
+
+/// Mergeable partial state for the mean and variance of floating-point values.
+///
+/// Each chunk is reduced with Welford's online algorithm, and partial states are combined with
+/// the pairwise update of Chan et al., which avoids the catastrophic cancellation of the naive
+/// sum-of-squares formula.
+#[derive(Debug, Default, Clone, Copy)]
+struct MomentState {
+    count: u64,
+    mean: f64,
+    /// The sum of squared differences from the mean
+    m2: f64,
+}
+
+impl MomentState {
+    fn from_array(array: &dyn Array) -> Result<Self, ArrowError> {
+        let array = arrow_cast::cast(array, &DataType::Float64)?;
+        let mut state = Self::default();
+        for value in array.as_primitive::<Float64Type>().iter().flatten() {
+            state.count += 1;
+            let delta = value - state.mean;
+            state.mean += delta / state.count as f64;
+            state.m2 += delta * (value - state.mean);
+        }
+        Ok(state)
+    }
+
+    fn merge(self, other: Self) -> Self {
+        if self.count == 0 {
+            return other;
+        }
+        if other.count == 0 {
+            return self;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        Self {
+            count,
+            mean: self.mean + delta * weight,
+            m2: self.m2 + other.m2 + delta * delta * self.count as f64 * weight,
+        }
+    }
+
+    fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    fn variance(&self, ddof: u32) -> Option<f64> {
+        (self.count > u64::from(ddof)).then(|| self.m2 / (self.count - u64::from(ddof)) as f64)
+    }
+}
+
+fn moments(input: AnyArray, name: &str) -> PyArrowResult<MomentState> {
+    check_numeric(input.field()?.data_type(), name)?;
+    let (state, _field) = fold_chunks(input, MomentState::default(), |state, chunk| {
+        Ok(state.merge(MomentState::from_array(chunk)?))
+    })?;
+    Ok(state)
+}
+
+/// Mergeable partial state for the exact mean of decimal values.
+#[derive(Debug, Clone, Copy)]
+struct DecimalSumState {
+    sum: i256,
+    count: u64,
+}
+
+impl DecimalSumState {
+    fn update(self, array: &dyn Array) -> Result<Self, ArrowError> {
+        let overflow = || ArrowError::ComputeError("Overflow in decimal mean".to_string());
+        let mut state = self;
+        let values: Box<dyn Iterator<Item = i256> + '_> = match array.data_type() {
+            DataType::Decimal128(_, _) => Box::new(
+                array
+                    .as_primitive::<Decimal128Type>()
+                    .iter()
+                    .flatten()
+                    .map(i256::from_i128),
+            ),
+            DataType::Decimal256(_, _) => {
+                Box::new(array.as_primitive::<Decimal256Type>().iter().flatten())
+            }
+            d => return Err(ArrowError::ComputeError(format!("{d} is not a decimal"))),
+        };
+        for value in values {
+            state.sum = state.sum.checked_add(value).ok_or_else(overflow)?;
+            state.count += 1;
+        }
+        Ok(state)
+    }
+
+    /// The mean, rounded half away from zero to the scale of the input.
+    fn mean(&self) -> Option<i256> {
+        if self.count == 0 {
+            return None;
+        }
+        let count = i256::from_i128(self.count as i128);
+        let quotient = self.sum / count;
+        let remainder = self.sum % count;
+        if remainder.wrapping_abs() * i256::from_i128(2) >= count {
+            Some(quotient + i256::from_i128(remainder.signum().as_i128()))
+        } else {
+            Some(quotient)
+        }
+    }
+}
+
+/// Return the arithmetic mean of the valid values of the input.
+///
+/// Decimal input returns a decimal of the same scale with maximum precision, and is computed
+/// exactly. All other numeric input returns Float64.
+#[pyfunction]
+pub fn mean(input: AnyArray) -> PyArrowResult<Arro3Scalar> {
+    let data_type = input.field()?.data_type().clone();
+    match data_type {
+        DataType::Decimal128(_, scale) => {
+            let init = DecimalSumState {
+                sum: i256::ZERO,
+                count: 0,
+            };
+            let (state, _field) = fold_chunks(input, init, |state, chunk| state.update(chunk))?;
+            let mean = state
+                .mean()
+                .map(|mean| {
+                    mean.to_i128().ok_or(ArrowError::ComputeError(
+                        "Overflow in decimal mean".to_string(),
+                    ))
+                })
+                .transpose()?;
+            let array = Decimal128Array::from(vec![mean])
+                .with_precision_and_scale(DECIMAL128_MAX_PRECISION, scale)?;
+            to_scalar(Arc::new(array))
+        }
+        DataType::Decimal256(_, scale) => {
+            let init = DecimalSumState {
+                sum: i256::ZERO,
+                count: 0,
+            };
+            let (state, _field) = fold_chunks(input, init, |state, chunk| state.update(chunk))?;
+            let array = Decimal256Array::from(vec![state.mean()])
+                .with_precision_and_scale(DECIMAL256_MAX_PRECISION, scale)?;
+            to_scalar(Arc::new(array))
+        }
+        _ => {
+            let state = moments(input, "mean")?;
+            to_scalar(Arc::new(Float64Array::from(vec![state.mean()])))
+        }
+    }
+}
+
+/// Return the variance of the valid values of the input, as Float64.
+///
+/// The divisor is `N - ddof`, where `N` is the number of valid values. Returns null if
+/// `N <= ddof`.
+#[pyfunction]
+#[pyo3(signature = (input, *, ddof=0))]
+pub fn variance(input: AnyArray, ddof: u32) -> PyArrowResult<Arro3Scalar> {
+    let state = moments(input, "variance")?;
+    to_scalar(Arc::new(Float64Array::from(vec![state.variance(ddof)])))
+}
+
+/// Return the standard deviation of the valid values of the input, as Float64.
+///
+/// The divisor is `N - ddof`, where `N` is the number of valid values. Returns null if
+/// `N <= ddof`.
+#[pyfunction]
+#[pyo3(signature = (input, *, ddof=0))]
+pub fn stddev(input: AnyArray, ddof: u32) -> PyArrowResult<Arro3Scalar> {
+    let state = moments(input, "stddev")?;
+    let stddev = state.variance(ddof).map(f64::sqrt);
+    to_scalar(Arc::new(Float64Array::from(vec![stddev])))
+}
+
+fn product_of<T: ArrowPrimitiveType>(
+    input: AnyArray,
+    output_type: DataType,
+) -> PyArrowResult<Arro3Scalar> {
+    let (product, _field) = fold_chunks(input, None, |product: Option<T::Native>, chunk| {
+        let chunk = arrow_cast::cast(chunk, &output_type)?;
+        chunk.as_primitive::<T>().iter().flatten().try_fold(
+            product,
+            |product, value| -> Result<_, ArrowError> {
+                Ok(Some(match product {
+                    Some(product) => product.mul_checked(value)?,
+                    None => value,
+                }))
+            },
+        )
+    })?;
+    let array = PrimitiveArray::<T>::from(vec![product]).with_data_type(output_type);
+    to_scalar(Arc::new(array))
+}
+
+/// Return the product of the valid values of the input, or null if there are none.
+///
+/// Signed integers are multiplied as Int64, unsigned integers as UInt64 and floats as Float64.
+/// Integer overflow raises an error.
+#[pyfunction]
+pub fn product(input: AnyArray) -> PyArrowResult<Arro3Scalar> {
+    match input.field()?.data_type() {
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+            product_of::<Int64Type>(input, DataType::Int64)
+        }
+        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+            product_of::<UInt64Type>(input, DataType::UInt64)
+        }
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+            product_of::<Float64Type>(input, DataType::Float64)
+        }
+        d => Err(ArrowError::ComputeError(format!("{d} not supported in product")).into()),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum CountMode {
+    /// Count all values, including nulls
+    All,
+    /// Count only valid values
+    Valid,
+    /// Count only null values
+    Null,
+}
+
+impl<'py> FromPyObject<'_, 'py> for CountMode {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "all" => Ok(Self::All),
+            "valid" => Ok(Self::Valid),
+            "null" => Ok(Self::Null),
+            _ => Err(PyValueError::new_err("Unexpected count mode")),
+        }
+    }
+}
+
+/// Return the number of values of the input, as Int64.
+#[pyfunction]
+#[pyo3(signature = (input, *, mode=CountMode::Valid))]
+pub fn count(input: AnyArray, mode: CountMode) -> PyArrowResult<Arro3Scalar> {
+    let (count, _field) = fold_chunks(input, 0, |count, chunk| {
+        let chunk_count = match mode {
+            CountMode::All => chunk.len(),
+            CountMode::Valid => chunk.len() - chunk.logical_null_count(),
+            CountMode::Null => chunk.logical_null_count(),
+        };
+        Ok(count + chunk_count as i64)
+    })?;
+    to_scalar(Arc::new(Int64Array::from(vec![count])))
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum QuantileMethod {
+    /// Collect all valid values and interpolate linearly between the two nearest ranks
+    Exact,
+    /// Estimate quantiles with a t-digest, in bounded memory
+    TDigest,
+}
+
+impl<'py> FromPyObject<'_, 'py> for QuantileMethod {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "exact" => Ok(Self::Exact),
+            "tdigest" => Ok(Self::TDigest),
+            _ => Err(PyValueError::new_err("Unexpected quantile method")),
+        }
+    }
+}
+
+/// Either a single quantile or a list of quantiles.
+#[derive(FromPyObject)]
+pub(crate) enum QuantileInput {
+    One(f64),
+    Many(Vec<f64>),
+}
+
+/// Compute each quantile of `qs` over the valid values of `input`, ignoring NaN.
+///
+/// For stream input, the exact method collects the valid values of every chunk as f64, while the
+/// t-digest method merges a bounded digest of each chunk.
+fn quantiles(
+    input: AnyArray,
+    qs: &[f64],
+    method: QuantileMethod,
+    delta: usize,
+) -> PyArrowResult<Vec<Option<f64>>> {
+    if let Some(q) = qs.iter().find(|q| !(0.0..=1.0).contains(*q)) {
+        return Err(
+            PyValueError::new_err(format!("Quantile must be between 0 and 1, got {q}")).into(),
+        );
+    }
+    check_numeric(input.field()?.data_type(), "quantile")?;
+
+    match method {
+        QuantileMethod::Exact => {
+            let (mut values, _field) = fold_chunks(input, vec![], |mut values, chunk| {
+                let chunk = arrow_cast::cast(chunk, &DataType::Float64)?;
+                let valid = chunk.as_primitive::<Float64Type>().iter().flatten();
+                values.extend(valid.filter(|value| !value.is_nan()));
+                Ok(values)
+            })?;
+            values.sort_by(|a, b| a.total_cmp(b));
+            Ok(qs
+                .iter()
+                .map(|q| {
+                    let last = values.len().checked_sub(1)?;
+                    let position = q * last as f64;
+                    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+                    // Interpolating between equal infinite values would give NaN
+                    if values[lower] == values[upper] {
+                        return Some(values[lower]);
+                    }
+                    let fraction = position - lower as f64;
+                    Some(values[lower] + (values[upper] - values[lower]) * fraction)
+                })
+                .collect())
+        }
+        QuantileMethod::TDigest => {
+            let (mut digest, _field) =
+                fold_chunks(input, TDigest::new(delta), |mut digest, chunk| {
+                    let chunk = arrow_cast::cast(chunk, &DataType::Float64)?;
+                    let mut chunk_digest = TDigest::new(delta);
+                    for value in chunk.as_primitive::<Float64Type>().iter().flatten() {
+                        chunk_digest.add(value);
+                    }
+                    digest.merge(chunk_digest);
+                    Ok(digest)
+                })?;
+            Ok(qs.iter().map(|q| digest.quantile(*q)).collect())
+        }
+    }
+}
+
+/// Compute one or more quantiles of the valid values of the input.
+///
+/// Returns a Float64 scalar if `q` is a single number, or a Float64 array otherwise.
+#[pyfunction]
+#[pyo3(signature = (input, q=QuantileInput::One(0.5), *, method=QuantileMethod::Exact, delta=100))]
+pub fn quantile<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    q: QuantileInput,
+    method: QuantileMethod,
+    delta: usize,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    match q {
+        QuantileInput::One(q) => {
+            let values = quantiles(input, &[q], method, delta)?;
+            Ok(to_scalar(Arc::new(Float64Array::from(values)))?.into_bound_py_any(py)?)
+        }
+        QuantileInput::Many(qs) => {
+            let values = quantiles(input, &qs, method, delta)?;
+            Ok(
+                Arro3Array::from(Arc::new(Float64Array::from(values)) as ArrayRef)
+                    .into_bound_py_any(py)?,
+            )
+        }
+    }
+}
+
+/// Compute the median of the valid values of the input, as Float64.
+#[pyfunction]
+#[pyo3(signature = (input, *, method=QuantileMethod::Exact, delta=100))]
+pub fn median(input: AnyArray, method: QuantileMethod, delta: usize) -> PyArrowResult<Arro3Scalar> {
+    let values = quantiles(input, &[0.5], method, delta)?;
+    to_scalar(Arc::new(Float64Array::from(values)))
+}
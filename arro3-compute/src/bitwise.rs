@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use arrow_arith::bitwise;
+use arrow_array::cast::AsArray;
+use arrow_array::types::UInt32Type;
+use arrow_array::{downcast_integer, ArrayRef};
+use arrow_schema::{ArrowError, DataType};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::Arro3Array;
+use pyo3_arrow::input::AnyDatum;
+use pyo3_arrow::PyArray;
+
+use crate::utils::{broadcast, broadcast_len};
+
+#[derive(Debug, Clone, Copy)]
+enum BitwiseOp {
+    And,
+    Or,
+    Xor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+macro_rules! binary_helper {
+    ($t:ty, $lhs:ident, $rhs:ident, $op:ident) => {{
+        let (lhs, rhs) = ($lhs.as_primitive::<$t>(), $rhs.as_primitive::<$t>());
+        let out = match $op {
+            BitwiseOp::And => bitwise::bitwise_and(lhs, rhs)?,
+            BitwiseOp::Or => bitwise::bitwise_or(lhs, rhs)?,
+            BitwiseOp::Xor => bitwise::bitwise_xor(lhs, rhs)?,
+            BitwiseOp::ShiftLeft => bitwise::bitwise_shift_left(lhs, rhs)?,
+            BitwiseOp::ShiftRight => bitwise::bitwise_shift_right(lhs, rhs)?,
+        };
+        Ok::<ArrayRef, ArrowError>(Arc::new(out))
+    }};
+}
+
+fn check_integer(data_type: &DataType) -> PyResult<()> {
+    if data_type.is_integer() {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Expected integer input, got {data_type}"
+        )))
+    }
+}
+
+/// Apply `op` to `lhs` and `rhs`, after casting `rhs` to the type of `lhs` and broadcasting
+/// scalars. A value of `rhs` that does not fit the type of `lhs` is an error.
+fn binary(lhs: AnyDatum, rhs: AnyDatum, op: BitwiseOp) -> PyArrowResult<Arro3Array> {
+    let data_type = lhs.field().data_type().clone();
+    check_integer(&data_type)?;
+    check_integer(rhs.field().data_type())?;
+    let len = broadcast_len(&[&lhs, &rhs]);
+    let lhs = broadcast(&lhs, &data_type, len)?;
+    let rhs = broadcast(&rhs, &data_type, len)?;
+    let out = downcast_integer! {
+        &data_type => (binary_helper, lhs, rhs, op),
+        _ => unreachable!()
+    }?;
+    Ok(out.into())
+}
+
+#[pyfunction]
+pub fn bitwise_and(lhs: AnyDatum, rhs: AnyDatum) -> PyArrowResult<Arro3Array> {
+    binary(lhs, rhs, BitwiseOp::And)
+}
+
+#[pyfunction]
+pub fn bitwise_or(lhs: AnyDatum, rhs: AnyDatum) -> PyArrowResult<Arro3Array> {
+    binary(lhs, rhs, BitwiseOp::Or)
+}
+
+#[pyfunction]
+pub fn bitwise_xor(lhs: AnyDatum, rhs: AnyDatum) -> PyArrowResult<Arro3Array> {
+    binary(lhs, rhs, BitwiseOp::Xor)
+}
+
+#[pyfunction]
+pub fn shift_left(lhs: AnyDatum, rhs: AnyDatum) -> PyArrowResult<Arro3Array> {
+    binary(lhs, rhs, BitwiseOp::ShiftLeft)
+}
+
+#[pyfunction]
+pub fn shift_right(lhs: AnyDatum, rhs: AnyDatum) -> PyArrowResult<Arro3Array> {
+    binary(lhs, rhs, BitwiseOp::ShiftRight)
+}
+
+macro_rules! not_helper {
+    ($t:ty, $array:ident) => {
+        Ok::<ArrayRef, ArrowError>(Arc::new(bitwise::bitwise_not($array.as_primitive::<$t>())?))
+    };
+}
+
+#[pyfunction]
+pub fn bitwise_not(array: PyArray) -> PyArrowResult<Arro3Array> {
+    let array = array.as_ref();
+    check_integer(array.data_type())?;
+    let out = downcast_integer! {
+        array.data_type() => (not_helper, array),
+        _ => unreachable!()
+    }?;
+    Ok(out.into())
+}
+
+macro_rules! bit_count_helper {
+    ($t:ty, $array:ident) => {
+        Arc::new(
+            $array
+                .as_primitive::<$t>()
+                .unary::<_, UInt32Type>(|value| value.count_ones()),
+        ) as ArrayRef
+    };
+}
+
+#[pyfunction]
+pub fn bit_count(array: PyArray) -> PyArrowResult<Arro3Array> {
+    let array = array.as_ref();
+    check_integer(array.data_type())?;
+    let out = downcast_integer! {
+        array.data_type() => (bit_count_helper, array),
+        _ => unreachable!()
+    };
+    Ok(out.into())
+}
@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
-use arrow_array::ArrayRef;
-use arrow_schema::{DataType, Field};
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, BooleanArray};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{ArrowError, DataType, Field};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::IntoPyObjectExt;
 use pyo3_arrow::error::PyArrowResult;
@@ -10,6 +13,8 @@ use pyo3_arrow::ffi::ArrayIterator;
 use pyo3_arrow::input::AnyArray;
 use pyo3_arrow::{PyArray, PyArrayReader};
 
+use crate::utils::AlignedChunks;
+
 #[pyfunction]
 pub fn is_null<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
     match input {
@@ -61,3 +66,161 @@ pub fn is_not_null<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound
         }
     }
 }
+
+type BinaryBooleanKernel = fn(&BooleanArray, &BooleanArray) -> Result<BooleanArray, ArrowError>;
+
+fn as_boolean(array: &dyn Array) -> Result<&BooleanArray, ArrowError> {
+    array
+        .as_boolean_opt()
+        .ok_or(ArrowError::ComputeError(format!(
+            "Expected boolean array, got {}",
+            array.data_type()
+        )))
+}
+
+fn binary_boolean<'py>(
+    py: Python<'py>,
+    lhs: AnyArray,
+    rhs: AnyArray,
+    op: BinaryBooleanKernel,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    match (lhs, rhs) {
+        (AnyArray::Array(lhs), AnyArray::Array(rhs)) => {
+            let out = op(as_boolean(lhs.as_ref())?, as_boolean(rhs.as_ref())?)?;
+            Ok(Arro3Array::from(PyArray::from_array_ref(Arc::new(out))).into_bound_py_any(py)?)
+        }
+        (AnyArray::Stream(lhs), AnyArray::Stream(rhs)) => {
+            let lhs = lhs.into_reader()?;
+            let rhs = rhs.into_reader()?;
+
+            if !lhs.field().data_type().equals_datatype(&DataType::Boolean)
+                || !rhs.field().data_type().equals_datatype(&DataType::Boolean)
+            {
+                return Err(PyValueError::new_err("Expected boolean array input").into());
+            }
+
+            let out_field = Field::new("", DataType::Boolean, true);
+            let iter = AlignedChunks::new(vec![lhs, rhs]).map(move |chunks| {
+                let chunks = chunks?;
+                let out = op(chunks[0].as_boolean(), chunks[1].as_boolean())?;
+                Ok(Arc::new(out) as ArrayRef)
+            });
+            Ok(
+                Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+                    iter,
+                    out_field.into(),
+                ))))
+                .into_bound_py_any(py)?,
+            )
+        }
+        _ => Err(PyValueError::new_err("Unsupported combination of array and stream").into()),
+    }
+}
+
+/// Performs `XOR` on two boolean arrays. If either side is null, the result is null.
+fn xor_kernel(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray, ArrowError> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform bitwise operation on arrays of different length".to_string(),
+        ));
+    }
+    let values = left.values() ^ right.values();
+    let nulls = NullBuffer::union(left.nulls(), right.nulls());
+    Ok(BooleanArray::new(values, nulls))
+}
+
+/// Performs `AND NOT` on two boolean arrays using Kleene logic, i.e. `left AND (NOT right)`.
+fn and_not_kleene_kernel(
+    left: &BooleanArray,
+    right: &BooleanArray,
+) -> Result<BooleanArray, ArrowError> {
+    arrow_arith::boolean::and_kleene(left, &arrow_arith::boolean::not(right)?)
+}
+
+#[pyfunction]
+pub fn and_<'py>(
+    py: Python<'py>,
+    lhs: AnyArray,
+    rhs: AnyArray,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    binary_boolean(py, lhs, rhs, arrow_arith::boolean::and)
+}
+
+#[pyfunction]
+pub fn and_kleene<'py>(
+    py: Python<'py>,
+    lhs: AnyArray,
+    rhs: AnyArray,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    binary_boolean(py, lhs, rhs, arrow_arith::boolean::and_kleene)
+}
+
+#[pyfunction]
+pub fn and_not<'py>(
+    py: Python<'py>,
+    lhs: AnyArray,
+    rhs: AnyArray,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    binary_boolean(py, lhs, rhs, arrow_arith::boolean::and_not)
+}
+
+#[pyfunction]
+pub fn and_not_kleene<'py>(
+    py: Python<'py>,
+    lhs: AnyArray,
+    rhs: AnyArray,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    binary_boolean(py, lhs, rhs, and_not_kleene_kernel)
+}
+
+#[pyfunction]
+pub fn or_<'py>(py: Python<'py>, lhs: AnyArray, rhs: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    binary_boolean(py, lhs, rhs, arrow_arith::boolean::or)
+}
+
+#[pyfunction]
+pub fn or_kleene<'py>(
+    py: Python<'py>,
+    lhs: AnyArray,
+    rhs: AnyArray,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    binary_boolean(py, lhs, rhs, arrow_arith::boolean::or_kleene)
+}
+
+#[pyfunction]
+pub fn xor<'py>(py: Python<'py>, lhs: AnyArray, rhs: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    binary_boolean(py, lhs, rhs, xor_kernel)
+}
+
+#[pyfunction]
+pub fn invert<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    match input {
+        AnyArray::Array(input) => {
+            let out = arrow_arith::boolean::not(as_boolean(input.as_ref())?)?;
+            Ok(Arro3Array::from(PyArray::from_array_ref(Arc::new(out))).into_bound_py_any(py)?)
+        }
+        AnyArray::Stream(input) => {
+            let input = input.into_reader()?;
+            if !input
+                .field()
+                .data_type()
+                .equals_datatype(&DataType::Boolean)
+            {
+                return Err(PyValueError::new_err("Expected boolean array input").into());
+            }
+            let out_field = Field::new("", DataType::Boolean, true);
+
+            let iter = input.into_iter().map(move |input| {
+                let out = arrow_arith::boolean::not(input?.as_boolean())?;
+                Ok(Arc::new(out) as ArrayRef)
+            });
+            Ok(
+                Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+                    iter,
+                    out_field.into(),
+                ))))
+                .into_bound_py_any(py)?,
+            )
+        }
+    }
+}
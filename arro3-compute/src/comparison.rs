@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, BooleanArray, Datum};
+use arrow_schema::{ArrowError, DataType, Field};
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+
+use crate::utils::{binary_datum_kernel, AnyDatumOrStream};
+
+fn compare<'py>(
+    py: Python<'py>,
+    lhs: AnyDatumOrStream,
+    rhs: AnyDatumOrStream,
+    op: fn(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let output_field = Arc::new(Field::new("", DataType::Boolean, true));
+    binary_datum_kernel(py, lhs, rhs, output_field, move |lhs, rhs| {
+        Ok(Arc::new(op(lhs, rhs)?) as ArrayRef)
+    })
+}
+
+#[pyfunction]
+pub fn eq<'py>(
+    py: Python<'py>,
+    lhs: AnyDatumOrStream,
+    rhs: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    compare(py, lhs, rhs, arrow_ord::cmp::eq)
+}
+
+#[pyfunction]
+pub fn neq<'py>(
+    py: Python<'py>,
+    lhs: AnyDatumOrStream,
+    rhs: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    compare(py, lhs, rhs, arrow_ord::cmp::neq)
+}
+
+#[pyfunction]
+pub fn lt<'py>(
+    py: Python<'py>,
+    lhs: AnyDatumOrStream,
+    rhs: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    compare(py, lhs, rhs, arrow_ord::cmp::lt)
+}
+
+#[pyfunction]
+pub fn lt_eq<'py>(
+    py: Python<'py>,
+    lhs: AnyDatumOrStream,
+    rhs: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    compare(py, lhs, rhs, arrow_ord::cmp::lt_eq)
+}
+
+#[pyfunction]
+pub fn gt<'py>(
+    py: Python<'py>,
+    lhs: AnyDatumOrStream,
+    rhs: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    compare(py, lhs, rhs, arrow_ord::cmp::gt)
+}
+
+#[pyfunction]
+pub fn gt_eq<'py>(
+    py: Python<'py>,
+    lhs: AnyDatumOrStream,
+    rhs: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    compare(py, lhs, rhs, arrow_ord::cmp::gt_eq)
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use arrow_arith::boolean::is_not_null;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int64Type;
+use arrow_array::{new_null_array, Array, ArrayRef, Datum, Scalar};
+use arrow_schema::{ArrowError, DataType, Field};
+use arrow_select::filter::prep_null_mask_filter;
+use arrow_select::interleave::interleave;
+use arrow_select::zip::zip;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+
+use crate::utils::{
+    broadcast, broadcast_len, cast_strict, common_input_type, nary_datum_kernel, AnyDatumOrStream,
+};
+
+fn check_boolean(input: &AnyDatumOrStream, name: &str) -> PyResult<()> {
+    let data_type = input.field()?.data_type().clone();
+    if data_type == DataType::Boolean {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Expected boolean {name}, got {data_type}"
+        )))
+    }
+}
+
+fn if_else_datums(
+    cond: &dyn Datum,
+    left: &dyn Datum,
+    right: &dyn Datum,
+    data_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    let len = broadcast_len(&[cond, left, right]);
+    let cond = broadcast(cond, &DataType::Boolean, len)?;
+    let cond = cond.as_boolean();
+    let left = broadcast(left, data_type, len)?;
+    let right = broadcast(right, data_type, len)?;
+    let result = zip(&prep_null_mask_filter(cond), &left, &right)?;
+    if cond.null_count() == 0 {
+        return Ok(result);
+    }
+    // A null condition produces a null, rather than selecting `right`
+    zip(
+        &is_not_null(cond)?,
+        &result,
+        &Scalar::new(new_null_array(data_type, 1)),
+    )
+}
+
+/// Select from `left` where `cond` is true and from `right` where it is false.
+///
+/// `left` and `right` are cast to the narrowest type that holds both, raising if there is none,
+/// and scalars are broadcast. A null condition produces a null.
+#[pyfunction]
+pub fn if_else<'py>(
+    py: Python<'py>,
+    cond: AnyDatumOrStream,
+    left: AnyDatumOrStream,
+    right: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    check_boolean(&cond, "condition")?;
+    let data_type = common_input_type([&left, &right])?;
+    let output_field = Arc::new(Field::new("", data_type.clone(), true));
+    nary_datum_kernel(py, vec![cond, left, right], output_field, move |datums| {
+        if_else_datums(datums[0], datums[1], datums[2], &data_type)
+    })
+}
+
+/// Evaluate `conditions` and `values` (followed by an optional default) as in [case_when].
+fn case_when_datums(
+    datums: &[&dyn Datum],
+    num_branches: usize,
+    data_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    let len = broadcast_len(datums);
+    let mut result = match datums.get(2 * num_branches) {
+        Some(default) => broadcast(*default, data_type, len)?,
+        None => new_null_array(data_type, len),
+    };
+    // Apply branches from last to first, so that the first true condition wins
+    for i in (0..num_branches).rev() {
+        let cond = broadcast(datums[i], &DataType::Boolean, len)?;
+        let value = broadcast(datums[num_branches + i], data_type, len)?;
+        result = zip(&prep_null_mask_filter(cond.as_boolean()), &value, &result)?;
+    }
+    Ok(result)
+}
+
+/// For each row, select the value of the first true condition, or `default` if no condition is
+/// true.
+///
+/// Null conditions are treated as false. Values are cast to the narrowest type that holds all of
+/// them, raising if there is none, and scalars are broadcast.
+#[pyfunction]
+#[pyo3(signature = (conditions, values, default=None))]
+pub fn case_when<'py>(
+    py: Python<'py>,
+    conditions: Vec<AnyDatumOrStream>,
+    values: Vec<AnyDatumOrStream>,
+    default: Option<AnyDatumOrStream>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    if conditions.len() != values.len() {
+        return Err(PyValueError::new_err(format!(
+            "Expected the same number of conditions and values, got {} and {}",
+            conditions.len(),
+            values.len()
+        ))
+        .into());
+    }
+    for cond in &conditions {
+        check_boolean(cond, "condition")?;
+    }
+    let data_type = common_input_type(values.iter().chain(default.iter()))?;
+    let output_field = Arc::new(Field::new("", data_type.clone(), true));
+
+    let num_branches = conditions.len();
+    let mut inputs = conditions;
+    inputs.extend(values);
+    inputs.extend(default);
+    nary_datum_kernel(py, inputs, output_field, move |datums| {
+        case_when_datums(datums, num_branches, &data_type)
+    })
+}
+
+/// Evaluate `indices` followed by `values` as in [choose].
+fn choose_datums(datums: &[&dyn Datum], data_type: &DataType) -> Result<ArrayRef, ArrowError> {
+    let len = broadcast_len(datums);
+    let indices = broadcast(datums[0], &DataType::Int64, len)?;
+    let values = datums[1..]
+        .iter()
+        .map(|datum| {
+            let (array, is_scalar) = datum.get();
+            if !is_scalar && array.len() != len {
+                return Err(ArrowError::ComputeError(format!(
+                    "Expected all arrays to have length {len}, got {}",
+                    array.len()
+                )));
+            }
+            Ok((cast_strict(array, data_type)?, is_scalar))
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+
+    // A trailing null source, selected by null indices
+    let null = new_null_array(data_type, 1);
+    let mut sources = values
+        .iter()
+        .map(|(array, _)| array.as_ref())
+        .collect::<Vec<_>>();
+    sources.push(null.as_ref());
+
+    let picks = indices
+        .as_primitive::<Int64Type>()
+        .iter()
+        .enumerate()
+        .map(|(row, index)| match index {
+            None => Ok((values.len(), 0)),
+            Some(index) if index >= 0 && (index as usize) < values.len() => {
+                let (_, is_scalar) = values[index as usize];
+                Ok((index as usize, if is_scalar { 0 } else { row }))
+            }
+            Some(index) => Err(ArrowError::ComputeError(format!(
+                "choose index {index} is out of range for {} values",
+                values.len()
+            ))),
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+    interleave(&sources, &picks)
+}
+
+/// For each row, select the value from `values[indices[i]]`.
+///
+/// A null index produces a null. Values are cast to the narrowest type that holds all of them,
+/// raising if there is none, and scalars are broadcast.
+#[pyfunction]
+#[pyo3(signature = (indices, *values))]
+pub fn choose<'py>(
+    py: Python<'py>,
+    indices: AnyDatumOrStream,
+    values: Vec<AnyDatumOrStream>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let indices_type = indices.field()?.data_type().clone();
+    if !indices_type.is_integer() {
+        return Err(
+            PyValueError::new_err(format!("Expected integer indices, got {indices_type}")).into(),
+        );
+    }
+    if values.is_empty() {
+        return Err(PyValueError::new_err("choose requires at least one value").into());
+    }
+    let data_type = common_input_type(&values)?;
+    let output_field = Arc::new(Field::new("", data_type.clone(), true));
+
+    let mut inputs = vec![indices];
+    inputs.extend(values);
+    nary_datum_kernel(py, inputs, output_field, move |datums| {
+        choose_datums(datums, &data_type)
+    })
+}
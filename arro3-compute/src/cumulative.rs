@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use arrow_array::builder::PrimitiveBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Decimal128Type, Decimal256Type, DecimalType};
+use arrow_array::{downcast_primitive, Array, ArrayRef, ArrowNativeTypeOp, ArrowPrimitiveType};
+use arrow_schema::{ArrowError, DataType};
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::input::AnyArray;
+
+use crate::utils::unary_kernel;
+
+#[derive(Debug, Clone, Copy)]
+enum ScanOp {
+    Sum,
+    SumWrapping,
+    Prod,
+    ProdWrapping,
+    Min,
+    Max,
+}
+
+impl ScanOp {
+    /// Whether this operation is meaningful for values of `data_type`.
+    ///
+    /// Sums are limited to numbers, and products also exclude decimals, whose scale would change.
+    /// Minimums and maximums also apply to dates, times, timestamps and durations.
+    fn supports(&self, data_type: &DataType) -> bool {
+        let decimal = matches!(
+            data_type,
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _)
+        );
+        match self {
+            Self::Sum | Self::SumWrapping => data_type.is_numeric(),
+            Self::Prod | Self::ProdWrapping => data_type.is_numeric() && !decimal,
+            Self::Min | Self::Max => {
+                data_type.is_numeric()
+                    || (data_type.is_temporal() && !matches!(data_type, DataType::Interval(_)))
+            }
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Sum => "cumulative_sum",
+            Self::SumWrapping => "cumulative_sum_wrapping",
+            Self::Prod => "cumulative_prod",
+            Self::ProdWrapping => "cumulative_prod_wrapping",
+            Self::Min => "cumulative_min",
+            Self::Max => "cumulative_max",
+        }
+    }
+
+    fn apply<T: ArrowNativeTypeOp>(&self, acc: T, value: T) -> Result<T, ArrowError> {
+        match self {
+            Self::Sum => acc.add_checked(value),
+            Self::SumWrapping => Ok(acc.add_wrapping(value)),
+            Self::Prod => acc.mul_checked(value),
+            Self::ProdWrapping => Ok(acc.mul_wrapping(value)),
+            Self::Min => Ok(if value.is_lt(acc) { value } else { acc }),
+            Self::Max => Ok(if value.is_gt(acc) { value } else { acc }),
+        }
+    }
+}
+
+type Scanner = Box<dyn FnMut(&dyn Array) -> Result<ArrayRef, ArrowError> + Send>;
+
+/// Create a scanner over chunks of type `T`, which carries the running value from the end of
+/// one chunk to the start of the next.
+fn primitive_scanner<T: ArrowPrimitiveType>(
+    data_type: &DataType,
+    op: ScanOp,
+    skip_nulls: bool,
+) -> Scanner {
+    let data_type = data_type.clone();
+    let mut acc: Option<T::Native> = None;
+    // Whether a null has been seen, after which every output value is null unless skip_nulls
+    let mut poisoned = false;
+    Box::new(move |array| {
+        let array = array.as_primitive::<T>();
+        let mut builder = PrimitiveBuilder::<T>::with_capacity(array.len());
+        for value in array.iter() {
+            match value {
+                Some(value) if !poisoned => {
+                    let next = match acc {
+                        Some(acc) => op.apply(acc, value)?,
+                        None => value,
+                    };
+                    acc = Some(next);
+                    builder.append_value(next);
+                }
+                None if !skip_nulls => {
+                    poisoned = true;
+                    builder.append_null();
+                }
+                _ => builder.append_null(),
+            }
+        }
+        // Preserve parameterized types such as timestamps with a time zone or decimals
+        Ok(Arc::new(builder.finish().with_data_type(data_type.clone())))
+    })
+}
+
+macro_rules! primitive_scanner_helper {
+    ($t:ty, $data_type:expr, $op:expr, $skip_nulls:expr) => {
+        Ok(primitive_scanner::<$t>($data_type, $op, $skip_nulls))
+    };
+}
+
+/// Create a scanner over decimal chunks, which returns an error once the running value no
+/// longer fits in `precision`.
+fn decimal_scanner<T: DecimalType>(
+    data_type: &DataType,
+    precision: u8,
+    op: ScanOp,
+    skip_nulls: bool,
+) -> Scanner {
+    let mut scanner = primitive_scanner::<T>(data_type, op, skip_nulls);
+    Box::new(move |array| {
+        let out = scanner(array)?;
+        out.as_primitive::<T>()
+            .validate_decimal_precision(precision)?;
+        Ok(out)
+    })
+}
+
+fn scanner(data_type: &DataType, op: ScanOp, skip_nulls: bool) -> Result<Scanner, ArrowError> {
+    if !op.supports(data_type) {
+        return Err(ArrowError::ComputeError(format!(
+            "{data_type} not supported in {}",
+            op.name()
+        )));
+    }
+    match data_type {
+        DataType::Decimal128(precision, _) => Ok(decimal_scanner::<Decimal128Type>(
+            data_type, *precision, op, skip_nulls,
+        )),
+        DataType::Decimal256(precision, _) => Ok(decimal_scanner::<Decimal256Type>(
+            data_type, *precision, op, skip_nulls,
+        )),
+        _ => downcast_primitive! {
+            data_type => (primitive_scanner_helper, data_type, op, skip_nulls),
+            d => Err(ArrowError::ComputeError(format!("{d:?} not supported in {}", op.name())))
+        },
+    }
+}
+
+fn cumulative<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    op: ScanOp,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    let scanner = scanner(&data_type, op, skip_nulls)?;
+    unary_kernel(py, input, data_type, scanner)
+}
+
+/// Return the running sum of the input, returning an error on overflow.
+#[pyfunction]
+#[pyo3(signature = (input, *, skip_nulls=false))]
+pub fn cumulative_sum<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    cumulative(py, input, ScanOp::Sum, skip_nulls)
+}
+
+/// Return the running sum of the input, wrapping on overflow for integer data types.
+#[pyfunction]
+#[pyo3(signature = (input, *, skip_nulls=false))]
+pub fn cumulative_sum_wrapping<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    cumulative(py, input, ScanOp::SumWrapping, skip_nulls)
+}
+
+/// Return the running product of the input, returning an error on overflow.
+#[pyfunction]
+#[pyo3(signature = (input, *, skip_nulls=false))]
+pub fn cumulative_prod<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    cumulative(py, input, ScanOp::Prod, skip_nulls)
+}
+
+/// Return the running product of the input, wrapping on overflow for integer data types.
+#[pyfunction]
+#[pyo3(signature = (input, *, skip_nulls=false))]
+pub fn cumulative_prod_wrapping<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    cumulative(py, input, ScanOp::ProdWrapping, skip_nulls)
+}
+
+/// Return the running minimum of the input.
+#[pyfunction]
+#[pyo3(signature = (input, *, skip_nulls=false))]
+pub fn cumulative_min<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    cumulative(py, input, ScanOp::Min, skip_nulls)
+}
+
+/// Return the running maximum of the input.
+#[pyfunction]
+#[pyo3(signature = (input, *, skip_nulls=false))]
+pub fn cumulative_max<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    cumulative(py, input, ScanOp::Max, skip_nulls)
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{new_null_array, Array, ArrayRef, Int64Array, StructArray};
+use arrow_row::{OwnedRow, Row, RowConverter, Rows, SortField};
+use arrow_schema::{ArrowError, DataType, Field, FieldRef};
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3Array, Arro3Scalar};
+use pyo3_arrow::input::AnyArray;
+use pyo3_arrow::{PyArray, PyScalar};
+
+/// Counts the occurrences of each distinct value, in order of first appearance.
+///
+/// Values are hashed in their row-encoded form, so any type supported by [RowConverter] can be
+/// counted. Dictionary-encoded chunks are counted by key, so that each dictionary value is only
+/// hashed once per chunk.
+struct ValueCounter {
+    /// The type of the input, which may be a dictionary
+    data_type: DataType,
+    /// A converter for the value type of the input
+    converter: RowConverter,
+    index: HashMap<Box<[u8]>, usize>,
+    distinct: Rows,
+    counts: Vec<i64>,
+    null_row: OwnedRow,
+}
+
+impl ValueCounter {
+    fn try_new(data_type: &DataType) -> Result<Self, ArrowError> {
+        let value_type = match data_type {
+            DataType::Dictionary(_, value_type) => value_type.as_ref(),
+            data_type => data_type,
+        };
+        let converter = RowConverter::new(vec![SortField::new(value_type.clone())])?;
+        let null_row = converter
+            .convert_columns(&[new_null_array(value_type, 1)])?
+            .row(0)
+            .owned();
+        let distinct = converter.empty_rows(0, 0);
+        Ok(Self {
+            data_type: data_type.clone(),
+            converter,
+            index: HashMap::new(),
+            distinct,
+            counts: vec![],
+            null_row,
+        })
+    }
+
+    fn add(&mut self, row: Row<'_>, count: i64) {
+        match self.index.get(row.as_ref()) {
+            Some(i) => self.counts[*i] += count,
+            None => {
+                self.index.insert(row.as_ref().into(), self.counts.len());
+                self.distinct.push(row);
+                self.counts.push(count);
+            }
+        }
+    }
+
+    fn update(&mut self, array: &ArrayRef) -> Result<(), ArrowError> {
+        if let Some(dict) = array.as_any_dictionary_opt() {
+            let mut key_counts = vec![0; dict.values().len()];
+            // The first row of each key, and of nulls, so that values are added in order of
+            // first appearance rather than dictionary order
+            let mut first_rows = vec![0; dict.values().len()];
+            let mut null_count = 0;
+            let mut null_first_row = 0;
+            let key_nulls = dict.keys().logical_nulls();
+            for (i, key) in dict.normalized_keys().into_iter().enumerate() {
+                if key_nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i)) {
+                    if key_counts[key] == 0 {
+                        first_rows[key] = i;
+                    }
+                    key_counts[key] += 1;
+                } else {
+                    if null_count == 0 {
+                        null_first_row = i;
+                    }
+                    null_count += 1;
+                }
+            }
+
+            let mut order = key_counts
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                .map(|(key, _)| (first_rows[key], Some(key)))
+                .collect::<Vec<_>>();
+            if null_count > 0 {
+                order.push((null_first_row, None));
+            }
+            order.sort_unstable();
+
+            let rows = self.converter.convert_columns(&[dict.values().clone()])?;
+            for (_, key) in order {
+                match key {
+                    Some(key) => self.add(rows.row(key), key_counts[key]),
+                    None => {
+                        let null_row = self.null_row.clone();
+                        self.add(null_row.row(), null_count);
+                    }
+                }
+            }
+        } else {
+            let rows = self.converter.convert_columns(&[array.clone()])?;
+            for row in rows.iter() {
+                self.add(row, 1);
+            }
+        }
+        Ok(())
+    }
+
+    /// Count the distinct values of every chunk of `input`.
+    fn try_from_input(input: AnyArray) -> PyArrowResult<(Self, FieldRef)> {
+        match input {
+            AnyArray::Array(array) => {
+                let (array, field) = array.into_inner();
+                let mut counter = Self::try_new(field.data_type())?;
+                counter.update(&array)?;
+                Ok((counter, field))
+            }
+            AnyArray::Stream(stream) => {
+                let reader = stream.into_reader()?;
+                let field = reader.field();
+                let mut counter = Self::try_new(field.data_type())?;
+                for array in reader {
+                    counter.update(&array?)?;
+                }
+                Ok((counter, field))
+            }
+        }
+    }
+
+    /// The number of distinct non-null values.
+    fn count_distinct(&self) -> usize {
+        let has_null = self.index.contains_key(self.null_row.row().as_ref());
+        self.counts.len() - usize::from(has_null)
+    }
+
+    /// Return the distinct values, with the same type as the input, and their counts.
+    fn finish(self) -> Result<(ArrayRef, Vec<i64>), ArrowError> {
+        let values = self.converter.convert_rows(&self.distinct)?;
+        let values = arrow_cast::cast(&values[0], &self.data_type)?;
+        Ok((values, self.counts))
+    }
+}
+
+/// Return the distinct values of the input, including null if present, in order of first
+/// appearance.
+#[pyfunction]
+pub fn unique(input: AnyArray) -> PyArrowResult<Arro3Array> {
+    let (counter, field) = ValueCounter::try_from_input(input)?;
+    let (values, _counts) = counter.finish()?;
+    Ok(PyArray::try_new(values, field)?.into())
+}
+
+/// Return a Struct array of the distinct values of the input and the number of times each
+/// occurs, in order of first appearance.
+#[pyfunction]
+pub fn value_counts(input: AnyArray) -> PyArrowResult<Arro3Array> {
+    let (counter, field) = ValueCounter::try_from_input(input)?;
+    let (values, counts) = counter.finish()?;
+    let fields = vec![
+        Field::new("values", field.data_type().clone(), true),
+        Field::new("counts", DataType::Int64, true),
+    ];
+    let columns = vec![values, Arc::new(Int64Array::from(counts)) as ArrayRef];
+    Ok(PyArray::from_array_ref(Arc::new(StructArray::try_new(
+        fields.into(),
+        columns,
+        None,
+    )?))
+    .into())
+}
+
+/// Return the number of distinct non-null values of the input.
+#[pyfunction]
+pub fn count_distinct(input: AnyArray) -> PyArrowResult<Arro3Scalar> {
+    let (counter, _field) = ValueCounter::try_from_input(input)?;
+    let count = Int64Array::from(vec![counter.count_distinct() as i64]);
+    let field = Arc::new(Field::new("", DataType::Int64, false));
+    Ok(PyScalar::try_new(Arc::new(count), field)?.into())
+}
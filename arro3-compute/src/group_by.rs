@@ -0,0 +1,463 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Decimal128Type, Decimal256Type, Float64Type, Int64Type, UInt64Type};
+use arrow_array::{
+    new_null_array, Array, ArrayRef, ArrowNativeTypeOp, ArrowPrimitiveType, Float64Array,
+    Int64Array, PrimitiveArray, RecordBatch,
+};
+use arrow_row::{OwnedRow, RowConverter, Rows, SortField};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::Arro3Table;
+use pyo3_arrow::input::AnyRecordBatch;
+use pyo3_arrow::PyTable;
+
+use crate::utils::ColumnRef;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Aggregation {
+    /// The number of valid values in each group
+    Count,
+    /// The number of rows in each group, including nulls
+    CountAll,
+    /// The sum of valid values in each group
+    Sum,
+    /// The arithmetic mean of valid values in each group
+    Mean,
+    /// The smallest valid value in each group
+    Min,
+    /// The largest valid value in each group
+    Max,
+}
+
+impl<'py> FromPyObject<'_, 'py> for Aggregation {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "count" => Ok(Self::Count),
+            "count_all" => Ok(Self::CountAll),
+            "sum" => Ok(Self::Sum),
+            "mean" => Ok(Self::Mean),
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            _ => Err(PyValueError::new_err("Unexpected aggregation")),
+        }
+    }
+}
+
+impl Aggregation {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Count => "count",
+            Self::CountAll => "count_all",
+            Self::Sum => "sum",
+            Self::Mean => "mean",
+            Self::Min => "min",
+            Self::Max => "max",
+        }
+    }
+
+    /// Create an empty accumulator for this aggregation over values of type `data_type`.
+    fn accumulator(&self, data_type: &DataType) -> Result<Box<dyn GroupAccumulator>, ArrowError> {
+        let value_type = match data_type {
+            DataType::Dictionary(_, value_type) => value_type.as_ref(),
+            data_type => data_type,
+        };
+        let check_numeric = || {
+            if value_type.is_numeric() {
+                Ok(())
+            } else {
+                Err(ArrowError::ComputeError(format!(
+                    "{} not supported for {value_type}",
+                    self.name()
+                )))
+            }
+        };
+
+        match self {
+            Self::Count => Ok(Box::new(CountAccumulator::new(false))),
+            Self::CountAll => Ok(Box::new(CountAccumulator::new(true))),
+            Self::Sum => {
+                check_numeric()?;
+                match value_type {
+                    DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                        Ok(Box::new(SumAccumulator::<Int64Type>::new(DataType::Int64)))
+                    }
+                    DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => Ok(
+                        Box::new(SumAccumulator::<UInt64Type>::new(DataType::UInt64)),
+                    ),
+                    DataType::Decimal128(_, scale) => Ok(Box::new(
+                        SumAccumulator::<Decimal128Type>::new(DataType::Decimal128(38, *scale)),
+                    )),
+                    DataType::Decimal256(_, scale) => Ok(Box::new(
+                        SumAccumulator::<Decimal256Type>::new(DataType::Decimal256(76, *scale)),
+                    )),
+                    _ => Ok(Box::new(SumAccumulator::<Float64Type>::new(
+                        DataType::Float64,
+                    ))),
+                }
+            }
+            Self::Mean => {
+                check_numeric()?;
+                Ok(Box::new(MeanAccumulator::default()))
+            }
+            Self::Min => Ok(Box::new(MinMaxAccumulator::try_new(data_type, false)?)),
+            Self::Max => Ok(Box::new(MinMaxAccumulator::try_new(data_type, true)?)),
+        }
+    }
+}
+
+/// One or more aggregations to apply to a single column.
+#[derive(FromPyObject)]
+enum AggregationList {
+    One(Aggregation),
+    Many(Vec<Aggregation>),
+}
+
+impl AggregationList {
+    fn into_vec(self) -> Vec<Aggregation> {
+        match self {
+            Self::One(agg) => vec![agg],
+            Self::Many(aggs) => aggs,
+        }
+    }
+}
+
+/// The running state of one aggregation across all groups.
+trait GroupAccumulator: Send {
+    /// Update the state with `values`, where `group_ids[i]` is the group of row `i`.
+    ///
+    /// `num_groups` is the total number of groups seen so far, which may be more than were seen
+    /// in any previous call.
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        group_ids: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError>;
+
+    /// Produce the output array, with one row per group.
+    fn finish(self: Box<Self>) -> Result<ArrayRef, ArrowError>;
+}
+
+struct CountAccumulator {
+    counts: Vec<i64>,
+    count_nulls: bool,
+}
+
+impl CountAccumulator {
+    fn new(count_nulls: bool) -> Self {
+        Self {
+            counts: vec![],
+            count_nulls,
+        }
+    }
+}
+
+impl GroupAccumulator for CountAccumulator {
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        group_ids: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError> {
+        self.counts.resize(num_groups, 0);
+        let nulls = values.logical_nulls();
+        for (i, group) in group_ids.iter().enumerate() {
+            if self.count_nulls || nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i)) {
+                self.counts[*group] += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<ArrayRef, ArrowError> {
+        Ok(Arc::new(Int64Array::from(self.counts)))
+    }
+}
+
+/// Sums values after casting them to a wide type: Int64, UInt64, Float64, Decimal128(38, _) or
+/// Decimal256(76, _).
+struct SumAccumulator<T: ArrowPrimitiveType> {
+    sums: Vec<Option<T::Native>>,
+    data_type: DataType,
+}
+
+impl<T: ArrowPrimitiveType> SumAccumulator<T> {
+    fn new(data_type: DataType) -> Self {
+        Self {
+            sums: vec![],
+            data_type,
+        }
+    }
+}
+
+impl<T: ArrowPrimitiveType> GroupAccumulator for SumAccumulator<T> {
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        group_ids: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError> {
+        self.sums.resize(num_groups, None);
+        let values = arrow_cast::cast(values, &self.data_type)?;
+        let values = values.as_primitive::<T>();
+        for (i, group) in group_ids.iter().enumerate() {
+            if values.is_valid(i) {
+                let value = values.value(i);
+                self.sums[*group] = Some(match self.sums[*group] {
+                    Some(sum) => sum.add_checked(value)?,
+                    None => value,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<ArrayRef, ArrowError> {
+        let array = PrimitiveArray::<T>::from_iter(self.sums).with_data_type(self.data_type);
+        Ok(Arc::new(array))
+    }
+}
+
+#[derive(Default)]
+struct MeanAccumulator {
+    sums: Vec<f64>,
+    counts: Vec<u64>,
+}
+
+impl GroupAccumulator for MeanAccumulator {
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        group_ids: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError> {
+        self.sums.resize(num_groups, 0.0);
+        self.counts.resize(num_groups, 0);
+        let values = arrow_cast::cast(values, &DataType::Float64)?;
+        let values = values.as_primitive::<Float64Type>();
+        for (i, group) in group_ids.iter().enumerate() {
+            if values.is_valid(i) {
+                self.sums[*group] += values.value(i);
+                self.counts[*group] += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<ArrayRef, ArrowError> {
+        let array = self
+            .sums
+            .iter()
+            .zip(self.counts.iter())
+            .map(|(sum, count)| (*count > 0).then(|| sum / *count as f64))
+            .collect::<Float64Array>();
+        Ok(Arc::new(array))
+    }
+}
+
+/// Tracks the smallest or largest value of each group, in their row-encoded form.
+///
+/// Comparing row-encoded values means that any type supported by [RowConverter], including
+/// strings and dictionaries, can be aggregated.
+struct MinMaxAccumulator {
+    converter: RowConverter,
+    best: Vec<Option<OwnedRow>>,
+    data_type: DataType,
+    max: bool,
+}
+
+impl MinMaxAccumulator {
+    fn try_new(data_type: &DataType, max: bool) -> Result<Self, ArrowError> {
+        Ok(Self {
+            converter: RowConverter::new(vec![SortField::new(data_type.clone())])?,
+            best: vec![],
+            data_type: data_type.clone(),
+            max,
+        })
+    }
+}
+
+impl GroupAccumulator for MinMaxAccumulator {
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        group_ids: &[usize],
+        num_groups: usize,
+    ) -> Result<(), ArrowError> {
+        self.best.resize(num_groups, None);
+        let rows = self.converter.convert_columns(&[values.clone()])?;
+        let nulls = values.logical_nulls();
+        for (i, group) in group_ids.iter().enumerate() {
+            if !nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i)) {
+                continue;
+            }
+            let row = rows.row(i);
+            let replace = match &self.best[*group] {
+                Some(best) if self.max => row > best.row(),
+                Some(best) => row < best.row(),
+                None => true,
+            };
+            if replace {
+                self.best[*group] = Some(row.owned());
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<ArrayRef, ArrowError> {
+        let null_rows = self
+            .converter
+            .convert_columns(&[new_null_array(&self.data_type, 1)])?;
+        let mut rows = self.converter.empty_rows(self.best.len(), 0);
+        for best in self.best.iter() {
+            rows.push(best.as_ref().map_or(null_rows.row(0), |best| best.row()));
+        }
+        let columns = self.converter.convert_rows(&rows)?;
+        arrow_cast::cast(&columns[0], &self.data_type)
+    }
+}
+
+/// Assigns a dense group id to each distinct combination of key values.
+struct Grouper {
+    converter: RowConverter,
+    group_ids: HashMap<Box<[u8]>, usize>,
+    group_rows: Rows,
+}
+
+impl Grouper {
+    fn try_new(key_fields: &[&Field]) -> Result<Self, ArrowError> {
+        let converter = RowConverter::new(
+            key_fields
+                .iter()
+                .map(|field| SortField::new(field.data_type().clone()))
+                .collect(),
+        )?;
+        let group_rows = converter.empty_rows(0, 0);
+        Ok(Self {
+            converter,
+            group_ids: HashMap::new(),
+            group_rows,
+        })
+    }
+
+    fn num_groups(&self) -> usize {
+        self.group_rows.num_rows()
+    }
+
+    /// Return the group id of each row of `keys`, registering any new groups.
+    fn update(&mut self, keys: &[ArrayRef]) -> Result<Vec<usize>, ArrowError> {
+        let rows = self.converter.convert_columns(keys)?;
+        let mut ids = Vec::with_capacity(rows.num_rows());
+        for row in rows.iter() {
+            let id = match self.group_ids.get(row.as_ref()) {
+                Some(id) => *id,
+                None => {
+                    let id = self.group_rows.num_rows();
+                    self.group_rows.push(row);
+                    self.group_ids.insert(row.as_ref().into(), id);
+                    id
+                }
+            };
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
+    /// Decode the distinct keys, one row per group in order of first appearance.
+    fn finish(self, key_fields: &[&Field]) -> Result<Vec<ArrayRef>, ArrowError> {
+        self.converter
+            .convert_rows(&self.group_rows)?
+            .iter()
+            .zip(key_fields)
+            .map(|(column, field)| arrow_cast::cast(column, field.data_type()))
+            .collect()
+    }
+}
+
+/// Group tabular input by one or more key columns and compute aggregations within each group.
+///
+/// Stream input is consumed one batch at a time, so only the group keys and the aggregation
+/// state are held in memory. Groups are returned in order of first appearance.
+#[pyfunction]
+#[pyo3(signature = (input, keys, aggs=None))]
+pub fn group_by<'py>(
+    input: AnyRecordBatch,
+    keys: Vec<ColumnRef>,
+    aggs: Option<Bound<'py, PyDict>>,
+) -> PyArrowResult<Arro3Table> {
+    if keys.is_empty() {
+        return Err(PyValueError::new_err("At least one key column must be provided").into());
+    }
+
+    let reader = input.into_reader()?;
+    let schema = reader.schema();
+    let fields = schema.fields();
+
+    let key_positions = keys
+        .into_iter()
+        .map(|key| key.into_position(fields))
+        .collect::<PyResult<Vec<_>>>()?;
+    let key_fields = key_positions
+        .iter()
+        .map(|position| fields[*position].as_ref())
+        .collect::<Vec<_>>();
+
+    let mut output_fields = key_fields
+        .iter()
+        .map(|field| (*field).clone())
+        .collect::<Vec<_>>();
+    let mut agg_positions = vec![];
+    let mut accumulators = vec![];
+    if let Some(aggs) = aggs {
+        for (column, agg_list) in aggs.iter() {
+            let position = column.extract::<ColumnRef>()?.into_position(fields)?;
+            let field = &fields[position];
+            for agg in agg_list.extract::<AggregationList>()?.into_vec() {
+                accumulators.push(agg.accumulator(field.data_type())?);
+                agg_positions.push(position);
+                output_fields.push(Field::new(
+                    format!("{}_{}", field.name(), agg.name()),
+                    DataType::Null,
+                    true,
+                ));
+            }
+        }
+    }
+
+    let mut grouper = Grouper::try_new(&key_fields)?;
+    for batch in reader {
+        let batch = batch?;
+        let keys = key_positions
+            .iter()
+            .map(|position| batch.column(*position).clone())
+            .collect::<Vec<_>>();
+        let group_ids = grouper.update(&keys)?;
+        let num_groups = grouper.num_groups();
+        for (accumulator, position) in accumulators.iter_mut().zip(agg_positions.iter()) {
+            accumulator.update(batch.column(*position), &group_ids, num_groups)?;
+        }
+    }
+
+    let mut columns = grouper.finish(&key_fields)?;
+    for accumulator in accumulators {
+        columns.push(accumulator.finish()?);
+    }
+    // The output type of each aggregation is only known once it has been computed
+    let output_fields = output_fields
+        .into_iter()
+        .zip(columns.iter())
+        .map(|(field, column)| field.with_data_type(column.data_type().clone()))
+        .collect::<Vec<_>>();
+    let output_schema = Arc::new(Schema::new(output_fields));
+    let batch = RecordBatch::try_new(output_schema.clone(), columns)?;
+    Ok(PyTable::try_new(vec![batch], output_schema)?.into())
+}
@@ -0,0 +1,439 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{
+    new_null_array, Array, ArrayRef, RecordBatch, RecordBatchIterator, RecordBatchOptions,
+    RecordBatchReader, UInt64Array,
+};
+use arrow_buffer::NullBuffer;
+use arrow_row::{RowConverter, SortField};
+use arrow_schema::{ArrowError, DataType, Schema, SchemaRef};
+use arrow_select::take::take;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3RecordBatchReader, Arro3Table};
+use pyo3_arrow::input::AnyRecordBatch;
+use pyo3_arrow::PyTable;
+
+use crate::utils::{cast_strict, common_type, ColumnRef};
+
+#[derive(Debug, Clone, Copy)]
+pub enum JoinType {
+    /// Only rows with a matching key on both sides
+    Inner,
+    /// All left rows, with nulls for right columns where there is no match
+    Left,
+    /// All right rows, with nulls for left columns where there is no match
+    Right,
+    /// All rows from both sides
+    Full,
+    /// Left rows that have a match on the right, with only the left columns
+    Semi,
+    /// Left rows that have no match on the right, with only the left columns
+    Anti,
+}
+
+impl<'py> FromPyObject<'_, 'py> for JoinType {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "inner" => Ok(Self::Inner),
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            "full" | "outer" => Ok(Self::Full),
+            "semi" => Ok(Self::Semi),
+            "anti" => Ok(Self::Anti),
+            _ => Err(PyValueError::new_err("Unexpected join type")),
+        }
+    }
+}
+
+impl JoinType {
+    fn emits_right_columns(&self) -> bool {
+        !matches!(self, Self::Semi | Self::Anti)
+    }
+
+    fn left_nullable(&self) -> bool {
+        matches!(self, Self::Right | Self::Full)
+    }
+
+    fn right_nullable(&self) -> bool {
+        matches!(self, Self::Left | Self::Full)
+    }
+
+    /// Whether right rows without a match are emitted, taking their key values from the right.
+    fn emits_unmatched_right(&self) -> bool {
+        matches!(self, Self::Right | Self::Full)
+    }
+}
+
+/// One or more join key columns.
+#[derive(FromPyObject)]
+enum JoinKeys {
+    One(ColumnRef),
+    Many(Vec<ColumnRef>),
+}
+
+impl JoinKeys {
+    fn into_positions(self, schema: &Schema) -> PyResult<Vec<usize>> {
+        let keys = match self {
+            Self::One(key) => vec![key],
+            Self::Many(keys) => keys,
+        };
+        if keys.is_empty() {
+            return Err(PyValueError::new_err(
+                "At least one key column must be provided",
+            ));
+        }
+        keys.into_iter()
+            .map(|key| key.into_position(schema.fields()))
+            .collect()
+    }
+}
+
+/// The validity of each row of `keys`, where a row is null if any of its key values is null.
+fn key_validity(keys: &[ArrayRef]) -> Option<NullBuffer> {
+    keys.iter().fold(None, |validity, key| {
+        NullBuffer::union(validity.as_ref(), key.logical_nulls().as_ref())
+    })
+}
+
+/// Build the output schema of a join, appending suffixes to column names that appear on both
+/// sides. Left key columns keep their name, and right key columns are omitted.
+///
+/// If unmatched right rows are emitted, the key columns hold right key values as well, so they
+/// take the common type of the left and right keys, `key_types`.
+fn join_schema(
+    left: &Schema,
+    right: &Schema,
+    left_keys: &[usize],
+    key_types: &[DataType],
+    right_output: &[usize],
+    how: JoinType,
+    (left_suffix, right_suffix): (&str, &str),
+) -> SchemaRef {
+    let is_conflict = |name: &String| {
+        left.fields().iter().any(|field| field.name() == name)
+            && right_output
+                .iter()
+                .any(|position| right.field(*position).name() == name)
+    };
+
+    let mut fields = left
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(position, field)| {
+            let mut field = field.as_ref().clone();
+            match left_keys.iter().position(|key| *key == position) {
+                Some(key) if how.emits_unmatched_right() => {
+                    field = field.with_data_type(key_types[key].clone());
+                }
+                Some(_) => {}
+                None if is_conflict(field.name()) => {
+                    field = field.with_name(format!("{}{left_suffix}", field.name()));
+                }
+                None => {}
+            }
+            let nullable = field.is_nullable() || how.left_nullable();
+            field.with_nullable(nullable)
+        })
+        .collect::<Vec<_>>();
+    if how.emits_right_columns() {
+        fields.extend(right_output.iter().map(|position| {
+            let mut field = right.field(*position).clone();
+            if is_conflict(field.name()) {
+                field = field.with_name(format!("{}{right_suffix}", field.name()));
+            }
+            let nullable = field.is_nullable() || how.right_nullable();
+            field.with_nullable(nullable)
+        }));
+    }
+    Arc::new(Schema::new_with_metadata(fields, left.metadata().clone()))
+}
+
+/// Probes each batch of a left stream against a hash table built from the right input.
+struct HashJoinIterator {
+    left: Box<dyn RecordBatchReader + Send>,
+    left_keys: Vec<usize>,
+    right: RecordBatch,
+    /// The common type of each pair of left and right key columns
+    key_types: Vec<DataType>,
+    /// The right key columns, cast to `key_types`
+    right_keys: Vec<ArrayRef>,
+    /// The positions of the right columns included in the output
+    right_output: Vec<usize>,
+    converter: RowConverter,
+    /// Maps each row-encoded key to the right rows with that key
+    table: HashMap<Box<[u8]>, Vec<usize>>,
+    /// Whether each right row has matched any left row so far
+    matched: Vec<bool>,
+    how: JoinType,
+    schema: SchemaRef,
+    finished: bool,
+}
+
+impl HashJoinIterator {
+    fn try_new(
+        left: Box<dyn RecordBatchReader + Send>,
+        left_keys: Vec<usize>,
+        right: RecordBatch,
+        right_key_positions: &[usize],
+        how: JoinType,
+        suffixes: (&str, &str),
+    ) -> Result<Self, ArrowError> {
+        let left_schema = left.schema();
+        let key_types = left_keys
+            .iter()
+            .zip(right_key_positions)
+            .map(|(left_position, right_position)| {
+                let left_field = left_schema.field(*left_position);
+                let right_type = right
+                    .schema_ref()
+                    .field(*right_position)
+                    .data_type()
+                    .clone();
+                common_type(left_field.data_type(), &right_type).ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "Cannot join key {} of type {} with {right_type}",
+                        left_field.name(),
+                        left_field.data_type()
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let right_keys = right_key_positions
+            .iter()
+            .zip(key_types.iter())
+            .map(|(position, key_type)| cast_strict(right.column(*position), key_type))
+            .collect::<Result<Vec<_>, _>>()?;
+        let right_output = (0..right.num_columns())
+            .filter(|position| !right_key_positions.contains(position))
+            .collect::<Vec<_>>();
+        let schema = join_schema(
+            &left_schema,
+            &right.schema(),
+            &left_keys,
+            &key_types,
+            &right_output,
+            how,
+            suffixes,
+        );
+
+        let converter = RowConverter::new(
+            key_types
+                .iter()
+                .map(|key_type| SortField::new(key_type.clone()))
+                .collect(),
+        )?;
+        let rows = converter.convert_columns(&right_keys)?;
+        let validity = key_validity(&right_keys);
+        let mut table: HashMap<Box<[u8]>, Vec<usize>> = HashMap::new();
+        for (i, row) in rows.iter().enumerate() {
+            // Null keys never compare equal, so they are never inserted
+            if validity
+                .as_ref()
+                .map_or(true, |validity| validity.is_valid(i))
+            {
+                table.entry(row.as_ref().into()).or_default().push(i);
+            }
+        }
+
+        Ok(Self {
+            left,
+            left_keys,
+            matched: vec![false; right.num_rows()],
+            right,
+            key_types,
+            right_keys,
+            right_output,
+            converter,
+            table,
+            how,
+            schema,
+            finished: false,
+        })
+    }
+
+    fn probe(&mut self, batch: RecordBatch) -> Result<RecordBatch, ArrowError> {
+        let keys = self
+            .left_keys
+            .iter()
+            .zip(self.key_types.iter())
+            .map(|(position, key_type)| cast_strict(batch.column(*position), key_type))
+            .collect::<Result<Vec<_>, _>>()?;
+        let rows = self.converter.convert_columns(&keys)?;
+        let validity = key_validity(&keys);
+
+        let mut left_indices = vec![];
+        let mut right_indices = vec![];
+        for i in 0..batch.num_rows() {
+            let matches = if validity
+                .as_ref()
+                .map_or(true, |validity| validity.is_valid(i))
+            {
+                self.table.get(rows.row(i).as_ref())
+            } else {
+                None
+            };
+            match (self.how, matches) {
+                (JoinType::Semi, Some(_)) | (JoinType::Anti, None) => left_indices.push(i as u64),
+                (JoinType::Semi | JoinType::Anti, _) => {}
+                (_, Some(matches)) => {
+                    for j in matches {
+                        left_indices.push(i as u64);
+                        right_indices.push(Some(*j as u64));
+                        self.matched[*j] = true;
+                    }
+                }
+                (JoinType::Left | JoinType::Full, None) => {
+                    left_indices.push(i as u64);
+                    right_indices.push(None);
+                }
+                (_, None) => {}
+            }
+        }
+
+        let left_indices = UInt64Array::from(left_indices);
+        let mut columns = batch
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(position, column)| {
+                match self.left_keys.iter().position(|key| *key == position) {
+                    // Key columns take the common key type, as in [Self::unmatched]
+                    Some(key) if self.how.emits_unmatched_right() => {
+                        take(keys[key].as_ref(), &left_indices, None)
+                    }
+                    _ => take(column.as_ref(), &left_indices, None),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if self.how.emits_right_columns() {
+            let right_indices = UInt64Array::from(right_indices);
+            for position in self.right_output.iter() {
+                columns.push(take(
+                    self.right.column(*position).as_ref(),
+                    &right_indices,
+                    None,
+                )?);
+            }
+        }
+        let options = RecordBatchOptions::new().with_row_count(Some(left_indices.len()));
+        RecordBatch::try_new_with_options(self.schema.clone(), columns, &options)
+    }
+
+    /// The right rows that never matched any left row, with null left columns other than the
+    /// key columns, which are taken from the right in the common key type.
+    fn unmatched(&self) -> Result<Option<RecordBatch>, ArrowError> {
+        let indices = UInt64Array::from_iter_values(
+            self.matched
+                .iter()
+                .enumerate()
+                .filter(|(_, matched)| !**matched)
+                .map(|(i, _)| i as u64),
+        );
+        if indices.is_empty() {
+            return Ok(None);
+        }
+
+        let left_schema = self.left.schema();
+        let mut columns = vec![];
+        for (position, field) in left_schema.fields().iter().enumerate() {
+            match self.left_keys.iter().position(|key| *key == position) {
+                Some(key) => columns.push(take(self.right_keys[key].as_ref(), &indices, None)?),
+                None => columns.push(new_null_array(field.data_type(), indices.len())),
+            }
+        }
+        for position in self.right_output.iter() {
+            columns.push(take(self.right.column(*position).as_ref(), &indices, None)?);
+        }
+        Ok(Some(RecordBatch::try_new(self.schema.clone(), columns)?))
+    }
+}
+
+impl Iterator for HashJoinIterator {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        match self.left.next() {
+            Some(batch) => Some(batch.and_then(|batch| self.probe(batch))),
+            None => {
+                self.finished = true;
+                match self.how {
+                    JoinType::Right | JoinType::Full => self.unmatched().transpose(),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// Join two tabular inputs on one or more key columns.
+///
+/// The right input is materialized into a hash table, while the left input is streamed one
+/// batch at a time.
+#[pyfunction]
+#[pyo3(signature = (left, right, on, how=JoinType::Inner, *, right_on=None, suffixes=(String::new(), "_right".to_string()), lazy=false))]
+#[allow(clippy::too_many_arguments)]
+pub fn join<'py>(
+    py: Python<'py>,
+    left: AnyRecordBatch,
+    right: AnyRecordBatch,
+    on: JoinKeys,
+    how: JoinType,
+    right_on: Option<JoinKeys>,
+    suffixes: (String, String),
+    lazy: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let left = left.into_reader()?;
+    let right = right.into_reader()?;
+    let left_schema = left.schema();
+    let right_schema = right.schema();
+
+    let left_keys = on.into_positions(&left_schema)?;
+    let right_keys = match right_on {
+        Some(right_on) => right_on.into_positions(&right_schema)?,
+        None => {
+            let names = left_keys
+                .iter()
+                .map(|position| ColumnRef::Name(left_schema.field(*position).name().clone()))
+                .collect();
+            JoinKeys::Many(names).into_positions(&right_schema)?
+        }
+    };
+    if left_keys.len() != right_keys.len() {
+        return Err(PyValueError::new_err(
+            "Left and right inputs must have the same number of key columns",
+        )
+        .into());
+    }
+
+    let right_batches = right.collect::<Result<Vec<_>, _>>()?;
+    let right = arrow_select::concat::concat_batches(&right_schema, &right_batches)?;
+    let iter = HashJoinIterator::try_new(
+        left,
+        left_keys,
+        right,
+        &right_keys,
+        how,
+        (&suffixes.0, &suffixes.1),
+    )?;
+    let schema = iter.schema.clone();
+
+    if lazy {
+        let reader: Box<dyn RecordBatchReader + Send> =
+            Box::new(RecordBatchIterator::new(iter, schema));
+        Ok(Arro3RecordBatchReader::from(reader).into_bound_py_any(py)?)
+    } else {
+        let batches = iter.collect::<Result<Vec<_>, _>>()?;
+        Ok(Arro3Table::from(PyTable::try_new(batches, schema)?).into_bound_py_any(py)?)
+    }
+}
@@ -2,13 +2,33 @@ use pyo3::prelude::*;
 
 mod aggregate;
 mod arith;
+mod bitwise;
 mod boolean;
 mod cast;
+mod comparison;
 mod concat;
+mod conditional;
+mod cumulative;
 mod dictionary;
+mod distinct;
 mod filter;
+mod group_by;
+mod join;
+mod math;
+mod null;
+mod pattern;
+mod rank;
+mod regexp;
+mod rolling;
+mod set_lookup;
+mod shift;
+mod sort;
+mod strings;
 mod take;
+mod tdigest;
 mod temporal;
+mod top_k;
+mod utils;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -43,9 +63,18 @@ fn _compute(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
 
     m.add_wrapped(wrap_pyfunction!(___version))?;
 
+    m.add_wrapped(wrap_pyfunction!(aggregate::arg_max))?;
+    m.add_wrapped(wrap_pyfunction!(aggregate::arg_min))?;
+    m.add_wrapped(wrap_pyfunction!(aggregate::count))?;
     m.add_wrapped(wrap_pyfunction!(aggregate::max))?;
+    m.add_wrapped(wrap_pyfunction!(aggregate::mean))?;
+    m.add_wrapped(wrap_pyfunction!(aggregate::median))?;
     m.add_wrapped(wrap_pyfunction!(aggregate::min))?;
+    m.add_wrapped(wrap_pyfunction!(aggregate::product))?;
+    m.add_wrapped(wrap_pyfunction!(aggregate::quantile))?;
+    m.add_wrapped(wrap_pyfunction!(aggregate::stddev))?;
     m.add_wrapped(wrap_pyfunction!(aggregate::sum))?;
+    m.add_wrapped(wrap_pyfunction!(aggregate::variance))?;
     m.add_wrapped(wrap_pyfunction!(arith::add_wrapping))?;
     m.add_wrapped(wrap_pyfunction!(arith::add))?;
     m.add_wrapped(wrap_pyfunction!(arith::div))?;
@@ -56,17 +85,133 @@ fn _compute(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(arith::rem))?;
     m.add_wrapped(wrap_pyfunction!(arith::sub_wrapping))?;
     m.add_wrapped(wrap_pyfunction!(arith::sub))?;
+    m.add_wrapped(wrap_pyfunction!(bitwise::bit_count))?;
+    m.add_wrapped(wrap_pyfunction!(bitwise::bitwise_and))?;
+    m.add_wrapped(wrap_pyfunction!(bitwise::bitwise_not))?;
+    m.add_wrapped(wrap_pyfunction!(bitwise::bitwise_or))?;
+    m.add_wrapped(wrap_pyfunction!(bitwise::bitwise_xor))?;
+    m.add_wrapped(wrap_pyfunction!(bitwise::shift_left))?;
+    m.add_wrapped(wrap_pyfunction!(bitwise::shift_right))?;
+    m.add_wrapped(wrap_pyfunction!(boolean::and_))?;
+    m.add_wrapped(wrap_pyfunction!(boolean::and_kleene))?;
+    m.add_wrapped(wrap_pyfunction!(boolean::and_not))?;
+    m.add_wrapped(wrap_pyfunction!(boolean::and_not_kleene))?;
+    m.add_wrapped(wrap_pyfunction!(boolean::invert))?;
     m.add_wrapped(wrap_pyfunction!(boolean::is_not_null))?;
     m.add_wrapped(wrap_pyfunction!(boolean::is_null))?;
+    m.add_wrapped(wrap_pyfunction!(boolean::or_))?;
+    m.add_wrapped(wrap_pyfunction!(boolean::or_kleene))?;
+    m.add_wrapped(wrap_pyfunction!(boolean::xor))?;
     m.add_wrapped(wrap_pyfunction!(cast::can_cast_types))?;
     m.add_wrapped(wrap_pyfunction!(cast::cast))?;
     m.add_wrapped(wrap_pyfunction!(cast::cast))?;
+    m.add_wrapped(wrap_pyfunction!(comparison::eq))?;
+    m.add_wrapped(wrap_pyfunction!(comparison::gt_eq))?;
+    m.add_wrapped(wrap_pyfunction!(comparison::gt))?;
+    m.add_wrapped(wrap_pyfunction!(comparison::lt_eq))?;
+    m.add_wrapped(wrap_pyfunction!(comparison::lt))?;
+    m.add_wrapped(wrap_pyfunction!(comparison::neq))?;
     m.add_wrapped(wrap_pyfunction!(concat::concat))?;
     m.add_wrapped(wrap_pyfunction!(concat::concat))?;
+    m.add_wrapped(wrap_pyfunction!(conditional::case_when))?;
+    m.add_wrapped(wrap_pyfunction!(conditional::choose))?;
+    m.add_wrapped(wrap_pyfunction!(conditional::if_else))?;
+    m.add_wrapped(wrap_pyfunction!(cumulative::cumulative_max))?;
+    m.add_wrapped(wrap_pyfunction!(cumulative::cumulative_min))?;
+    m.add_wrapped(wrap_pyfunction!(cumulative::cumulative_prod_wrapping))?;
+    m.add_wrapped(wrap_pyfunction!(cumulative::cumulative_prod))?;
+    m.add_wrapped(wrap_pyfunction!(cumulative::cumulative_sum_wrapping))?;
+    m.add_wrapped(wrap_pyfunction!(cumulative::cumulative_sum))?;
     m.add_wrapped(wrap_pyfunction!(dictionary::dictionary_encode))?;
+    m.add_wrapped(wrap_pyfunction!(distinct::count_distinct))?;
+    m.add_wrapped(wrap_pyfunction!(distinct::unique))?;
+    m.add_wrapped(wrap_pyfunction!(distinct::value_counts))?;
     m.add_wrapped(wrap_pyfunction!(filter::filter))?;
+    m.add_wrapped(wrap_pyfunction!(group_by::group_by))?;
+    m.add_wrapped(wrap_pyfunction!(join::join))?;
+    m.add_wrapped(wrap_pyfunction!(math::abs))?;
+    m.add_wrapped(wrap_pyfunction!(math::acos))?;
+    m.add_wrapped(wrap_pyfunction!(math::asin))?;
+    m.add_wrapped(wrap_pyfunction!(math::atan))?;
+    m.add_wrapped(wrap_pyfunction!(math::ceil))?;
+    m.add_wrapped(wrap_pyfunction!(math::cos))?;
+    m.add_wrapped(wrap_pyfunction!(math::exp))?;
+    m.add_wrapped(wrap_pyfunction!(math::floor))?;
+    m.add_wrapped(wrap_pyfunction!(math::ln))?;
+    m.add_wrapped(wrap_pyfunction!(math::log10))?;
+    m.add_wrapped(wrap_pyfunction!(math::log2))?;
+    m.add_wrapped(wrap_pyfunction!(math::pow))?;
+    m.add_wrapped(wrap_pyfunction!(math::round))?;
+    m.add_wrapped(wrap_pyfunction!(math::sign))?;
+    m.add_wrapped(wrap_pyfunction!(math::sin))?;
+    m.add_wrapped(wrap_pyfunction!(math::sqrt))?;
+    m.add_wrapped(wrap_pyfunction!(math::tan))?;
+    m.add_wrapped(wrap_pyfunction!(math::trunc))?;
+    m.add_wrapped(wrap_pyfunction!(null::coalesce))?;
+    m.add_wrapped(wrap_pyfunction!(null::drop_null))?;
+    m.add_wrapped(wrap_pyfunction!(null::fill_null_backward))?;
+    m.add_wrapped(wrap_pyfunction!(null::fill_null_forward))?;
+    m.add_wrapped(wrap_pyfunction!(null::fill_null))?;
+    m.add_wrapped(wrap_pyfunction!(pattern::contains))?;
+    m.add_wrapped(wrap_pyfunction!(pattern::ends_with))?;
+    m.add_wrapped(wrap_pyfunction!(pattern::ilike))?;
+    m.add_wrapped(wrap_pyfunction!(pattern::like))?;
+    m.add_wrapped(wrap_pyfunction!(pattern::regex_match))?;
+    m.add_wrapped(wrap_pyfunction!(pattern::starts_with))?;
+    m.add_wrapped(wrap_pyfunction!(rank::dense_rank))?;
+    m.add_wrapped(wrap_pyfunction!(rank::percent_rank))?;
+    m.add_wrapped(wrap_pyfunction!(rank::rank))?;
+    m.add_wrapped(wrap_pyfunction!(rank::row_number))?;
+    m.add_wrapped(wrap_pyfunction!(regexp::regex_extract_all))?;
+    m.add_wrapped(wrap_pyfunction!(regexp::regex_extract_groups))?;
+    m.add_wrapped(wrap_pyfunction!(regexp::regex_extract))?;
+    m.add_wrapped(wrap_pyfunction!(regexp::regex_replace))?;
+    m.add_wrapped(wrap_pyfunction!(rolling::rolling_max))?;
+    m.add_wrapped(wrap_pyfunction!(rolling::rolling_mean))?;
+    m.add_wrapped(wrap_pyfunction!(rolling::rolling_min))?;
+    m.add_wrapped(wrap_pyfunction!(rolling::rolling_sum))?;
+    m.add_wrapped(wrap_pyfunction!(set_lookup::index_in))?;
+    m.add_wrapped(wrap_pyfunction!(set_lookup::is_in))?;
+    m.add_wrapped(wrap_pyfunction!(shift::diff))?;
+    m.add_wrapped(wrap_pyfunction!(shift::pct_change))?;
+    m.add_wrapped(wrap_pyfunction!(shift::shift))?;
+    m.add_wrapped(wrap_pyfunction!(sort::sort_indices))?;
+    m.add_wrapped(wrap_pyfunction!(sort::sort))?;
+    m.add_wrapped(wrap_pyfunction!(strings::concat_ws))?;
+    m.add_wrapped(wrap_pyfunction!(strings::lower))?;
+    m.add_wrapped(wrap_pyfunction!(strings::lstrip))?;
+    m.add_wrapped(wrap_pyfunction!(strings::pad))?;
+    m.add_wrapped(wrap_pyfunction!(strings::reverse))?;
+    m.add_wrapped(wrap_pyfunction!(strings::rstrip))?;
+    m.add_wrapped(wrap_pyfunction!(strings::strip))?;
+    m.add_wrapped(wrap_pyfunction!(strings::substring))?;
+    m.add_wrapped(wrap_pyfunction!(strings::upper))?;
+    m.add_wrapped(wrap_pyfunction!(strings::utf8_length))?;
     m.add_wrapped(wrap_pyfunction!(take::take))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::add_interval))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::assume_timezone))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::ceil_temporal))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::convert_timezone))?;
     m.add_wrapped(wrap_pyfunction!(temporal::date_part))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::date_trunc))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::days_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::floor_temporal))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::hours_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::local_timestamp))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::microseconds_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::milliseconds_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::minutes_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::months_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::nanoseconds_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::quarters_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::round_temporal))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::seconds_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::strftime))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::strptime))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::weeks_between))?;
+    m.add_wrapped(wrap_pyfunction!(temporal::years_between))?;
+    m.add_wrapped(wrap_pyfunction!(top_k::bottom_k))?;
+    m.add_wrapped(wrap_pyfunction!(top_k::top_k))?;
 
     Ok(())
 }
@@ -0,0 +1,585 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Decimal128Type, Decimal256Type, DecimalType, Float64Type, Int64Type, Int8Type,
+};
+use arrow_array::{
+    downcast_integer, make_array, Array, ArrayRef, ArrowNativeTypeOp, ArrowPrimitiveType, Datum,
+    Float64Array, Int64Array,
+};
+use arrow_buffer::ArrowNativeType;
+use arrow_cast::CastOptions;
+use arrow_schema::{ArrowError, DataType, Field};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::input::AnyArray;
+
+use crate::utils::{
+    binary_datum_kernel, broadcast_len, cast_strict, unary_kernel, AnyDatumOrStream,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum RoundMode {
+    /// Round towards negative infinity
+    Down,
+    /// Round towards positive infinity
+    Up,
+    /// Round towards zero, i.e. truncate
+    TowardsZero,
+    /// Round away from zero
+    TowardsInfinity,
+    /// Round to nearest, with ties towards negative infinity
+    HalfDown,
+    /// Round to nearest, with ties towards positive infinity
+    HalfUp,
+    /// Round to nearest, with ties towards zero
+    HalfTowardsZero,
+    /// Round to nearest, with ties away from zero
+    HalfTowardsInfinity,
+    /// Round to nearest, with ties to the nearest even value
+    HalfToEven,
+    /// Round to nearest, with ties to the nearest odd value
+    HalfToOdd,
+}
+
+impl<'py> FromPyObject<'_, 'py> for RoundMode {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "down" => Ok(Self::Down),
+            "up" => Ok(Self::Up),
+            "towards_zero" => Ok(Self::TowardsZero),
+            "towards_infinity" => Ok(Self::TowardsInfinity),
+            "half_down" => Ok(Self::HalfDown),
+            "half_up" => Ok(Self::HalfUp),
+            "half_towards_zero" => Ok(Self::HalfTowardsZero),
+            "half_towards_infinity" => Ok(Self::HalfTowardsInfinity),
+            "half_to_even" => Ok(Self::HalfToEven),
+            "half_to_odd" => Ok(Self::HalfToOdd),
+            _ => Err(PyValueError::new_err("Unexpected round mode")),
+        }
+    }
+}
+
+/// One of the two candidates bracketing a value that is being rounded.
+enum Candidate {
+    /// The candidate nearer to zero
+    Truncated,
+    /// The candidate further from zero
+    Away,
+}
+
+impl RoundMode {
+    /// Choose between the two candidates bracketing a value.
+    ///
+    /// `distance` compares the distance from the value to the truncated candidate against its
+    /// distance to the other candidate, and `truncated_is_even` is whether the truncated candidate
+    /// is an even multiple of the rounding unit.
+    fn choose(self, negative: bool, distance: Ordering, truncated_is_even: bool) -> Candidate {
+        let down = if negative {
+            Candidate::Away
+        } else {
+            Candidate::Truncated
+        };
+        let up = if negative {
+            Candidate::Truncated
+        } else {
+            Candidate::Away
+        };
+        let even = if truncated_is_even {
+            Candidate::Truncated
+        } else {
+            Candidate::Away
+        };
+        let odd = if truncated_is_even {
+            Candidate::Away
+        } else {
+            Candidate::Truncated
+        };
+        match (self, distance) {
+            (Self::Down, _) => down,
+            (Self::Up, _) => up,
+            (Self::TowardsZero, _) => Candidate::Truncated,
+            (Self::TowardsInfinity, _) => Candidate::Away,
+            (_, Ordering::Less) => Candidate::Truncated,
+            (_, Ordering::Greater) => Candidate::Away,
+            (Self::HalfDown, Ordering::Equal) => down,
+            (Self::HalfUp, Ordering::Equal) => up,
+            (Self::HalfTowardsZero, Ordering::Equal) => Candidate::Truncated,
+            (Self::HalfTowardsInfinity, Ordering::Equal) => Candidate::Away,
+            (Self::HalfToEven, Ordering::Equal) => even,
+            (Self::HalfToOdd, Ordering::Equal) => odd,
+        }
+    }
+}
+
+/// Round `value` to an integer.
+fn round_f64(value: f64, mode: RoundMode) -> f64 {
+    let truncated = value.trunc();
+    if truncated == value || !value.is_finite() {
+        return value;
+    }
+    let distance = (value - truncated).abs().total_cmp(&0.5);
+    match mode.choose(value < 0.0, distance, truncated % 2.0 == 0.0) {
+        Candidate::Truncated => truncated,
+        Candidate::Away => truncated + value.signum(),
+    }
+}
+
+/// Round `value` to `ndigits` decimal places, where a negative `ndigits` rounds to a power of ten.
+fn round_float(value: f64, ndigits: i32, mode: RoundMode) -> f64 {
+    // Dividing by a power of ten, rather than multiplying by its inexact reciprocal, keeps
+    // results for negative `ndigits` exact
+    if ndigits >= 0 {
+        let scale = 10f64.powi(ndigits);
+        let scaled = value * scale;
+        if !scaled.is_finite() {
+            return value;
+        }
+        round_f64(scaled, mode) / scale
+    } else {
+        let scale = 10f64.powi(ndigits.saturating_neg());
+        if scale.is_infinite() && value.is_finite() {
+            // Every finite value is less than half of a power of ten this large
+            return 0f64.copysign(value);
+        }
+        round_f64(value / scale, mode) * scale
+    }
+}
+
+/// Round `value` to a multiple of `multiple`, which must be positive.
+fn round_to_multiple<T: ArrowNativeTypeOp>(
+    value: T,
+    multiple: T,
+    mode: RoundMode,
+) -> Result<T, ArrowError> {
+    // The remainder takes the sign of the value, so subtracting it truncates towards zero
+    let remainder = value.mod_wrapping(multiple);
+    if remainder.is_eq(T::ZERO) {
+        return Ok(value);
+    }
+    let truncated = value.sub_wrapping(remainder);
+    let negative = value.is_lt(T::ZERO);
+    let remainder = if negative {
+        remainder.neg_wrapping()
+    } else {
+        remainder
+    };
+    let distance = remainder.compare(multiple.sub_wrapping(remainder));
+    let truncated_is_even = truncated
+        .div_wrapping(multiple)
+        .mod_wrapping(T::usize_as(2))
+        .is_eq(T::ZERO);
+    match mode.choose(negative, distance, truncated_is_even) {
+        Candidate::Truncated => Ok(truncated),
+        Candidate::Away if negative => truncated.sub_checked(multiple),
+        Candidate::Away => truncated.add_checked(multiple),
+    }
+}
+
+/// Round a decimal array to `ndigits` decimal places, keeping its precision and scale.
+///
+/// Returns an error if a rounded value no longer fits in the precision.
+fn round_decimal<T: DecimalType>(
+    array: &dyn Array,
+    ndigits: i64,
+    mode: RoundMode,
+) -> Result<ArrayRef, ArrowError> {
+    let (precision, scale) = match array.data_type() {
+        DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+            (*precision, *scale)
+        }
+        d => unreachable!("Unexpected decimal type {d}"),
+    };
+    let digits = scale as i64 - ndigits;
+    if digits <= 0 {
+        return Ok(make_array(array.to_data()));
+    }
+    // Every value is smaller in magnitude than 10^MAX_PRECISION, so larger multiples behave the
+    // same
+    let digits = digits.min(T::MAX_PRECISION as i64) as u32;
+    let multiple = T::Native::usize_as(10).pow_checked(digits)?;
+    let array = array.as_primitive::<T>();
+    let rounded = array
+        .try_unary::<_, T, ArrowError>(|value| round_to_multiple(value, multiple, mode))?
+        .with_data_type(array.data_type().clone());
+    rounded.validate_decimal_precision(precision)?;
+    Ok(Arc::new(rounded))
+}
+
+/// Apply `op` to each value of a floating-point array, keeping its type.
+fn float_unary(array: &dyn Array, op: impl Fn(f64) -> f64) -> Result<ArrayRef, ArrowError> {
+    let values = arrow_cast::cast(array, &DataType::Float64)?;
+    let out: ArrayRef = Arc::new(
+        values
+            .as_primitive::<Float64Type>()
+            .unary::<_, Float64Type>(op),
+    );
+    if array.data_type() == &DataType::Float64 {
+        Ok(out)
+    } else {
+        arrow_cast::cast(&out, array.data_type())
+    }
+}
+
+fn is_float(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Float16 | DataType::Float32 | DataType::Float64
+    )
+}
+
+fn check_numeric(data_type: &DataType, name: &str) -> PyResult<()> {
+    if data_type.is_numeric() {
+        Ok(())
+    } else {
+        Err(PyValueError::new_err(format!(
+            "{data_type} not supported in {name}"
+        )))
+    }
+}
+
+fn round_array(array: &dyn Array, ndigits: i64, mode: RoundMode) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        d if is_float(d) => {
+            let ndigits = ndigits.clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+            float_unary(array, |value| round_float(value, ndigits, mode))
+        }
+        DataType::Decimal128(_, _) => round_decimal::<Decimal128Type>(array, ndigits, mode),
+        DataType::Decimal256(_, _) => round_decimal::<Decimal256Type>(array, ndigits, mode),
+        d if d.is_integer() => {
+            if ndigits >= 0 {
+                return Ok(make_array(array.to_data()));
+            }
+            // Every integer type fits in a Decimal128 with scale 0
+            let decimal = arrow_cast::cast(array, &DataType::Decimal128(38, 0))?;
+            let rounded = round_decimal::<Decimal128Type>(decimal.as_ref(), ndigits, mode)?;
+            let options = CastOptions {
+                safe: false,
+                ..Default::default()
+            };
+            arrow_cast::cast_with_options(&rounded, d, &options)
+        }
+        d => Err(ArrowError::ComputeError(format!(
+            "{d} not supported in round"
+        ))),
+    }
+}
+
+fn round_with_mode<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    ndigits: i64,
+    mode: RoundMode,
+    name: &str,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    check_numeric(&data_type, name)?;
+    unary_kernel(py, input, data_type, move |array| {
+        round_array(array, ndigits, mode)
+    })
+}
+
+/// Round each value to `ndigits` decimal places.
+///
+/// Floats, integers and decimals keep their type, and decimals keep their precision and scale.
+#[pyfunction]
+#[pyo3(signature = (input, ndigits=0, *, mode=RoundMode::HalfToEven))]
+pub fn round<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    ndigits: i64,
+    mode: RoundMode,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    round_with_mode(py, input, ndigits, mode, "round")
+}
+
+/// Round each value down to the nearest integer.
+#[pyfunction]
+pub fn floor<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    round_with_mode(py, input, 0, RoundMode::Down, "floor")
+}
+
+/// Round each value up to the nearest integer.
+#[pyfunction]
+pub fn ceil<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    round_with_mode(py, input, 0, RoundMode::Up, "ceil")
+}
+
+/// Round each value towards zero to the nearest integer.
+#[pyfunction]
+pub fn trunc<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    round_with_mode(py, input, 0, RoundMode::TowardsZero, "trunc")
+}
+
+fn abs_primitive<T: ArrowPrimitiveType>(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>();
+    let out = array.try_unary::<_, T, ArrowError>(|value| {
+        if value.is_lt(T::Native::ZERO) {
+            value.neg_checked()
+        } else {
+            Ok(value)
+        }
+    })?;
+    Ok(Arc::new(out.with_data_type(array.data_type().clone())))
+}
+
+macro_rules! abs_helper {
+    ($t:ty, $array:expr) => {
+        abs_primitive::<$t>($array)
+    };
+}
+
+fn abs_array(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    downcast_integer! {
+        array.data_type() => (abs_helper, array),
+        DataType::Decimal128(_, _) => abs_primitive::<Decimal128Type>(array),
+        DataType::Decimal256(_, _) => abs_primitive::<Decimal256Type>(array),
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => float_unary(array, f64::abs),
+        d => Err(ArrowError::ComputeError(format!("{d} not supported in abs")))
+    }
+}
+
+/// Return the absolute value of each value, returning an error on overflow.
+#[pyfunction]
+pub fn abs<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    check_numeric(&data_type, "abs")?;
+    unary_kernel(py, input, data_type, abs_array)
+}
+
+fn sign_primitive<T: ArrowPrimitiveType>(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    let out = array.as_primitive::<T>().unary::<_, Int8Type>(|value| {
+        match value.compare(T::Native::ZERO) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    });
+    Ok(Arc::new(out))
+}
+
+macro_rules! sign_helper {
+    ($t:ty, $array:expr) => {
+        sign_primitive::<$t>($array)
+    };
+}
+
+fn sign_array(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    downcast_integer! {
+        array.data_type() => (sign_helper, array),
+        DataType::Decimal128(_, _) => sign_primitive::<Decimal128Type>(array),
+        DataType::Decimal256(_, _) => sign_primitive::<Decimal256Type>(array),
+        DataType::Float16 | DataType::Float32 | DataType::Float64 => float_unary(array, |value| {
+            if value == 0.0 || value.is_nan() {
+                value.abs()
+            } else {
+                value.signum()
+            }
+        }),
+        d => Err(ArrowError::ComputeError(format!("{d} not supported in sign")))
+    }
+}
+
+/// Return the sign of each value: -1, 0 or 1.
+///
+/// Integer and decimal input returns Int8. Float input keeps its type, and NaN stays NaN.
+#[pyfunction]
+pub fn sign<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    check_numeric(&data_type, "sign")?;
+    let output_type = if is_float(&data_type) {
+        data_type
+    } else {
+        DataType::Int8
+    };
+    unary_kernel(py, input, output_type, sign_array)
+}
+
+/// Apply a floating-point function to each value.
+///
+/// Float input keeps its type, and integer and decimal input returns Float64. Values outside
+/// the domain of `op` return NaN.
+fn float_math<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    op: fn(f64) -> f64,
+    name: &str,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    check_numeric(&data_type, name)?;
+    if is_float(&data_type) {
+        unary_kernel(py, input, data_type, move |array| float_unary(array, op))
+    } else {
+        unary_kernel(py, input, DataType::Float64, move |array| {
+            let values = arrow_cast::cast(array, &DataType::Float64)?;
+            Ok(Arc::new(
+                values
+                    .as_primitive::<Float64Type>()
+                    .unary::<_, Float64Type>(op),
+            ))
+        })
+    }
+}
+
+/// Return the square root of each value.
+#[pyfunction]
+pub fn sqrt<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::sqrt, "sqrt")
+}
+
+/// Return e raised to the power of each value.
+#[pyfunction]
+pub fn exp<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::exp, "exp")
+}
+
+/// Return the natural logarithm of each value.
+#[pyfunction]
+pub fn ln<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::ln, "ln")
+}
+
+/// Return the base-10 logarithm of each value.
+#[pyfunction]
+pub fn log10<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::log10, "log10")
+}
+
+/// Return the base-2 logarithm of each value.
+#[pyfunction]
+pub fn log2<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::log2, "log2")
+}
+
+/// Return the sine of each value, in radians.
+#[pyfunction]
+pub fn sin<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::sin, "sin")
+}
+
+/// Return the cosine of each value, in radians.
+#[pyfunction]
+pub fn cos<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::cos, "cos")
+}
+
+/// Return the tangent of each value, in radians.
+#[pyfunction]
+pub fn tan<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::tan, "tan")
+}
+
+/// Return the arcsine of each value, in radians.
+#[pyfunction]
+pub fn asin<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::asin, "asin")
+}
+
+/// Return the arccosine of each value, in radians.
+#[pyfunction]
+pub fn acos<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::acos, "acos")
+}
+
+/// Return the arctangent of each value, in radians.
+#[pyfunction]
+pub fn atan<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    float_math(py, input, f64::atan, "atan")
+}
+
+/// Raise `base` to the power of `exponent` element-wise, after casting both to `data_type`
+/// (Int64 or Float64).
+fn pow_datums(
+    base: &dyn Datum,
+    exponent: &dyn Datum,
+    data_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    let len = broadcast_len(&[base, exponent]);
+    let (base, base_is_scalar) = base.get();
+    let (exponent, exponent_is_scalar) = exponent.get();
+    for (array, is_scalar) in [(base, base_is_scalar), (exponent, exponent_is_scalar)] {
+        if !is_scalar && array.len() != len {
+            return Err(ArrowError::ComputeError(format!(
+                "Expected all arrays to have length {len}, got {}",
+                array.len()
+            )));
+        }
+    }
+    let base = cast_strict(base, data_type)?;
+    let exponent = cast_strict(exponent, data_type)?;
+    // Index of row `i` in an input that may be a broadcast scalar
+    let base_index = |i: usize| if base_is_scalar { 0 } else { i };
+    let exponent_index = |i: usize| if exponent_is_scalar { 0 } else { i };
+
+    if data_type == &DataType::Int64 {
+        let (base, exponent) = (
+            base.as_primitive::<Int64Type>(),
+            exponent.as_primitive::<Int64Type>(),
+        );
+        let out = (0..len)
+            .map(|i| {
+                let (b, e) = (base_index(i), exponent_index(i));
+                if base.is_null(b) || exponent.is_null(e) {
+                    return Ok(None);
+                }
+                let exponent = u32::try_from(exponent.value(e)).map_err(|_| {
+                    ArrowError::ComputeError(format!(
+                        "Integer exponent {} must be non-negative and at most {}",
+                        exponent.value(e),
+                        u32::MAX
+                    ))
+                })?;
+                Ok(Some(base.value(b).pow_checked(exponent)?))
+            })
+            .collect::<Result<Int64Array, ArrowError>>()?;
+        Ok(Arc::new(out))
+    } else {
+        let (base, exponent) = (
+            base.as_primitive::<Float64Type>(),
+            exponent.as_primitive::<Float64Type>(),
+        );
+        let out = (0..len)
+            .map(|i| {
+                let (b, e) = (base_index(i), exponent_index(i));
+                if base.is_null(b) || exponent.is_null(e) {
+                    None
+                } else {
+                    Some(base.value(b).powf(exponent.value(e)))
+                }
+            })
+            .collect::<Float64Array>();
+        Ok(Arc::new(out))
+    }
+}
+
+/// Raise `base` to the power of `exponent`.
+///
+/// If both inputs are integers, the result is Int64 and an error is returned on overflow, for a
+/// negative exponent, or for a UInt64 input that does not fit in Int64. Otherwise both inputs are
+/// cast to Float64.
+#[pyfunction]
+pub fn pow<'py>(
+    py: Python<'py>,
+    base: AnyDatumOrStream,
+    exponent: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let base_type = base.field()?.data_type().clone();
+    let exponent_type = exponent.field()?.data_type().clone();
+    check_numeric(&base_type, "pow")?;
+    check_numeric(&exponent_type, "pow")?;
+    let data_type = if base_type.is_integer() && exponent_type.is_integer() {
+        DataType::Int64
+    } else {
+        DataType::Float64
+    };
+    let output_field = Arc::new(Field::new("", data_type.clone(), true));
+    binary_datum_kernel(py, base, exponent, output_field, move |base, exponent| {
+        pow_datums(base, exponent, &data_type)
+    })
+}
@@ -0,0 +1,378 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow_arith::boolean::{and, is_not_null, or};
+use arrow_array::cast::AsArray;
+use arrow_array::{
+    make_array, new_null_array, Array, ArrayRef, BooleanArray, Datum, Scalar, UInt64Array,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+use arrow_select::filter::filter;
+use arrow_select::take::take;
+use arrow_select::zip::zip;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3Array, Arro3ArrayReader, Arro3RecordBatch, Arro3Table};
+use pyo3_arrow::ffi::{ArrayIterator, ArrayReader};
+use pyo3_arrow::input::AnyArray;
+use pyo3_arrow::{PyArray, PyArrayReader, PyTable};
+
+use crate::utils::{
+    binary_datum_kernel, broadcast_len, cast_strict, common_input_type, nary_datum_kernel,
+    struct_to_record_batch, unary_kernel, AnyDatumOrStream, ColumnRef,
+};
+
+/// Cast `fill` to `data_type`, raising if a value does not fit, or if a float value has a
+/// fractional part that would be dropped by a cast to an integer or decimal type.
+fn cast_fill(fill: &dyn Array, data_type: &DataType) -> Result<ArrayRef, ArrowError> {
+    let cast = cast_strict(fill, data_type)?;
+    if fill.data_type().is_floating() && data_type.is_numeric() && !data_type.is_floating() {
+        let round_trip = arrow_cast::cast(&cast, fill.data_type())?;
+        if arrow_ord::cmp::not_distinct(&round_trip, &fill)?.false_count() > 0 {
+            return Err(ArrowError::ComputeError(format!(
+                "Fill value of type {} cannot be cast to {data_type} without losing precision",
+                fill.data_type()
+            )));
+        }
+    }
+    Ok(cast)
+}
+
+/// Replace the nulls of `values` with `fill`, which is cast to the type of `values` with
+/// [cast_fill].
+///
+/// `fill` may be a scalar, which is broadcast, or an array of the same length as `values`.
+fn fill_null_datum(values: &dyn Array, fill: &dyn Datum) -> Result<ArrayRef, ArrowError> {
+    if values.logical_null_count() == 0 {
+        return Ok(make_array(values.to_data()));
+    }
+    let (fill, fill_is_scalar) = fill.get();
+    let fill = cast_fill(fill, values.data_type())?;
+    let mask = is_not_null(values)?;
+    if fill_is_scalar {
+        zip(&mask, &values, &Scalar::new(fill))
+    } else {
+        zip(&mask, &values, &fill)
+    }
+}
+
+/// Replace each null of `array` with the nearest preceding (if `forward`) or following valid
+/// value within the same array.
+///
+/// Nulls with no such value, i.e. leading nulls when filling forward or trailing nulls when
+/// filling backward, are left as null.
+fn fill_within_chunk(array: &dyn Array, forward: bool) -> Result<ArrayRef, ArrowError> {
+    let Some(nulls) = array.logical_nulls() else {
+        return Ok(make_array(array.to_data()));
+    };
+    let mut indices = vec![None; array.len()];
+    let mut last_valid = None;
+    let positions: Box<dyn Iterator<Item = usize>> = if forward {
+        Box::new(0..array.len())
+    } else {
+        Box::new((0..array.len()).rev())
+    };
+    for i in positions {
+        if nulls.is_valid(i) {
+            last_valid = Some(i as u64);
+        }
+        indices[i] = last_valid;
+    }
+    take(array, &UInt64Array::from(indices), None)
+}
+
+/// The position of the first (or last) valid value of `array`, if any.
+fn find_valid(array: &dyn Array, first: bool) -> Option<usize> {
+    let Some(nulls) = array.logical_nulls() else {
+        return if array.is_empty() {
+            None
+        } else if first {
+            Some(0)
+        } else {
+            Some(array.len() - 1)
+        };
+    };
+    if first {
+        nulls.valid_indices().next()
+    } else {
+        nulls.valid_indices().last()
+    }
+}
+
+/// Backward-fill a stream of arrays.
+///
+/// Trailing nulls of an array can only be filled once a later array with a valid value arrives,
+/// so arrays that end in nulls are held back until then. Arrays are emitted in order, one per
+/// input array.
+struct BackwardFillIterator {
+    input: Box<dyn ArrayReader + Send>,
+    /// Arrays that have been filled within themselves, but whose trailing nulls are unresolved
+    pending: VecDeque<ArrayRef>,
+    /// Arrays that are fully resolved and ready to be emitted
+    ready: VecDeque<ArrayRef>,
+    done: bool,
+}
+
+impl BackwardFillIterator {
+    fn push(&mut self, array: ArrayRef) -> Result<(), ArrowError> {
+        let filled = fill_within_chunk(array.as_ref(), false)?;
+        if let Some(first_valid) = find_valid(array.as_ref(), true) {
+            let fill = Scalar::new(array.slice(first_valid, 1));
+            for pending in self.pending.drain(..) {
+                self.ready
+                    .push_back(fill_null_datum(pending.as_ref(), &fill)?);
+            }
+        }
+        if find_valid(filled.as_ref(), false).map_or(true, |i| i + 1 < filled.len()) {
+            self.pending.push_back(filled);
+        } else {
+            self.ready.push_back(filled);
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for BackwardFillIterator {
+    type Item = Result<ArrayRef, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(array) = self.ready.pop_front() {
+                return Some(Ok(array));
+            }
+            if self.done {
+                // Nothing follows the pending arrays, so their trailing nulls stay null
+                return self.pending.pop_front().map(Ok);
+            }
+            match self.input.next() {
+                Some(Ok(array)) => {
+                    if let Err(err) = self.push(array) {
+                        return Some(Err(err));
+                    }
+                }
+                Some(Err(err)) => return Some(Err(err)),
+                None => self.done = true,
+            }
+        }
+    }
+}
+
+/// Replace nulls of the input with `value`, a scalar or an array of the same length.
+#[pyfunction]
+pub fn fill_null<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    value: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let output_field = input.field()?;
+    binary_datum_kernel(py, input, value, output_field, |values, fill| {
+        fill_null_datum(values.get().0, fill)
+    })
+}
+
+/// Replace each null of the input with the last preceding valid value.
+///
+/// For stream input, the last valid value of each chunk is carried into the next.
+#[pyfunction]
+pub fn fill_null_forward<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    let mut last_valid: Option<ArrayRef> = None;
+    unary_kernel(py, input, data_type, move |array| {
+        let mut filled = fill_within_chunk(array, true)?;
+        if let Some(last_valid) = &last_valid {
+            filled = fill_null_datum(filled.as_ref(), &Scalar::new(last_valid))?;
+        }
+        if let Some(i) = find_valid(array, false) {
+            last_valid = Some(array.slice(i, 1));
+        }
+        Ok(filled)
+    })
+}
+
+/// Replace each null of the input with the next following valid value.
+///
+/// For stream input, chunks that end in nulls are held back until a later chunk provides a
+/// valid value.
+#[pyfunction]
+pub fn fill_null_backward<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    match input {
+        AnyArray::Array(array) => {
+            let filled = fill_within_chunk(array.as_ref(), false)?;
+            Ok(Arro3Array::from(filled).into_bound_py_any(py)?)
+        }
+        AnyArray::Stream(stream) => {
+            let reader = stream.into_reader()?;
+            let field = reader.field();
+            let iter = BackwardFillIterator {
+                input: reader,
+                pending: VecDeque::new(),
+                ready: VecDeque::new(),
+                done: false,
+            };
+            Ok(
+                Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+                    iter, field,
+                ))))
+                .into_bound_py_any(py)?,
+            )
+        }
+    }
+}
+
+/// Return the first non-null value of `values` at each position, cast to `data_type`.
+fn coalesce_datums(values: &[&dyn Datum], data_type: &DataType) -> Result<ArrayRef, ArrowError> {
+    let mut result = new_null_array(data_type, broadcast_len(values));
+    for value in values {
+        if result.logical_null_count() == 0 {
+            break;
+        }
+        result = fill_null_datum(result.as_ref(), *value)?;
+    }
+    Ok(result)
+}
+
+/// Return the first non-null value among the inputs at each position.
+///
+/// Inputs are cast to the narrowest type that holds all of them, raising if there is none, and
+/// scalars are broadcast. If any input is a stream, the streams are re-sliced to common chunk
+/// boundaries and an ArrayReader is returned.
+#[pyfunction]
+#[pyo3(signature = (*values))]
+pub fn coalesce<'py>(
+    py: Python<'py>,
+    values: Vec<AnyDatumOrStream>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    if values.is_empty() {
+        return Err(PyValueError::new_err("coalesce requires at least one input").into());
+    }
+    let data_type = common_input_type(&values)?;
+    let output_field = Arc::new(Field::new("", data_type.clone(), true));
+    nary_datum_kernel(py, values, output_field, move |values| {
+        coalesce_datums(values, &data_type)
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DropNullHow {
+    /// Drop rows with a null in any of the selected columns
+    Any,
+    /// Drop rows with a null in all of the selected columns
+    All,
+}
+
+impl<'py> FromPyObject<'_, 'py> for DropNullHow {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "any" => Ok(Self::Any),
+            "all" => Ok(Self::All),
+            _ => Err(PyValueError::new_err("Unexpected drop_null how")),
+        }
+    }
+}
+
+/// Compute which rows of `array` to keep.
+///
+/// If `positions` is provided, `array` is a struct array and the nulls of the selected children
+/// are combined according to `how`. Otherwise the nulls of `array` itself are used.
+fn keep_mask(
+    array: &dyn Array,
+    positions: Option<&[usize]>,
+    how: DropNullHow,
+) -> Result<BooleanArray, ArrowError> {
+    let Some(positions) = positions else {
+        return is_not_null(array);
+    };
+    let struct_array = array.as_struct();
+    let mut mask = None;
+    for position in positions {
+        let column_mask = is_not_null(struct_array.column(*position))?;
+        mask = Some(match (mask, how) {
+            (None, _) => column_mask,
+            (Some(mask), DropNullHow::Any) => and(&mask, &column_mask)?,
+            (Some(mask), DropNullHow::All) => or(&mask, &column_mask)?,
+        });
+    }
+    Ok(mask.unwrap_or_else(|| BooleanArray::from(vec![true; array.len()])))
+}
+
+/// Drop null values of an array, or rows with nulls of tabular input.
+///
+/// For tabular input, rows are dropped based on the nulls in `columns`, or in every column if
+/// `columns` is not provided.
+#[pyfunction]
+#[pyo3(signature = (input, columns=None, *, how=DropNullHow::Any))]
+pub fn drop_null<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    columns: Option<Vec<ColumnRef>>,
+    how: DropNullHow,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let field = input.field()?;
+    let (positions, schema) = match field.data_type() {
+        DataType::Struct(fields) => {
+            let positions = match columns {
+                Some(columns) => columns
+                    .into_iter()
+                    .map(|column| column.into_position(fields))
+                    .collect::<PyResult<Vec<_>>>()?,
+                None => (0..fields.len()).collect(),
+            };
+            let schema = Schema::new_with_metadata(fields.clone(), field.metadata().clone());
+            (Some(positions), Some(Arc::new(schema)))
+        }
+        _ => {
+            if columns.is_some() {
+                return Err(PyValueError::new_err(
+                    "columns may only be provided for tabular input",
+                )
+                .into());
+            }
+            (None, None)
+        }
+    };
+    let drop_nulls = move |array: &dyn Array| {
+        let mask = keep_mask(array, positions.as_deref(), how)?;
+        filter(array, &mask)
+    };
+
+    match (input, schema) {
+        (AnyArray::Array(array), Some(_)) => {
+            let filtered = drop_nulls(array.as_ref())?;
+            let batch = struct_to_record_batch(filtered.as_ref(), &field)?;
+            Ok(Arro3RecordBatch::from(batch).into_bound_py_any(py)?)
+        }
+        (AnyArray::Array(array), None) => {
+            let filtered = drop_nulls(array.as_ref())?;
+            Ok(Arro3Array::from(PyArray::new(filtered, field)).into_bound_py_any(py)?)
+        }
+        (AnyArray::Stream(stream), Some(schema)) => {
+            let batches = stream
+                .into_reader()?
+                .map(|array| struct_to_record_batch(drop_nulls(array?.as_ref())?.as_ref(), &field))
+                .collect::<Result<Vec<_>, ArrowError>>()?;
+            Ok(Arro3Table::from(PyTable::try_new(batches, schema)?).into_bound_py_any(py)?)
+        }
+        (AnyArray::Stream(stream), None) => {
+            let iter = stream
+                .into_reader()?
+                .map(move |array| drop_nulls(array?.as_ref()));
+            Ok(
+                Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+                    iter, field,
+                ))))
+                .into_bound_py_any(py)?,
+            )
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, BooleanArray, Datum, StringArray};
+use arrow_schema::{ArrowError, DataType, Field};
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::input::AnyDatum;
+use pyo3_arrow::PyScalar;
+use regex::{Regex, RegexBuilder};
+
+use crate::strings::{check_string_type, decode_strings, string_value};
+use crate::utils::{binary_datum_kernel, AnyDatumOrStream};
+
+/// A pattern argument: either a Python string, which is broadcast against every row, or Arrow
+/// data with one pattern per row.
+pub(crate) enum PatternInput {
+    Literal(String),
+    Datum(AnyDatumOrStream),
+}
+
+impl<'py> FromPyObject<'_, 'py> for PatternInput {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        if let Ok(pattern) = obj.extract::<String>() {
+            Ok(Self::Literal(pattern))
+        } else {
+            Ok(Self::Datum(obj.extract()?))
+        }
+    }
+}
+
+impl PatternInput {
+    /// Access the pattern as a single string, if it is a literal or a valid string Scalar.
+    pub(crate) fn as_literal(&self) -> Option<String> {
+        match self {
+            Self::Literal(pattern) => Some(pattern.clone()),
+            Self::Datum(AnyDatumOrStream::Datum(AnyDatum::Scalar(scalar))) => {
+                let array = decode_strings(scalar.array().as_ref()).ok()?;
+                string_value(array.as_ref(), 0).map(|pattern| pattern.to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Convert this into Arrow data, casting a literal pattern to the value type of `data_type`
+    /// so that it can be compared against input of that type.
+    fn into_datum(self, data_type: &DataType) -> PyArrowResult<AnyDatumOrStream> {
+        match self {
+            Self::Literal(pattern) => {
+                let value_type = match data_type {
+                    DataType::Dictionary(_, value_type) => value_type.as_ref(),
+                    data_type => data_type,
+                };
+                let array = StringArray::from(vec![pattern]);
+                let array = arrow_cast::cast(&array, value_type)?;
+                let field = Arc::new(Field::new("", value_type.clone(), true));
+                Ok(AnyDatumOrStream::Datum(AnyDatum::Scalar(
+                    PyScalar::try_new(array, field)?,
+                )))
+            }
+            Self::Datum(datum) => Ok(datum),
+        }
+    }
+}
+
+type PredicateKernel = fn(&dyn Datum, &dyn Datum) -> Result<BooleanArray, ArrowError>;
+
+fn predicate<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    pattern: PatternInput,
+    op: PredicateKernel,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let pattern = pattern.into_datum(input.field()?.data_type())?;
+    let output_field = Arc::new(Field::new("", DataType::Boolean, true));
+    binary_datum_kernel(py, input, pattern, output_field, move |values, pattern| {
+        Ok(Arc::new(op(values, pattern)?) as ArrayRef)
+    })
+}
+
+#[pyfunction]
+pub fn starts_with<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    pattern: PatternInput,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    predicate(py, input, pattern, arrow_string::like::starts_with)
+}
+
+#[pyfunction]
+pub fn ends_with<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    pattern: PatternInput,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    predicate(py, input, pattern, arrow_string::like::ends_with)
+}
+
+#[pyfunction]
+pub fn contains<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    pattern: PatternInput,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    predicate(py, input, pattern, arrow_string::like::contains)
+}
+
+#[pyfunction]
+pub fn like<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    pattern: PatternInput,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    predicate(py, input, pattern, arrow_string::like::like)
+}
+
+#[pyfunction]
+pub fn ilike<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    pattern: PatternInput,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    predicate(py, input, pattern, arrow_string::like::ilike)
+}
+
+/// Compile `pattern` into a [Regex].
+pub(crate) fn build_regex(pattern: &str, ignore_case: bool) -> Result<Regex, ArrowError> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(ignore_case)
+        .build()
+        .map_err(|err| {
+            ArrowError::ComputeError(format!("Regular expression did not compile: {err}"))
+        })
+}
+
+/// Match every value of `values` against a single pre-compiled regex.
+///
+/// Dictionary-encoded input is matched against its dictionary values only.
+fn regex_is_match_scalar(values: &dyn Array, regex: &Regex) -> Result<ArrayRef, ArrowError> {
+    if let Some(dict) = values.as_any_dictionary_opt() {
+        let matched = regex_is_match_scalar(dict.values().as_ref(), regex)?;
+        return arrow_select::take::take(matched.as_ref(), dict.keys(), None);
+    }
+
+    check_string_type(values.data_type())?;
+    Ok(Arc::new(
+        (0..values.len())
+            .map(|i| string_value(values, i).map(|value| regex.is_match(value)))
+            .collect::<BooleanArray>(),
+    ))
+}
+
+/// Match each value of `values` against the pattern in the same row of `patterns`.
+///
+/// Each distinct pattern is compiled only once.
+fn regex_is_match_array(
+    values: &dyn Datum,
+    patterns: &dyn Datum,
+    ignore_case: bool,
+) -> Result<ArrayRef, ArrowError> {
+    let (values, values_scalar) = values.get();
+    let (patterns, patterns_scalar) = patterns.get();
+    check_string_type(values.data_type())?;
+    check_string_type(patterns.data_type())?;
+    let values = decode_strings(values)?;
+    let patterns = decode_strings(patterns)?;
+
+    let len = match (values_scalar, patterns_scalar) {
+        (true, false) => patterns.len(),
+        (false, true) | (true, true) => values.len(),
+        (false, false) => {
+            if values.len() != patterns.len() {
+                return Err(ArrowError::ComputeError(format!(
+                    "Cannot match arrays of different lengths, got {} vs {}",
+                    values.len(),
+                    patterns.len()
+                )));
+            }
+            values.len()
+        }
+    };
+
+    let mut cache: HashMap<&str, Regex> = HashMap::new();
+    let mut builder = BooleanArray::builder(len);
+    for i in 0..len {
+        let value = string_value(values.as_ref(), if values_scalar { 0 } else { i });
+        let pattern = string_value(patterns.as_ref(), if patterns_scalar { 0 } else { i });
+        match (value, pattern) {
+            (Some(value), Some(pattern)) => {
+                let regex = match cache.entry(pattern) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(build_regex(pattern, ignore_case)?),
+                };
+                builder.append_value(regex.is_match(value));
+            }
+            _ => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Return whether each string matches a regular expression.
+///
+/// A single pattern is compiled once per call, rather than once per chunk.
+#[pyfunction]
+#[pyo3(signature = (input, pattern, *, ignore_case=false))]
+pub fn regex_match<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    pattern: PatternInput,
+    ignore_case: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let compiled = pattern
+        .as_literal()
+        .map(|pattern| build_regex(&pattern, ignore_case))
+        .transpose()?;
+    let pattern = pattern.into_datum(input.field()?.data_type())?;
+    let output_field = Arc::new(Field::new("", DataType::Boolean, true));
+
+    binary_datum_kernel(
+        py,
+        input,
+        pattern,
+        output_field,
+        move |values, patterns| match &compiled {
+            Some(regex) => regex_is_match_scalar(values.get().0, regex),
+            None => regex_is_match_array(values, patterns, ignore_case),
+        },
+    )
+}
@@ -0,0 +1,171 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use arrow_array::{Array, ArrayRef, Float64Array, UInt64Array};
+use arrow_ord::sort::{LexicographicalComparator, SortColumn};
+use arrow_schema::ArrowError;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::Arro3Array;
+use pyo3_arrow::input::AnyArray;
+
+use crate::sort::{lexsort, sort_columns, NullPlacement, SortKey, SortOrder};
+use crate::utils::concat_input;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Tiebreaker {
+    /// Ties get the smallest rank of the tied group
+    Min,
+    /// Ties get the largest rank of the tied group
+    Max,
+    /// Ties get distinct ranks, in order of appearance in the input
+    First,
+    /// Ties get the same rank, and ranks of successive groups increase by one
+    Dense,
+}
+
+impl<'py> FromPyObject<'_, 'py> for Tiebreaker {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "min" => Ok(Self::Min),
+            "max" => Ok(Self::Max),
+            "first" => Ok(Self::First),
+            "dense" => Ok(Self::Dense),
+            _ => Err(PyValueError::new_err("Unexpected tiebreaker")),
+        }
+    }
+}
+
+/// Compute the 1-based rank of each row of `columns`, each of which has length `len`.
+///
+/// Rows that compare equal on every column (including rows that are null in the same columns)
+/// are tied.
+fn rank_rows(
+    columns: &[SortColumn],
+    len: usize,
+    tiebreaker: Tiebreaker,
+) -> Result<Vec<u64>, ArrowError> {
+    let order = lexsort(columns, len)?;
+    let comparator = LexicographicalComparator::try_new(columns)?;
+    let mut ranks = vec![0; len];
+
+    let mut group_start = 0;
+    let mut dense_rank = 0;
+    while group_start < len {
+        let mut group_end = group_start + 1;
+        while group_end < len
+            && comparator.compare(order[group_start], order[group_end]) == Ordering::Equal
+        {
+            group_end += 1;
+        }
+        dense_rank += 1;
+        for (position, row) in order[group_start..group_end].iter().enumerate() {
+            ranks[*row] = match tiebreaker {
+                Tiebreaker::Min => group_start as u64 + 1,
+                Tiebreaker::Max => group_end as u64,
+                Tiebreaker::First => (group_start + position) as u64 + 1,
+                Tiebreaker::Dense => dense_rank,
+            };
+        }
+        group_start = group_end;
+    }
+    Ok(ranks)
+}
+
+fn rank_input(
+    py: Python,
+    input: AnyArray,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+    tiebreaker: Tiebreaker,
+) -> PyArrowResult<(Vec<u64>, usize)> {
+    let (array, _field) = concat_input(input)?;
+    let columns = sort_columns(&array, sort_keys, order, null_placement)?;
+    let len = array.len();
+    let ranks = py.detach(|| rank_rows(&columns, len, tiebreaker))?;
+    Ok((ranks, len))
+}
+
+/// Return the 1-based rank of each row of the input as UInt64.
+///
+/// For ChunkedArray, Table and stream input, ranks are global across all chunks.
+#[pyfunction]
+#[pyo3(signature = (input, sort_keys=None, *, order=SortOrder::Ascending, null_placement=NullPlacement::AtEnd, tiebreaker=Tiebreaker::Min))]
+pub fn rank(
+    py: Python,
+    input: AnyArray,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+    tiebreaker: Tiebreaker,
+) -> PyArrowResult<Arro3Array> {
+    let (ranks, _len) = rank_input(py, input, sort_keys, order, null_placement, tiebreaker)?;
+    Ok((Arc::new(UInt64Array::from(ranks)) as ArrayRef).into())
+}
+
+/// Return the dense rank of each row of the input as UInt64, where tied rows share a rank and
+/// ranks have no gaps.
+#[pyfunction]
+#[pyo3(signature = (input, sort_keys=None, *, order=SortOrder::Ascending, null_placement=NullPlacement::AtEnd))]
+pub fn dense_rank(
+    py: Python,
+    input: AnyArray,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+) -> PyArrowResult<Arro3Array> {
+    rank(
+        py,
+        input,
+        sort_keys,
+        order,
+        null_placement,
+        Tiebreaker::Dense,
+    )
+}
+
+/// Return the 1-based position of each row of the input in sorted order as UInt64, where tied
+/// rows are numbered in order of appearance.
+#[pyfunction]
+#[pyo3(signature = (input, sort_keys=None, *, order=SortOrder::Ascending, null_placement=NullPlacement::AtEnd))]
+pub fn row_number(
+    py: Python,
+    input: AnyArray,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+) -> PyArrowResult<Arro3Array> {
+    rank(
+        py,
+        input,
+        sort_keys,
+        order,
+        null_placement,
+        Tiebreaker::First,
+    )
+}
+
+/// Return the relative rank of each row of the input as Float64, computed as
+/// `(rank - 1) / (N - 1)` with the `min` tiebreaker.
+#[pyfunction]
+#[pyo3(signature = (input, sort_keys=None, *, order=SortOrder::Ascending, null_placement=NullPlacement::AtEnd))]
+pub fn percent_rank(
+    py: Python,
+    input: AnyArray,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+) -> PyArrowResult<Arro3Array> {
+    let (ranks, len) = rank_input(py, input, sort_keys, order, null_placement, Tiebreaker::Min)?;
+    let denominator = len.saturating_sub(1).max(1) as f64;
+    let percent = ranks
+        .into_iter()
+        .map(|rank| (rank - 1) as f64 / denominator)
+        .collect::<Float64Array>();
+    Ok((Arc::new(percent) as ArrayRef).into())
+}
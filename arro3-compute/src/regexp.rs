@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use arrow_array::builder::{ListBuilder, StringBuilder};
+use arrow_array::{Array, ArrayRef, StructArray};
+use arrow_buffer::NullBuffer;
+use arrow_schema::{ArrowError, DataType, Field, Fields};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::input::AnyArray;
+use regex::{Captures, Regex};
+
+use crate::pattern::build_regex;
+use crate::strings::{check_string_type, decode_strings, map_strings, string_value};
+use crate::utils::unary_kernel;
+
+/// A reference to a capture group, either by index or by name.
+#[derive(FromPyObject, Debug, Clone)]
+pub(crate) enum GroupRef {
+    Index(usize),
+    Name(String),
+}
+
+impl GroupRef {
+    /// Check that this group exists in `regex`.
+    fn validate(&self, regex: &Regex) -> PyResult<()> {
+        let exists = match self {
+            Self::Index(index) => *index < regex.captures_len(),
+            Self::Name(name) => regex.capture_names().any(|n| n == Some(name.as_str())),
+        };
+        if exists {
+            return Ok(());
+        }
+        let msg = match self {
+            Self::Index(index) => format!("Capture group {index} does not exist in pattern"),
+            Self::Name(name) => format!("Capture group '{name}' does not exist in pattern"),
+        };
+        Err(PyValueError::new_err(msg))
+    }
+
+    fn get<'a>(&self, captures: &Captures<'a>) -> Option<&'a str> {
+        let group = match self {
+            Self::Index(index) => captures.get(*index),
+            Self::Name(name) => captures.name(name),
+        };
+        group.map(|m| m.as_str())
+    }
+}
+
+/// Extract a capture group from the first match of a regular expression in each string.
+///
+/// Strings without a match, or where the group did not participate in the match, become null.
+#[pyfunction]
+#[pyo3(signature = (input, pattern, group=GroupRef::Index(0)))]
+pub fn regex_extract<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    pattern: String,
+    group: GroupRef,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let output_type = input.field()?.data_type().clone();
+    check_string_type(&output_type)?;
+    let regex = build_regex(&pattern, false)?;
+    group.validate(&regex)?;
+
+    unary_kernel(py, input, output_type, move |array| {
+        map_strings(array, &|s| {
+            let captures = regex.captures(s)?;
+            group.get(&captures).map(Cow::Borrowed)
+        })
+    })
+}
+
+fn regex_extract_all_array(
+    array: &dyn Array,
+    regex: &Regex,
+    group: &GroupRef,
+) -> Result<ArrayRef, ArrowError> {
+    let array = decode_strings(array)?;
+    let mut builder = ListBuilder::with_capacity(StringBuilder::new(), array.len());
+    for i in 0..array.len() {
+        match string_value(array.as_ref(), i) {
+            Some(value) => {
+                for captures in regex.captures_iter(value) {
+                    builder.values().append_option(group.get(&captures));
+                }
+                builder.append(true);
+            }
+            None => builder.append_null(),
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Extract a capture group from every non-overlapping match of a regular expression in each
+/// string, returning a `List<Utf8>` array.
+#[pyfunction]
+#[pyo3(signature = (input, pattern, group=GroupRef::Index(0)))]
+pub fn regex_extract_all<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    pattern: String,
+    group: GroupRef,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    check_string_type(input.field()?.data_type())?;
+    let regex = build_regex(&pattern, false)?;
+    group.validate(&regex)?;
+    let output_type = DataType::List(Arc::new(Field::new_list_field(DataType::Utf8, true)));
+
+    unary_kernel(py, input, output_type, move |array| {
+        regex_extract_all_array(array, &regex, &group)
+    })
+}
+
+fn regex_extract_groups_array(
+    array: &dyn Array,
+    regex: &Regex,
+    fields: &Fields,
+) -> Result<ArrayRef, ArrowError> {
+    let array = decode_strings(array)?;
+    let mut builders = fields
+        .iter()
+        .map(|_| StringBuilder::with_capacity(array.len(), 1024))
+        .collect::<Vec<_>>();
+    let mut validity = Vec::with_capacity(array.len());
+    for i in 0..array.len() {
+        let captures = string_value(array.as_ref(), i).and_then(|value| regex.captures(value));
+        validity.push(captures.is_some());
+        for (field, builder) in fields.iter().zip(builders.iter_mut()) {
+            builder.append_option(
+                captures
+                    .as_ref()
+                    .and_then(|captures| captures.name(field.name()))
+                    .map(|m| m.as_str()),
+            );
+        }
+    }
+    let children = builders
+        .iter_mut()
+        .map(|builder| Arc::new(builder.finish()) as ArrayRef)
+        .collect();
+    Ok(Arc::new(StructArray::try_new(
+        fields.clone(),
+        children,
+        Some(NullBuffer::from(validity)),
+    )?))
+}
+
+/// Extract all named capture groups from the first match of a regular expression in each string,
+/// returning a Struct array with one Utf8 child per named group.
+#[pyfunction]
+pub fn regex_extract_groups<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    pattern: String,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    check_string_type(input.field()?.data_type())?;
+    let regex = build_regex(&pattern, false)?;
+    let fields = regex
+        .capture_names()
+        .flatten()
+        .map(|name| Field::new(name, DataType::Utf8, true))
+        .collect::<Fields>();
+    if fields.is_empty() {
+        return Err(
+            PyValueError::new_err("Pattern must contain at least one named capture group").into(),
+        );
+    }
+    let output_type = DataType::Struct(fields.clone());
+
+    unary_kernel(py, input, output_type, move |array| {
+        regex_extract_groups_array(array, &regex, &fields)
+    })
+}
+
+/// Replace matches of a regular expression in each string.
+///
+/// `replacement` may reference capture groups with `$1` or `$name`.
+#[pyfunction]
+#[pyo3(signature = (input, pattern, replacement, *, max_replacements=None))]
+pub fn regex_replace<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    pattern: String,
+    replacement: String,
+    max_replacements: Option<usize>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let output_type = input.field()?.data_type().clone();
+    check_string_type(&output_type)?;
+    let regex = build_regex(&pattern, false)?;
+    // A limit of 0 means "replace all" in Regex::replacen
+    let limit = max_replacements.unwrap_or(0);
+    if max_replacements == Some(0) {
+        return unary_kernel(py, input, output_type, |array| {
+            map_strings(array, &|s| Some(Cow::Borrowed(s)))
+        });
+    }
+
+    unary_kernel(py, input, output_type, move |array| {
+        map_strings(array, &|s| {
+            Some(regex.replacen(s, limit, replacement.as_str()))
+        })
+    })
+}
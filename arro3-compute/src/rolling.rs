@@ -0,0 +1,402 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, Int64Type};
+use arrow_array::{Array, ArrayRef, Float64Array};
+use arrow_schema::{ArrowError, DataType, Field, TimeUnit};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3Array, Arro3ArrayReader};
+use pyo3_arrow::ffi::ArrayIterator;
+use pyo3_arrow::input::AnyArray;
+use pyo3_arrow::PyArrayReader;
+
+use crate::utils::{unary_kernel, AlignedChunks};
+
+#[derive(Debug, Clone, Copy)]
+enum RollingAgg {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+/// The size of a rolling window: either a number of rows, or a duration string such as `"5m"`.
+#[derive(FromPyObject, Debug, Clone)]
+pub(crate) enum WindowInput {
+    Rows(usize),
+    Duration(String),
+}
+
+/// Parse a duration string such as `"100ms"`, `"5m"` or `"2h"` into a number of nanoseconds.
+fn parse_duration(s: &str) -> PyResult<i64> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(|| PyValueError::new_err(format!("Missing unit in window '{s}'")))?;
+    let (value, unit) = s.split_at(split);
+    let value = value
+        .parse::<i64>()
+        .map_err(|_| PyValueError::new_err(format!("Invalid window '{s}'")))?;
+    let nanos_per_unit: i64 = match unit {
+        "ns" => 1,
+        "us" => 1_000,
+        "ms" => 1_000_000,
+        "s" => 1_000_000_000,
+        "m" => 60 * 1_000_000_000,
+        "h" => 60 * 60 * 1_000_000_000,
+        "d" => 24 * 60 * 60 * 1_000_000_000,
+        "w" => 7 * 24 * 60 * 60 * 1_000_000_000,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Unexpected unit '{unit}' in window '{s}'"
+            )))
+        }
+    };
+    value
+        .checked_mul(nanos_per_unit)
+        .ok_or_else(|| PyValueError::new_err(format!("Window '{s}' is too large")))
+}
+
+/// Convert a number of nanoseconds into a count of `unit`.
+fn nanos_to_unit(nanos: i64, unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => nanos / 1_000_000_000,
+        TimeUnit::Millisecond => nanos / 1_000_000,
+        TimeUnit::Microsecond => nanos / 1_000,
+        TimeUnit::Nanosecond => nanos,
+    }
+}
+
+/// A sum of the values in a rolling window, which supports removing values as they leave it.
+///
+/// Finite values are summed with Neumaier compensation, so error does not build up over long
+/// inputs. Non-finite values are counted separately, so that they stop affecting the sum once
+/// they leave the window.
+#[derive(Debug, Default)]
+struct WindowSum {
+    sum: f64,
+    compensation: f64,
+    nan: usize,
+    pos_inf: usize,
+    neg_inf: usize,
+}
+
+impl WindowSum {
+    fn add_finite(&mut self, value: f64) {
+        let total = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - total) + value;
+        } else {
+            self.compensation += (value - total) + self.sum;
+        }
+        self.sum = total;
+    }
+
+    fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            self.nan += 1;
+        } else if value == f64::INFINITY {
+            self.pos_inf += 1;
+        } else if value == f64::NEG_INFINITY {
+            self.neg_inf += 1;
+        } else {
+            self.add_finite(value);
+        }
+    }
+
+    fn remove(&mut self, value: f64) {
+        if value.is_nan() {
+            self.nan -= 1;
+        } else if value == f64::INFINITY {
+            self.pos_inf -= 1;
+        } else if value == f64::NEG_INFINITY {
+            self.neg_inf -= 1;
+        } else {
+            self.add_finite(-value);
+        }
+    }
+
+    fn value(&self) -> f64 {
+        if self.nan > 0 || (self.pos_inf > 0 && self.neg_inf > 0) {
+            f64::NAN
+        } else if self.pos_inf > 0 {
+            f64::INFINITY
+        } else if self.neg_inf > 0 {
+            f64::NEG_INFINITY
+        } else {
+            self.sum + self.compensation
+        }
+    }
+}
+
+/// The running state of a rolling window, carried across chunks.
+///
+/// Each row is keyed by its row number for count-based windows, or by its timestamp for
+/// time-based windows. A row with key `k` is in the window of a later row with key `t` if
+/// `k > t - width`.
+struct RollingState {
+    agg: RollingAgg,
+    width: i64,
+    min_periods: usize,
+    /// The key and value of every row in the current window
+    entries: VecDeque<(i64, Option<f64>)>,
+    sum: WindowSum,
+    valid: usize,
+    /// A monotonic queue of candidate extrema, used for min and max
+    extrema: VecDeque<(i64, f64)>,
+    /// The next row number, for count-based windows
+    next_row: i64,
+    /// The last timestamp seen, for time-based windows
+    last_key: Option<i64>,
+}
+
+impl RollingState {
+    fn new(agg: RollingAgg, width: i64, min_periods: usize) -> Self {
+        Self {
+            agg,
+            width,
+            min_periods,
+            entries: VecDeque::new(),
+            sum: WindowSum::default(),
+            valid: 0,
+            extrema: VecDeque::new(),
+            next_row: 0,
+            last_key: None,
+        }
+    }
+
+    /// Add one row to the window and return the aggregate of the window ending at that row.
+    fn push(&mut self, key: i64, value: Option<f64>) -> Option<f64> {
+        self.entries.push_back((key, value));
+        if let Some(value) = value {
+            self.sum.add(value);
+            self.valid += 1;
+            let dominated = |candidate: f64| match self.agg {
+                RollingAgg::Min => candidate > value,
+                RollingAgg::Max => candidate < value,
+                _ => false,
+            };
+            while self.extrema.back().is_some_and(|(_, c)| dominated(*c)) {
+                self.extrema.pop_back();
+            }
+            // NaN is tracked by the sum and never enters the deque, where it would not compare
+            if matches!(self.agg, RollingAgg::Min | RollingAgg::Max) && !value.is_nan() {
+                self.extrema.push_back((key, value));
+            }
+        }
+
+        let cutoff = key.saturating_sub(self.width);
+        while let Some((front_key, front_value)) = self.entries.front().copied() {
+            if front_key > cutoff {
+                break;
+            }
+            self.entries.pop_front();
+            if let Some(front_value) = front_value {
+                self.sum.remove(front_value);
+                self.valid -= 1;
+            }
+        }
+        if self.valid == 0 {
+            // Start again from an exact zero once the window has no values
+            self.sum = WindowSum::default();
+        }
+        while self
+            .extrema
+            .front()
+            .is_some_and(|(front_key, _)| *front_key <= cutoff)
+        {
+            self.extrema.pop_front();
+        }
+
+        if self.valid < self.min_periods || self.valid == 0 {
+            return None;
+        }
+        match self.agg {
+            RollingAgg::Sum => Some(self.sum.value()),
+            RollingAgg::Mean => Some(self.sum.value() / self.valid as f64),
+            RollingAgg::Min | RollingAgg::Max if self.sum.nan > 0 => Some(f64::NAN),
+            RollingAgg::Min | RollingAgg::Max => self.extrema.front().map(|(_, value)| *value),
+        }
+    }
+
+    /// Apply the window to a chunk of values, keyed by row number.
+    fn update_rows(&mut self, values: &dyn Array) -> Result<ArrayRef, ArrowError> {
+        let values = arrow_cast::cast(values, &DataType::Float64)?;
+        let output = values
+            .as_primitive::<Float64Type>()
+            .iter()
+            .map(|value| {
+                let key = self.next_row;
+                self.next_row += 1;
+                self.push(key, value)
+            })
+            .collect::<Float64Array>();
+        Ok(Arc::new(output))
+    }
+
+    /// Apply the window to a chunk of values, keyed by the timestamps in `by`.
+    fn update_time(&mut self, values: &dyn Array, by: &dyn Array) -> Result<ArrayRef, ArrowError> {
+        if values.len() != by.len() {
+            return Err(ArrowError::ComputeError(format!(
+                "Values and timestamps must have the same length, got {} vs {}",
+                values.len(),
+                by.len()
+            )));
+        }
+        if by.logical_null_count() > 0 {
+            return Err(ArrowError::ComputeError(
+                "Timestamps of a time-based window may not be null".to_string(),
+            ));
+        }
+        let values = arrow_cast::cast(values, &DataType::Float64)?;
+        let by = arrow_cast::cast(by, &DataType::Int64)?;
+        let mut output = Vec::with_capacity(values.len());
+        for (value, key) in values
+            .as_primitive::<Float64Type>()
+            .iter()
+            .zip(by.as_primitive::<Int64Type>().values().iter())
+        {
+            if self.last_key.is_some_and(|last_key| *key < last_key) {
+                return Err(ArrowError::ComputeError(
+                    "Timestamps of a time-based window must be sorted in ascending order"
+                        .to_string(),
+                ));
+            }
+            self.last_key = Some(*key);
+            output.push(self.push(*key, value));
+        }
+        Ok(Arc::new(Float64Array::from(output)))
+    }
+}
+
+fn rolling<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    window: WindowInput,
+    by: Option<AnyArray>,
+    min_periods: Option<usize>,
+    agg: RollingAgg,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let (by, width) = match (window, by) {
+        (WindowInput::Rows(rows), None) => {
+            if rows == 0 {
+                return Err(PyValueError::new_err("Window must be at least 1 row").into());
+            }
+            let mut state = RollingState::new(agg, rows as i64, min_periods.unwrap_or(rows));
+            return unary_kernel(py, input, DataType::Float64, move |values| {
+                state.update_rows(values)
+            });
+        }
+        (WindowInput::Rows(_), Some(_)) => {
+            return Err(
+                PyValueError::new_err("by may only be provided for a time-based window").into(),
+            );
+        }
+        (WindowInput::Duration(_), None) => {
+            return Err(PyValueError::new_err(
+                "A time-based window requires a Timestamp column in by",
+            )
+            .into());
+        }
+        (WindowInput::Duration(duration), Some(by)) => {
+            let nanos = parse_duration(&duration)?;
+            let width = match by.field()?.data_type() {
+                DataType::Timestamp(unit, _) => nanos_to_unit(nanos, unit),
+                d => {
+                    return Err(PyValueError::new_err(format!(
+                        "by must be a Timestamp column, got {d}"
+                    ))
+                    .into())
+                }
+            };
+            if width <= 0 {
+                return Err(PyValueError::new_err("Window must be positive").into());
+            }
+            (by, width)
+        }
+    };
+    let mut state = RollingState::new(agg, width, min_periods.unwrap_or(1));
+
+    match (input, by) {
+        (AnyArray::Array(values), AnyArray::Array(by)) => {
+            let out = state.update_time(values.as_ref(), by.as_ref())?;
+            Ok(Arro3Array::from(out).into_bound_py_any(py)?)
+        }
+        (AnyArray::Stream(values), AnyArray::Stream(by)) => {
+            let iter = AlignedChunks::new(vec![values.into_reader()?, by.into_reader()?]).map(
+                move |chunks| {
+                    let chunks = chunks?;
+                    state.update_time(chunks[0].as_ref(), chunks[1].as_ref())
+                },
+            );
+            let output_field = Arc::new(Field::new("", DataType::Float64, true));
+            Ok(
+                Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+                    iter,
+                    output_field,
+                ))))
+                .into_bound_py_any(py)?,
+            )
+        }
+        _ => Err(PyValueError::new_err(
+            "Expected both input and by to be arrays or both to be streams",
+        )
+        .into()),
+    }
+}
+
+/// Return the sum of each rolling window of the input, as Float64.
+#[pyfunction]
+#[pyo3(signature = (input, window, *, by=None, min_periods=None))]
+pub fn rolling_sum<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    window: WindowInput,
+    by: Option<AnyArray>,
+    min_periods: Option<usize>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    rolling(py, input, window, by, min_periods, RollingAgg::Sum)
+}
+
+/// Return the mean of each rolling window of the input, as Float64.
+#[pyfunction]
+#[pyo3(signature = (input, window, *, by=None, min_periods=None))]
+pub fn rolling_mean<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    window: WindowInput,
+    by: Option<AnyArray>,
+    min_periods: Option<usize>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    rolling(py, input, window, by, min_periods, RollingAgg::Mean)
+}
+
+/// Return the minimum of each rolling window of the input, as Float64.
+#[pyfunction]
+#[pyo3(signature = (input, window, *, by=None, min_periods=None))]
+pub fn rolling_min<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    window: WindowInput,
+    by: Option<AnyArray>,
+    min_periods: Option<usize>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    rolling(py, input, window, by, min_periods, RollingAgg::Min)
+}
+
+/// Return the maximum of each rolling window of the input, as Float64.
+#[pyfunction]
+#[pyo3(signature = (input, window, *, by=None, min_periods=None))]
+pub fn rolling_max<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    window: WindowInput,
+    by: Option<AnyArray>,
+    min_periods: Option<usize>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    rolling(py, input, window, by, min_periods, RollingAgg::Max)
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, BooleanArray, Int32Array};
+use arrow_row::{RowConverter, SortField};
+use arrow_schema::{ArrowError, DataType};
+use pyo3::prelude::*;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::input::AnyArray;
+
+use crate::utils::{cast_strict, common_type, concat_input, unary_kernel};
+
+/// A hash table from each distinct value of a value set to the position of its first occurrence.
+///
+/// Values are hashed in their row-encoded form, so any type supported by [RowConverter] can be
+/// looked up. The value set and inputs are compared in the [common_type] of their value types,
+/// and dictionary-encoded inputs are looked up once per dictionary value rather than once per row.
+struct ValueSet {
+    /// The type in which values are compared
+    data_type: DataType,
+    converter: RowConverter,
+    positions: HashMap<Box<[u8]>, i32>,
+    /// The position of the first null in the value set, unless nulls are skipped
+    null_position: Option<i32>,
+}
+
+impl ValueSet {
+    /// Build a value set from `value_set`, for looking up inputs of type `values_type`.
+    fn try_new(
+        value_set: &dyn Array,
+        values_type: &DataType,
+        skip_nulls: bool,
+    ) -> Result<Self, ArrowError> {
+        let data_type = common_type(
+            decoded_type(values_type),
+            decoded_type(value_set.data_type()),
+        )
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Cannot look up values of type {values_type} in a value set of type {}",
+                value_set.data_type()
+            ))
+        })?;
+        if value_set.len() > i32::MAX as usize {
+            return Err(ArrowError::ComputeError(
+                "value_set may have at most 2^31 - 1 values".to_string(),
+            ));
+        }
+        let value_set = cast_strict(value_set, &data_type)?;
+        let converter = RowConverter::new(vec![SortField::new(data_type.clone())])?;
+        let rows = converter.convert_columns(&[value_set.clone()])?;
+
+        let mut positions = HashMap::new();
+        let mut null_position = None;
+        for (i, row) in rows.iter().enumerate() {
+            if value_set.is_null(i) {
+                if !skip_nulls && null_position.is_none() {
+                    null_position = Some(i as i32);
+                }
+            } else {
+                positions.entry(row.as_ref().into()).or_insert(i as i32);
+            }
+        }
+        Ok(Self {
+            data_type,
+            converter,
+            positions,
+            null_position,
+        })
+    }
+
+    /// Return the position in the value set of each value of `array`, or null if absent.
+    fn index_in(&self, array: &dyn Array) -> Result<Int32Array, ArrowError> {
+        if let Some(dict) = array.as_any_dictionary_opt() {
+            let value_positions = self.index_in(dict.values().as_ref())?;
+            let key_nulls = dict.keys().logical_nulls();
+            return Ok(dict
+                .normalized_keys()
+                .into_iter()
+                .enumerate()
+                .map(|(i, key)| {
+                    if key_nulls.as_ref().map_or(true, |nulls| nulls.is_valid(i)) {
+                        value_positions
+                            .is_valid(key)
+                            .then(|| value_positions.value(key))
+                    } else {
+                        self.null_position
+                    }
+                })
+                .collect());
+        }
+
+        let array = cast_strict(array, &self.data_type)?;
+        let rows = self.converter.convert_columns(&[array.clone()])?;
+        Ok(rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                if array.is_null(i) {
+                    self.null_position
+                } else {
+                    self.positions.get(row.as_ref()).copied()
+                }
+            })
+            .collect())
+    }
+
+    fn is_in(&self, array: &dyn Array) -> Result<BooleanArray, ArrowError> {
+        let positions = self.index_in(array)?;
+        Ok(BooleanArray::from_iter(
+            (0..positions.len()).map(|i| Some(positions.is_valid(i))),
+        ))
+    }
+}
+
+/// The value type of a dictionary type, or the type itself otherwise.
+fn decoded_type(data_type: &DataType) -> &DataType {
+    match data_type {
+        DataType::Dictionary(_, value_type) => value_type,
+        data_type => data_type,
+    }
+}
+
+fn build_value_set(
+    values: &AnyArray,
+    value_set: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<ValueSet> {
+    let values_field = values.field()?;
+    let (value_set, _field) = concat_input(value_set)?;
+    Ok(ValueSet::try_new(
+        value_set.as_ref(),
+        values_field.data_type(),
+        skip_nulls,
+    )?)
+}
+
+/// Return whether each value of the input is in `value_set`, as a non-null boolean array.
+///
+/// The hash table is built once from `value_set` and applied to every chunk of the input.
+#[pyfunction]
+#[pyo3(signature = (values, value_set, *, skip_nulls=false))]
+pub fn is_in<'py>(
+    py: Python<'py>,
+    values: AnyArray,
+    value_set: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let set = build_value_set(&values, value_set, skip_nulls)?;
+    unary_kernel(py, values, DataType::Boolean, move |array| {
+        Ok(Arc::new(set.is_in(array)?))
+    })
+}
+
+/// Return the position of each value of the input in `value_set` as Int32, or null if absent.
+///
+/// The hash table is built once from `value_set` and applied to every chunk of the input.
+#[pyfunction]
+#[pyo3(signature = (values, value_set, *, skip_nulls=false))]
+pub fn index_in<'py>(
+    py: Python<'py>,
+    values: AnyArray,
+    value_set: AnyArray,
+    skip_nulls: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let set = build_value_set(&values, value_set, skip_nulls)?;
+    unary_kernel(py, values, DataType::Int32, move |array| {
+        Ok(Arc::new(set.index_in(array)?))
+    })
+}
@@ -0,0 +1,248 @@
+use std::collections::VecDeque;
+
+use arrow_arith::numeric;
+use arrow_array::{new_empty_array, new_null_array, Array, ArrayRef, Datum, UInt64Array};
+use arrow_schema::{ArrowError, DataType, Field};
+use arrow_select::take::take;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3Array, Arro3ArrayReader};
+use pyo3_arrow::ffi::ArrayIterator;
+use pyo3_arrow::input::{AnyArray, AnyDatum};
+use pyo3_arrow::PyArrayReader;
+
+use crate::utils::broadcast;
+
+/// Pairs each chunk of the input with the same rows of the input shifted by `periods`.
+///
+/// A positive `periods` lags the input: the shifted rows start with `periods` fill values,
+/// followed by the input, so the last `periods` values are carried forward into the next chunk. A
+/// negative `periods` leads the input: a chunk is held back until enough later rows have arrived,
+/// and the rows after the last input row are fill values.
+///
+/// Fill values are materialized as each output chunk is built, so memory use does not depend on
+/// `periods`.
+struct ShiftIterator<I> {
+    input: I,
+    /// A single fill value
+    fill: ArrayRef,
+    /// The number of fill values still to emit before the input, when lagging
+    fill_before: usize,
+    lead: bool,
+    /// Input chunks that have not been emitted yet
+    pending: VecDeque<ArrayRef>,
+    /// Input values that have not been emitted as shifted values yet, following any remaining
+    /// fill values
+    buffer: ArrayRef,
+    /// The number of leading input rows still to drop, when leading
+    skip: usize,
+    done: bool,
+}
+
+impl<I: Iterator<Item = Result<ArrayRef, ArrowError>>> ShiftIterator<I> {
+    fn new(input: I, data_type: &DataType, periods: i64, fill: ArrayRef) -> Self {
+        let lead = periods < 0;
+        let num_fill = usize::try_from(periods.unsigned_abs()).unwrap_or(usize::MAX);
+        Self {
+            input,
+            fill,
+            fill_before: if lead { 0 } else { num_fill },
+            lead,
+            pending: VecDeque::new(),
+            buffer: new_empty_array(data_type),
+            skip: if lead { num_fill } else { 0 },
+            done: false,
+        }
+    }
+
+    fn append(&mut self, values: &dyn Array) -> Result<(), ArrowError> {
+        self.buffer = arrow_select::concat::concat(&[self.buffer.as_ref(), values])?;
+        Ok(())
+    }
+
+    /// `len` copies of the fill value.
+    fn repeat_fill(&self, len: usize) -> Result<ArrayRef, ArrowError> {
+        take(self.fill.as_ref(), &UInt64Array::from(vec![0; len]), None)
+    }
+
+    /// Take the next `len` shifted values. Once the input is exhausted, rows past the end of the
+    /// buffer are fill values.
+    fn take_shifted(&mut self, len: usize) -> Result<ArrayRef, ArrowError> {
+        let before = self.fill_before.min(len);
+        self.fill_before -= before;
+        let from_buffer = (len - before).min(self.buffer.len());
+        let after = len - before - from_buffer;
+
+        let mut parts = vec![];
+        if before > 0 {
+            parts.push(self.repeat_fill(before)?);
+        }
+        parts.push(self.buffer.slice(0, from_buffer));
+        self.buffer = self
+            .buffer
+            .slice(from_buffer, self.buffer.len() - from_buffer);
+        if after > 0 {
+            parts.push(self.repeat_fill(after)?);
+        }
+        let parts = parts.iter().map(|part| part.as_ref()).collect::<Vec<_>>();
+        arrow_select::concat::concat(&parts)
+    }
+
+    fn advance(&mut self) -> Result<Option<(ArrayRef, ArrayRef)>, ArrowError> {
+        loop {
+            if let Some(len) = self.pending.front().map(|chunk| chunk.len()) {
+                if self.done || self.fill_before.saturating_add(self.buffer.len()) >= len {
+                    let shifted = self.take_shifted(len)?;
+                    let chunk = self.pending.pop_front().unwrap();
+                    return Ok(Some((chunk, shifted)));
+                }
+            }
+            if self.done {
+                return Ok(None);
+            }
+            match self.input.next() {
+                Some(chunk) => {
+                    let chunk = chunk?;
+                    let skip = self.skip.min(chunk.len());
+                    self.skip -= skip;
+                    self.append(chunk.slice(skip, chunk.len() - skip).as_ref())?;
+                    self.pending.push_back(chunk);
+                }
+                None => self.done = true,
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = Result<ArrayRef, ArrowError>>> Iterator for ShiftIterator<I> {
+    type Item = Result<(ArrayRef, ArrayRef), ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance().transpose()
+    }
+}
+
+/// Apply `op` to each chunk of `input` and the same rows of `input` shifted by `periods`.
+///
+/// Array input returns an Array. Stream input returns an ArrayReader with the given output data
+/// type, with one output chunk per input chunk.
+fn shift_kernel<'py, F>(
+    py: Python<'py>,
+    input: AnyArray,
+    periods: i64,
+    fill_value: Option<AnyDatum>,
+    output_type: DataType,
+    mut op: F,
+) -> PyArrowResult<Bound<'py, PyAny>>
+where
+    F: FnMut(ArrayRef, ArrayRef) -> Result<ArrayRef, ArrowError> + Send + 'static,
+{
+    let data_type = input.field()?.data_type().clone();
+    let fill = match fill_value {
+        Some(fill_value) => {
+            if !fill_value.get().1 {
+                return Err(PyValueError::new_err("fill_value must be a scalar").into());
+            }
+            broadcast(&fill_value, &data_type, 1)?
+        }
+        None => new_null_array(&data_type, 1),
+    };
+
+    match input {
+        AnyArray::Array(array) => {
+            let (array, _field) = array.into_inner();
+            let mut iter =
+                ShiftIterator::new(std::iter::once(Ok(array)), &data_type, periods, fill);
+            let (array, shifted) = iter.next().expect("One output chunk per input chunk")?;
+            Ok(Arro3Array::from(op(array, shifted)?).into_bound_py_any(py)?)
+        }
+        AnyArray::Stream(stream) => {
+            let reader = stream.into_reader()?;
+            let output_field = Field::new("", output_type, true);
+
+            let iter = ShiftIterator::new(reader, &data_type, periods, fill).map(move |pair| {
+                let (array, shifted) = pair?;
+                op(array, shifted)
+            });
+            Ok(
+                Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+                    iter,
+                    output_field.into(),
+                ))))
+                .into_bound_py_any(py)?,
+            )
+        }
+    }
+}
+
+/// Shift the values of the input by `periods` rows, filling the vacated rows with
+/// `fill_value`.
+///
+/// A positive `periods` shifts values towards the end (lag), and a negative `periods` shifts
+/// values towards the start (lead). Values are carried across chunks of a stream.
+#[pyfunction]
+#[pyo3(signature = (input, periods=1, fill_value=None))]
+pub fn shift<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    periods: i64,
+    fill_value: Option<AnyDatum>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    shift_kernel(py, input, periods, fill_value, data_type, |_, shifted| {
+        Ok(shifted)
+    })
+}
+
+/// Compute the difference between each value and the value `periods` rows earlier.
+///
+/// The output type is that of subtracting two input values, e.g. a Duration for Timestamp input.
+/// An error is returned on overflow.
+#[pyfunction]
+#[pyo3(signature = (input, periods=1))]
+pub fn diff<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    periods: i64,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    let empty = new_empty_array(&data_type);
+    let output_type = numeric::sub(&empty, &empty)
+        .map_err(|_| PyValueError::new_err(format!("{data_type} not supported in diff")))?
+        .data_type()
+        .clone();
+    shift_kernel(py, input, periods, None, output_type, |array, shifted| {
+        numeric::sub(&array, &shifted)
+    })
+}
+
+/// Compute the relative change between each value and the value `periods` rows earlier, as
+/// Float64.
+#[pyfunction]
+#[pyo3(signature = (input, periods=1))]
+pub fn pct_change<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    periods: i64,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let data_type = input.field()?.data_type().clone();
+    if !data_type.is_numeric() {
+        return Err(
+            PyValueError::new_err(format!("{data_type} not supported in pct_change")).into(),
+        );
+    }
+    shift_kernel(
+        py,
+        input,
+        periods,
+        None,
+        DataType::Float64,
+        |array, shifted| {
+            let array = arrow_cast::cast(&array, &DataType::Float64)?;
+            let shifted = arrow_cast::cast(&shifted, &DataType::Float64)?;
+            numeric::div(&numeric::sub(&array, &shifted)?, &shifted)
+        },
+    )
+}
@@ -0,0 +1,213 @@
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, ArrayRef, UInt32Array, UInt64Array};
+use arrow_ord::sort::{LexicographicalComparator, SortColumn, SortOptions};
+use arrow_schema::{ArrowError, DataType};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3Array, Arro3Table};
+use pyo3_arrow::input::AnyArray;
+use pyo3_arrow::PyArray;
+
+use crate::utils::{concat_input, struct_to_table, ColumnRef};
+
+#[derive(Debug, Clone, Copy)]
+pub enum SortOrder {
+    /// Sort from smallest to largest
+    Ascending,
+    /// Sort from largest to smallest
+    Descending,
+}
+
+impl<'py> FromPyObject<'_, 'py> for SortOrder {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "ascending" => Ok(Self::Ascending),
+            "descending" => Ok(Self::Descending),
+            _ => Err(PyValueError::new_err("Unexpected sort order")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum NullPlacement {
+    /// Nulls are ordered before all valid values
+    AtStart,
+    /// Nulls are ordered after all valid values
+    AtEnd,
+}
+
+impl<'py> FromPyObject<'_, 'py> for NullPlacement {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "at_start" => Ok(Self::AtStart),
+            "at_end" => Ok(Self::AtEnd),
+            _ => Err(PyValueError::new_err("Unexpected null placement")),
+        }
+    }
+}
+
+pub(crate) fn sort_options(order: SortOrder, null_placement: NullPlacement) -> SortOptions {
+    SortOptions {
+        descending: matches!(order, SortOrder::Descending),
+        nulls_first: matches!(null_placement, NullPlacement::AtStart),
+    }
+}
+
+/// A single sort key: a column reference, optionally paired with a sort order and a null
+/// placement that override the function-level defaults.
+#[derive(FromPyObject, Debug, Clone)]
+pub(crate) enum SortKey {
+    Column(ColumnRef),
+    WithOrder(ColumnRef, SortOrder),
+    WithNullPlacement(ColumnRef, SortOrder, NullPlacement),
+}
+
+impl SortKey {
+    fn into_parts(self) -> (ColumnRef, Option<SortOrder>, Option<NullPlacement>) {
+        match self {
+            Self::Column(column) => (column, None, None),
+            Self::WithOrder(column, order) => (column, Some(order), None),
+            Self::WithNullPlacement(column, order, null_placement) => {
+                (column, Some(order), Some(null_placement))
+            }
+        }
+    }
+}
+
+/// Resolve `sort_keys` against `array`, returning the columns to compare.
+///
+/// Struct-typed input (i.e. a RecordBatch or Table) is treated as tabular: each sort key selects
+/// one of its children, and if no keys are provided all columns are used in order. Any other
+/// input is sorted by its own values, and sort keys may not be provided.
+pub(crate) fn sort_columns(
+    array: &ArrayRef,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+) -> PyResult<Vec<SortColumn>> {
+    let default_options = sort_options(order, null_placement);
+    match array.data_type() {
+        DataType::Struct(fields) => {
+            let struct_array = array.as_struct();
+            match sort_keys {
+                Some(sort_keys) => sort_keys
+                    .into_iter()
+                    .map(|key| {
+                        let (column, key_order, key_null_placement) = key.into_parts();
+                        let position = column.into_position(fields)?;
+                        Ok(SortColumn {
+                            values: struct_array.column(position).clone(),
+                            options: Some(sort_options(
+                                key_order.unwrap_or(order),
+                                key_null_placement.unwrap_or(null_placement),
+                            )),
+                        })
+                    })
+                    .collect(),
+                None => Ok(struct_array
+                    .columns()
+                    .iter()
+                    .map(|column| SortColumn {
+                        values: column.clone(),
+                        options: Some(default_options),
+                    })
+                    .collect()),
+            }
+        }
+        _ => {
+            if sort_keys.is_some() {
+                return Err(PyValueError::new_err(
+                    "sort_keys may only be provided for tabular input",
+                ));
+            }
+            Ok(vec![SortColumn {
+                values: array.clone(),
+                options: Some(default_options),
+            }])
+        }
+    }
+}
+
+/// Compute the row positions that would stably sort `columns`, each of which has length `len`.
+pub(crate) fn lexsort(columns: &[SortColumn], len: usize) -> Result<Vec<usize>, ArrowError> {
+    let comparator = LexicographicalComparator::try_new(columns)?;
+    let mut indices = (0..len).collect::<Vec<_>>();
+    indices.sort_by(|a, b| comparator.compare(*a, *b));
+    Ok(indices)
+}
+
+/// Compute the indices that would stably sort `columns`, each of which has length `len`.
+///
+/// Returns a UInt32 array, unless `len` does not fit in a `u32`, in which case returns a UInt64
+/// array.
+pub(crate) fn lexsort_to_indices(
+    columns: &[SortColumn],
+    len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let indices = lexsort(columns, len)?;
+
+    if len <= u32::MAX as usize {
+        Ok(Arc::new(UInt32Array::from_iter_values(
+            indices.into_iter().map(|i| i as u32),
+        )))
+    } else {
+        Ok(Arc::new(UInt64Array::from_iter_values(
+            indices.into_iter().map(|i| i as u64),
+        )))
+    }
+}
+
+/// Return the indices that would sort the input.
+///
+/// For ChunkedArray, Table and stream input, the sort is global across all chunks.
+#[pyfunction]
+#[pyo3(signature = (input, sort_keys=None, *, order=SortOrder::Ascending, null_placement=NullPlacement::AtEnd))]
+pub fn sort_indices(
+    py: Python,
+    input: AnyArray,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+) -> PyArrowResult<Arro3Array> {
+    let (array, _field) = concat_input(input)?;
+    let columns = sort_columns(&array, sort_keys, order, null_placement)?;
+    let indices = py.detach(|| lexsort_to_indices(&columns, array.len()))?;
+    Ok(indices.into())
+}
+
+/// Sort the input, returning an Array for array input or a Table for tabular input.
+///
+/// For ChunkedArray, Table and stream input, the sort is global across all chunks.
+#[pyfunction]
+#[pyo3(signature = (input, sort_keys=None, *, order=SortOrder::Ascending, null_placement=NullPlacement::AtEnd))]
+pub fn sort<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let (array, field) = concat_input(input)?;
+    let columns = sort_columns(&array, sort_keys, order, null_placement)?;
+    let sorted = py.detach(|| {
+        let indices = lexsort_to_indices(&columns, array.len())?;
+        arrow_select::take::take(array.as_ref(), indices.as_ref(), None)
+    })?;
+
+    match field.data_type() {
+        DataType::Struct(_) => {
+            Ok(Arro3Table::from(struct_to_table(&sorted, &field)?).into_bound_py_any(py)?)
+        }
+        _ => Ok(Arro3Array::from(PyArray::new(sorted, field)).into_bound_py_any(py)?),
+    }
+}
@@ -0,0 +1,444 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use arrow_array::builder::GenericStringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Int32Type, Int64Type};
+use arrow_array::{
+    make_array, Array, ArrayRef, ArrowPrimitiveType, GenericStringArray, OffsetSizeTrait,
+    PrimitiveArray, StringArrayType, StringViewArray,
+};
+use arrow_buffer::ArrowNativeType;
+use arrow_schema::{ArrowError, DataType, Field};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use pyo3::IntoPyObjectExt;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3Array, Arro3ArrayReader};
+use pyo3_arrow::ffi::{ArrayIterator, ArrayReader};
+use pyo3_arrow::input::AnyArray;
+use pyo3_arrow::PyArrayReader;
+
+use crate::utils::{unary_kernel, AlignedChunks};
+
+/// Check that `data_type` is a string type, or a dictionary with string values.
+pub(crate) fn check_string_type(data_type: &DataType) -> Result<(), ArrowError> {
+    match data_type {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => Ok(()),
+        DataType::Dictionary(_, value_type) => check_string_type(value_type),
+        d => Err(ArrowError::ComputeError(format!(
+            "Expected string array, got {d}"
+        ))),
+    }
+}
+
+/// Access the value at index `i` of a Utf8, LargeUtf8 or Utf8View array, or `None` if null.
+pub(crate) fn string_value(array: &dyn Array, i: usize) -> Option<&str> {
+    if array.is_null(i) {
+        return None;
+    }
+    match array.data_type() {
+        DataType::Utf8 => Some(array.as_string::<i32>().value(i)),
+        DataType::LargeUtf8 => Some(array.as_string::<i64>().value(i)),
+        DataType::Utf8View => Some(array.as_string_view().value(i)),
+        d => unreachable!("Unexpected string type {d}"),
+    }
+}
+
+/// Apply `op` to each valid value of a string array, preserving its layout. Values for which `op`
+/// returns `None` become null.
+///
+/// Dictionary-encoded input is transformed by applying `op` to its dictionary values only.
+pub(crate) fn map_strings<F>(array: &dyn Array, op: &F) -> Result<ArrayRef, ArrowError>
+where
+    F: for<'a> Fn(&'a str) -> Option<Cow<'a, str>>,
+{
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        let values = map_strings(dict.values().as_ref(), op)?;
+        return Ok(dict.with_values(values));
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => Ok(Arc::new(map_generic_strings(array.as_string::<i32>(), op))),
+        DataType::LargeUtf8 => Ok(Arc::new(map_generic_strings(array.as_string::<i64>(), op))),
+        DataType::Utf8View => Ok(Arc::new(
+            array
+                .as_string_view()
+                .iter()
+                .map(|value| value.and_then(op))
+                .collect::<StringViewArray>(),
+        )),
+        d => Err(ArrowError::ComputeError(format!(
+            "Expected string array, got {d}"
+        ))),
+    }
+}
+
+#[inline(never)]
+fn map_generic_strings<O: OffsetSizeTrait, F>(
+    array: &GenericStringArray<O>,
+    op: &F,
+) -> GenericStringArray<O>
+where
+    F: for<'a> Fn(&'a str) -> Option<Cow<'a, str>>,
+{
+    array.iter().map(|value| value.and_then(op)).collect()
+}
+
+/// Apply a string kernel that preserves the layout of its input.
+fn string_transform<'py, F>(
+    py: Python<'py>,
+    input: AnyArray,
+    op: F,
+) -> PyArrowResult<Bound<'py, PyAny>>
+where
+    F: for<'a> Fn(&'a str) -> Cow<'a, str> + Send + 'static,
+{
+    let output_type = input.field()?.data_type().clone();
+    check_string_type(&output_type)?;
+    unary_kernel(py, input, output_type, move |array| {
+        map_strings(array, &|s| Some(op(s)))
+    })
+}
+
+fn utf8_length_type(data_type: &DataType) -> Result<DataType, ArrowError> {
+    match data_type {
+        DataType::Utf8 | DataType::Utf8View => Ok(DataType::Int32),
+        DataType::LargeUtf8 => Ok(DataType::Int64),
+        DataType::Dictionary(key_type, value_type) => Ok(DataType::Dictionary(
+            key_type.clone(),
+            Box::new(utf8_length_type(value_type)?),
+        )),
+        d => Err(ArrowError::ComputeError(format!(
+            "Expected string array, got {d}"
+        ))),
+    }
+}
+
+fn utf8_length_array(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    if let Some(dict) = array.as_any_dictionary_opt() {
+        let values = utf8_length_array(dict.values().as_ref())?;
+        return Ok(dict.with_values(values));
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => Ok(Arc::new(char_lengths::<_, Int32Type>(
+            array.as_string::<i32>(),
+        ))),
+        DataType::LargeUtf8 => Ok(Arc::new(char_lengths::<_, Int64Type>(
+            array.as_string::<i64>(),
+        ))),
+        DataType::Utf8View => Ok(Arc::new(char_lengths::<_, Int32Type>(
+            array.as_string_view(),
+        ))),
+        d => Err(ArrowError::ComputeError(format!(
+            "Expected string array, got {d}"
+        ))),
+    }
+}
+
+#[inline(never)]
+fn char_lengths<'a, S, T>(array: S) -> PrimitiveArray<T>
+where
+    S: StringArrayType<'a>,
+    T: ArrowPrimitiveType,
+{
+    array
+        .iter()
+        .map(|value| value.map(|s| T::Native::from_usize(s.chars().count()).unwrap()))
+        .collect()
+}
+
+/// Return the number of characters (not bytes) in each string.
+#[pyfunction]
+pub fn utf8_length<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    let output_type = utf8_length_type(input.field()?.data_type())?;
+    unary_kernel(py, input, output_type, utf8_length_array)
+}
+
+#[pyfunction]
+pub fn upper<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    string_transform(py, input, |s| Cow::Owned(s.to_uppercase()))
+}
+
+#[pyfunction]
+pub fn lower<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    string_transform(py, input, |s| Cow::Owned(s.to_lowercase()))
+}
+
+#[pyfunction]
+pub fn reverse<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    string_transform(py, input, |s| Cow::Owned(s.chars().rev().collect()))
+}
+
+/// Slice `s` by character positions.
+///
+/// A negative `start` counts from the end of the string. If `length` is `None`, slices to the end
+/// of the string.
+fn substring_chars(s: &str, start: i64, length: Option<usize>) -> &str {
+    let start = if start >= 0 {
+        start as usize
+    } else {
+        s.chars()
+            .count()
+            .saturating_sub(start.unsigned_abs() as usize)
+    };
+    let begin = s
+        .char_indices()
+        .nth(start)
+        .map(|(i, _)| i)
+        .unwrap_or(s.len());
+    let rest = &s[begin..];
+    let end = match length {
+        Some(length) => rest
+            .char_indices()
+            .nth(length)
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len()),
+        None => rest.len(),
+    };
+    &rest[..end]
+}
+
+#[pyfunction]
+#[pyo3(signature = (input, start, length=None))]
+pub fn substring<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    start: i64,
+    length: Option<usize>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    string_transform(py, input, move |s| {
+        Cow::Borrowed(substring_chars(s, start, length))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (input, characters=None))]
+pub fn strip<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    characters: Option<String>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    match characters {
+        Some(characters) => string_transform(py, input, move |s| {
+            Cow::Borrowed(s.trim_matches(|c| characters.contains(c)))
+        }),
+        None => string_transform(py, input, |s| Cow::Borrowed(s.trim())),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (input, characters=None))]
+pub fn lstrip<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    characters: Option<String>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    match characters {
+        Some(characters) => string_transform(py, input, move |s| {
+            Cow::Borrowed(s.trim_start_matches(|c| characters.contains(c)))
+        }),
+        None => string_transform(py, input, |s| Cow::Borrowed(s.trim_start())),
+    }
+}
+
+#[pyfunction]
+#[pyo3(signature = (input, characters=None))]
+pub fn rstrip<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    characters: Option<String>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    match characters {
+        Some(characters) => string_transform(py, input, move |s| {
+            Cow::Borrowed(s.trim_end_matches(|c| characters.contains(c)))
+        }),
+        None => string_transform(py, input, |s| Cow::Borrowed(s.trim_end())),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PadSide {
+    /// Pad on the left, right-aligning the string
+    Left,
+    /// Pad on the right, left-aligning the string
+    Right,
+    /// Pad on both sides, centering the string
+    Both,
+}
+
+impl<'py> FromPyObject<'_, 'py> for PadSide {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            "both" => Ok(Self::Both),
+            _ => Err(PyValueError::new_err("Unexpected pad side")),
+        }
+    }
+}
+
+fn pad_chars(s: &str, width: usize, padding: char, side: PadSide) -> Cow<'_, str> {
+    let len = s.chars().count();
+    if len >= width {
+        return Cow::Borrowed(s);
+    }
+    let total = width - len;
+    let (left, right) = match side {
+        PadSide::Left => (total, 0),
+        PadSide::Right => (0, total),
+        PadSide::Both => (total / 2, total - total / 2),
+    };
+    let mut out = String::with_capacity(s.len() + total * padding.len_utf8());
+    out.extend(std::iter::repeat(padding).take(left));
+    out.push_str(s);
+    out.extend(std::iter::repeat(padding).take(right));
+    Cow::Owned(out)
+}
+
+#[pyfunction]
+#[pyo3(signature = (input, width, *, side=PadSide::Left, padding=' '))]
+pub fn pad<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    width: usize,
+    side: PadSide,
+    padding: char,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    string_transform(py, input, move |s| pad_chars(s, width, padding, side))
+}
+
+/// Decode dictionary-encoded strings into their value type.
+pub(crate) fn decode_strings(array: &dyn Array) -> Result<ArrayRef, ArrowError> {
+    check_string_type(array.data_type())?;
+    match array.data_type() {
+        DataType::Dictionary(_, value_type) => arrow_cast::cast(array, value_type),
+        _ => Ok(make_array(array.to_data())),
+    }
+}
+
+fn is_large_string(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::LargeUtf8 => true,
+        DataType::Dictionary(_, value_type) => is_large_string(value_type),
+        _ => false,
+    }
+}
+
+fn concat_ws_arrays(arrays: &[ArrayRef], separator: &str) -> Result<ArrayRef, ArrowError> {
+    let arrays = arrays
+        .iter()
+        .map(|array| decode_strings(array.as_ref()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let len = arrays[0].len();
+    if arrays.iter().any(|array| array.len() != len) {
+        return Err(ArrowError::ComputeError(
+            "Arrays passed to concat_ws must have the same length".to_string(),
+        ));
+    }
+
+    if arrays
+        .iter()
+        .any(|array| is_large_string(array.data_type()))
+    {
+        Ok(Arc::new(concat_ws_generic::<i64>(&arrays, separator, len)))
+    } else {
+        Ok(Arc::new(concat_ws_generic::<i32>(&arrays, separator, len)))
+    }
+}
+
+#[inline(never)]
+fn concat_ws_generic<O: OffsetSizeTrait>(
+    arrays: &[ArrayRef],
+    separator: &str,
+    len: usize,
+) -> GenericStringArray<O> {
+    let mut builder = GenericStringBuilder::<O>::with_capacity(len, 1024);
+    let mut row = String::new();
+    for i in 0..len {
+        row.clear();
+        let mut first = true;
+        for value in arrays
+            .iter()
+            .filter_map(|array| string_value(array.as_ref(), i))
+        {
+            if !first {
+                row.push_str(separator);
+            }
+            row.push_str(value);
+            first = false;
+        }
+        builder.append_value(&row);
+    }
+    builder.finish()
+}
+
+/// Concatenate strings element-wise, separated by `separator`, skipping nulls.
+#[pyfunction]
+#[pyo3(signature = (separator, *inputs))]
+pub fn concat_ws<'py>(
+    py: Python<'py>,
+    separator: String,
+    inputs: &Bound<'py, PyTuple>,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let inputs = inputs
+        .iter()
+        .map(|input| input.extract::<AnyArray>())
+        .collect::<PyResult<Vec<_>>>()?;
+    if inputs.is_empty() {
+        return Err(PyValueError::new_err("concat_ws requires at least one input").into());
+    }
+
+    if inputs
+        .iter()
+        .all(|input| matches!(input, AnyArray::Array(_)))
+    {
+        let arrays = inputs
+            .into_iter()
+            .map(|input| match input {
+                AnyArray::Array(array) => array.into_inner().0,
+                AnyArray::Stream(_) => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        let out = concat_ws_arrays(&arrays, &separator)?;
+        return Ok(Arro3Array::from(out).into_bound_py_any(py)?);
+    }
+
+    if !inputs
+        .iter()
+        .all(|input| matches!(input, AnyArray::Stream(_)))
+    {
+        return Err(PyValueError::new_err("Unsupported combination of array and stream").into());
+    }
+
+    let readers = inputs
+        .into_iter()
+        .map(|input| input.into_reader())
+        .collect::<PyResult<Vec<_>>>()?;
+    let mut large = false;
+    for reader in readers.iter() {
+        let data_type = reader.field().data_type().clone();
+        check_string_type(&data_type)?;
+        large |= is_large_string(&data_type);
+    }
+    let output_type = if large {
+        DataType::LargeUtf8
+    } else {
+        DataType::Utf8
+    };
+
+    let iter =
+        AlignedChunks::new(readers).map(move |chunks| concat_ws_arrays(&chunks?, &separator));
+    Ok(
+        Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+            iter,
+            Field::new("", output_type, true).into(),
+        ))))
+        .into_bound_py_any(py)?,
+    )
+}
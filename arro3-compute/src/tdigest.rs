@@ -0,0 +1,164 @@
+//! A mergeable t-digest for approximate quantiles.
+//!
+//! This is the merging variant described in Dunning & Ertl, "Computing Extremely Accurate
+//! Quantiles Using t-Digests", using the `k1` (arcsine) scale function.
+
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, Copy)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl Centroid {
+    fn merge(&mut self, other: &Centroid) {
+        let weight = self.weight + other.weight;
+        self.mean += (other.mean - self.mean) * other.weight / weight;
+        self.weight = weight;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct TDigest {
+    /// The compression parameter. Larger values retain more centroids and are more accurate.
+    delta: f64,
+    centroids: Vec<Centroid>,
+    /// Values that have been added but not yet merged into `centroids`
+    buffer: Vec<f64>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    pub(crate) fn new(delta: usize) -> Self {
+        Self {
+            delta: delta.max(1) as f64,
+            centroids: vec![],
+            buffer: vec![],
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    fn buffer_capacity(&self) -> usize {
+        self.delta as usize * 5
+    }
+
+    /// Add a single value. NaN values are ignored.
+    pub(crate) fn add(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.buffer.push(value);
+        if self.buffer.len() >= self.buffer_capacity() {
+            self.flush();
+        }
+    }
+
+    /// Merge the state of `other` into this digest.
+    pub(crate) fn merge(&mut self, mut other: TDigest) {
+        other.flush();
+        self.flush();
+        self.count += other.count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.extend(other.centroids);
+        self.centroids = self.compress(centroids);
+    }
+
+    /// Merge any buffered values into the centroids.
+    fn flush(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut centroids = std::mem::take(&mut self.centroids);
+        for value in self.buffer.drain(..) {
+            self.count += 1.0;
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+            centroids.push(Centroid {
+                mean: value,
+                weight: 1.0,
+            });
+        }
+        self.centroids = self.compress(centroids);
+    }
+
+    fn k(&self, q: f64) -> f64 {
+        self.delta / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn k_inverse(&self, k: f64) -> f64 {
+        ((2.0 * PI * k / self.delta).sin() + 1.0) / 2.0
+    }
+
+    /// Merge adjacent centroids as long as each stays within the size bound of the scale
+    /// function.
+    fn compress(&self, mut centroids: Vec<Centroid>) -> Vec<Centroid> {
+        if centroids.len() <= 1 {
+            return centroids;
+        }
+        centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total = centroids.iter().map(|c| c.weight).sum::<f64>();
+
+        let mut output = Vec::with_capacity(centroids.len());
+        let mut iter = centroids.into_iter();
+        let mut current = iter.next().unwrap();
+        let mut weight_so_far = 0.0;
+        let mut q_limit = self.k_inverse(self.k(0.0) + 1.0);
+        for centroid in iter {
+            let q = (weight_so_far + current.weight + centroid.weight) / total;
+            if q <= q_limit {
+                current.merge(&centroid);
+            } else {
+                weight_so_far += current.weight;
+                output.push(current);
+                q_limit = self.k_inverse(self.k(weight_so_far / total) + 1.0);
+                current = centroid;
+            }
+        }
+        output.push(current);
+        output
+    }
+
+    /// Estimate the `q`-th quantile, or `None` if no values have been added.
+    pub(crate) fn quantile(&mut self, q: f64) -> Option<f64> {
+        self.flush();
+        if self.centroids.is_empty() {
+            return None;
+        }
+        if self.centroids.len() == 1 {
+            return Some(self.centroids[0].mean);
+        }
+
+        // Each centroid's weight is treated as centered on its mean, and the quantile is
+        // interpolated between adjacent centers, or between the outer centers and min/max.
+        let target = q * self.count;
+        let first = &self.centroids[0];
+        if target < first.weight / 2.0 {
+            let fraction = target / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * fraction);
+        }
+
+        let mut cumulative = 0.0;
+        for pair in self.centroids.windows(2) {
+            let (left, right) = (&pair[0], &pair[1]);
+            let left_center = cumulative + left.weight / 2.0;
+            let right_center = cumulative + left.weight + right.weight / 2.0;
+            if target < right_center {
+                let fraction = (target - left_center) / (right_center - left_center);
+                return Some(left.mean + (right.mean - left.mean) * fraction);
+            }
+            cumulative += left.weight;
+        }
+
+        let last = self.centroids.last().unwrap();
+        let last_center = self.count - last.weight / 2.0;
+        let fraction = ((target - last_center) / (last.weight / 2.0)).min(1.0);
+        Some(last.mean + (self.max - last.mean) * fraction)
+    }
+}
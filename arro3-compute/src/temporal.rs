@@ -1,4 +1,21 @@
-use arrow_schema::{DataType, Field};
+use std::fmt::Write;
+use std::sync::Arc;
+
+use arrow_array::builder::StringBuilder;
+use arrow_array::cast::AsArray;
+use arrow_array::temporal_conversions::as_datetime;
+use arrow_array::types::{
+    ArrowPrimitiveType, ArrowTimestampType, Date32Type, Date64Type, IntervalMonthDayNanoType,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType,
+};
+use arrow_array::{make_array, Array, ArrayRef, Date32Array, Date64Array, Datum, Int64Array};
+use arrow_schema::{ArrowError, DataType, Field, IntervalUnit, TimeUnit};
+use chrono::format::{Fixed, Item, StrftimeItems};
+use chrono::{
+    DateTime, Datelike, LocalResult, Months, NaiveDate, NaiveDateTime, NaiveTime, Offset,
+    TimeDelta, TimeZone,
+};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::IntoPyObjectExt;
@@ -6,7 +23,10 @@ use pyo3_arrow::error::PyArrowResult;
 use pyo3_arrow::export::{Arro3Array, Arro3ArrayReader};
 use pyo3_arrow::ffi::ArrayIterator;
 use pyo3_arrow::input::AnyArray;
-use pyo3_arrow::PyArrayReader;
+use pyo3_arrow::{PyArrayReader, PyArrowTz};
+
+use crate::strings::{check_string_type, decode_strings, string_value};
+use crate::utils::{binary_datum_kernel, broadcast, broadcast_len, unary_kernel, AnyDatumOrStream};
 
 pub enum DatePart {
     /// Quarter of the year, in range `1..=4`
@@ -114,3 +134,1333 @@ pub fn date_part<'py>(
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemporalUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+impl<'py> FromPyObject<'_, 'py> for TemporalUnit {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "year" => Ok(Self::Year),
+            "quarter" => Ok(Self::Quarter),
+            "month" => Ok(Self::Month),
+            "week" => Ok(Self::Week),
+            "day" => Ok(Self::Day),
+            "hour" => Ok(Self::Hour),
+            "minute" => Ok(Self::Minute),
+            "second" => Ok(Self::Second),
+            "millisecond" => Ok(Self::Millisecond),
+            "microsecond" => Ok(Self::Microsecond),
+            "nanosecond" => Ok(Self::Nanosecond),
+            _ => Err(PyValueError::new_err("Unexpected temporal unit")),
+        }
+    }
+}
+
+impl TemporalUnit {
+    /// The length of this unit in nanoseconds, or `None` for calendar units of varying length.
+    fn nanos(&self) -> Option<i128> {
+        match self {
+            Self::Year | Self::Quarter | Self::Month => None,
+            Self::Week => Some(7 * 86_400_000_000_000),
+            Self::Day => Some(86_400_000_000_000),
+            Self::Hour => Some(3_600_000_000_000),
+            Self::Minute => Some(60_000_000_000),
+            Self::Second => Some(1_000_000_000),
+            Self::Millisecond => Some(1_000_000),
+            Self::Microsecond => Some(1_000),
+            Self::Nanosecond => Some(1),
+        }
+    }
+
+    /// The length of this unit in months, or `None` for units of fixed length.
+    fn months(&self) -> Option<i64> {
+        match self {
+            Self::Year => Some(12),
+            Self::Quarter => Some(3),
+            Self::Month => Some(1),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TemporalRounding {
+    Floor,
+    Ceil,
+    Round,
+}
+
+/// Rounds wall-clock times to a multiple of a temporal unit.
+///
+/// Fixed-length units are counted from the Unix epoch, except for weeks, which are counted from
+/// the first Monday (or Sunday) after the epoch. Calendar units are counted in months from
+/// January 1970.
+#[derive(Debug, Clone, Copy)]
+struct TemporalRounder {
+    multiple: i64,
+    unit: TemporalUnit,
+    week_starts_monday: bool,
+    rounding: TemporalRounding,
+}
+
+fn to_nanos(t: NaiveDateTime) -> i128 {
+    let t = t.and_utc();
+    t.timestamp() as i128 * 1_000_000_000 + t.timestamp_subsec_nanos() as i128
+}
+
+fn from_nanos(nanos: i128) -> Option<NaiveDateTime> {
+    let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+    let nanos = nanos.rem_euclid(1_000_000_000) as u32;
+    DateTime::from_timestamp(secs, nanos).map(|t| t.naive_utc())
+}
+
+/// The first instant of the month `months` months after January 1970.
+fn from_months(months: i64) -> Option<NaiveDateTime> {
+    let year = i32::try_from(1970 + months.div_euclid(12)).ok()?;
+    let month = months.rem_euclid(12) as u32 + 1;
+    NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)
+}
+
+impl TemporalRounder {
+    /// The first boundary, in nanoseconds since the epoch, for units of fixed length.
+    fn origin(&self) -> i128 {
+        // 1970-01-05 was a Monday and 1970-01-04 a Sunday
+        match (self.unit, self.week_starts_monday) {
+            (TemporalUnit::Week, true) => 4 * 86_400_000_000_000,
+            (TemporalUnit::Week, false) => 3 * 86_400_000_000_000,
+            _ => 0,
+        }
+    }
+
+    /// Return the index of the period containing `t`, counting from the first boundary.
+    fn period_index(&self, t: NaiveDateTime) -> Option<i128> {
+        if let Some(months) = self.unit.months() {
+            let span = months.checked_mul(self.multiple)?;
+            let elapsed = (t.year() as i64 - 1970) * 12 + t.month0() as i64;
+            Some(elapsed.div_euclid(span) as i128)
+        } else {
+            let period = self.unit.nanos()?.checked_mul(self.multiple as i128)?;
+            Some((to_nanos(t) - self.origin()).div_euclid(period))
+        }
+    }
+
+    /// Return the last boundary at or before `t` and the first boundary after it.
+    fn bounds(&self, t: NaiveDateTime) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        if let Some(months) = self.unit.months() {
+            let span = months.checked_mul(self.multiple)?;
+            let elapsed = (t.year() as i64 - 1970) * 12 + t.month0() as i64;
+            let floor = elapsed.div_euclid(span) * span;
+            Some((from_months(floor)?, from_months(floor.checked_add(span)?)?))
+        } else {
+            let period = self.unit.nanos()?.checked_mul(self.multiple as i128)?;
+            let origin = self.origin();
+            let floor = origin + (to_nanos(t) - origin).div_euclid(period) * period;
+            Some((from_nanos(floor)?, from_nanos(floor + period)?))
+        }
+    }
+
+    fn round(&self, t: NaiveDateTime) -> Option<NaiveDateTime> {
+        let (floor, ceil) = self.bounds(t)?;
+        match self.rounding {
+            TemporalRounding::Floor => Some(floor),
+            TemporalRounding::Ceil if floor == t => Some(t),
+            TemporalRounding::Ceil => Some(ceil),
+            // Ties round up
+            TemporalRounding::Round if t - floor < ceil - t => Some(floor),
+            TemporalRounding::Round => Some(ceil),
+        }
+    }
+
+    /// Round `t`, which is a wall-clock time in `tz` if given, and return the result in UTC.
+    ///
+    /// A result that is ambiguous in `tz` resolves to the instant with the same UTC offset as `t`,
+    /// so that rounding within a repeated hour stays within it. If neither instant has that
+    /// offset, floor picks the latest instant at or before `t`, ceil the earliest at or after `t`,
+    /// and round the nearest. A result that does not exist in `tz` (i.e. falls in a DST gap) is an
+    /// error.
+    fn round_in(
+        &self,
+        t: NaiveDateTime,
+        tz: Option<PyArrowTz>,
+    ) -> Result<NaiveDateTime, ArrowError> {
+        let out_of_range = || ArrowError::ComputeError(format!("Rounding {t} is out of range"));
+        let Some(tz) = tz else {
+            return self.round(t).ok_or_else(out_of_range);
+        };
+        let input = tz.from_utc_datetime(&t);
+        let local = input.naive_local();
+        let rounded = self.round(local).ok_or_else(out_of_range)?;
+        match tz.from_local_datetime(&rounded) {
+            LocalResult::Single(rounded) => Ok(rounded.naive_utc()),
+            LocalResult::Ambiguous(earliest, latest) => {
+                let offset = input.offset().fix();
+                for candidate in [earliest, latest] {
+                    if candidate.offset().fix() == offset {
+                        return Ok(candidate.naive_utc());
+                    }
+                }
+                let (earliest, latest) = (earliest.naive_utc(), latest.naive_utc());
+                Ok(match self.rounding {
+                    TemporalRounding::Floor if latest <= t => latest,
+                    TemporalRounding::Floor => earliest,
+                    TemporalRounding::Ceil if earliest >= t => earliest,
+                    TemporalRounding::Ceil => latest,
+                    TemporalRounding::Round if (t - earliest).abs() < (latest - t).abs() => {
+                        earliest
+                    }
+                    TemporalRounding::Round => latest,
+                })
+            }
+            LocalResult::None => Err(ArrowError::ComputeError(format!(
+                "Rounding {local} gives {rounded}, which does not exist in the time zone"
+            ))),
+        }
+    }
+}
+
+fn round_timestamps<T: ArrowTimestampType>(
+    array: &dyn Array,
+    tz: Option<PyArrowTz>,
+    rounder: &TemporalRounder,
+) -> Result<ArrayRef, ArrowError> {
+    let array = array.as_primitive::<T>();
+    let out = array
+        .try_unary::<_, T, ArrowError>(|value| {
+            let t = as_datetime::<T>(value).ok_or_else(|| {
+                ArrowError::ComputeError(format!("Timestamp {value} is out of range"))
+            })?;
+            let rounded = rounder.round_in(t, tz)?;
+            T::make_value(rounded)
+                .ok_or_else(|| ArrowError::ComputeError(format!("Rounding {t} is out of range")))
+        })?
+        .with_data_type(array.data_type().clone());
+    Ok(Arc::new(out))
+}
+
+fn round_temporal_array(
+    array: &dyn Array,
+    rounder: &TemporalRounder,
+) -> Result<ArrayRef, ArrowError> {
+    match array.data_type() {
+        DataType::Date32 => {
+            let out = array
+                .as_primitive::<Date32Type>()
+                .try_unary::<_, Date32Type, ArrowError>(|value| {
+                    let t = as_datetime::<Date32Type>(value as i64).ok_or_else(|| {
+                        ArrowError::ComputeError(format!("Date {value} is out of range"))
+                    })?;
+                    Ok(Date32Type::from_naive_date(
+                        rounder.round_in(t, None)?.date(),
+                    ))
+                })?;
+            Ok(Arc::new(out))
+        }
+        DataType::Date64 => {
+            let out = array
+                .as_primitive::<Date64Type>()
+                .try_unary::<_, Date64Type, ArrowError>(|value| {
+                    let t = as_datetime::<Date64Type>(value).ok_or_else(|| {
+                        ArrowError::ComputeError(format!("Date {value} is out of range"))
+                    })?;
+                    Ok(rounder.round_in(t, None)?.and_utc().timestamp_millis())
+                })?;
+            Ok(Arc::new(out))
+        }
+        DataType::Timestamp(unit, tz) => {
+            let tz = tz.as_deref().map(str::parse::<PyArrowTz>).transpose()?;
+            match unit {
+                TimeUnit::Second => round_timestamps::<TimestampSecondType>(array, tz, rounder),
+                TimeUnit::Millisecond => {
+                    round_timestamps::<TimestampMillisecondType>(array, tz, rounder)
+                }
+                TimeUnit::Microsecond => {
+                    round_timestamps::<TimestampMicrosecondType>(array, tz, rounder)
+                }
+                TimeUnit::Nanosecond => {
+                    round_timestamps::<TimestampNanosecondType>(array, tz, rounder)
+                }
+            }
+        }
+        d => Err(ArrowError::ComputeError(format!(
+            "{d} not supported in temporal rounding"
+        ))),
+    }
+}
+
+fn round_temporal_with<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    rounder: TemporalRounder,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    if rounder.multiple <= 0 {
+        return Err(PyValueError::new_err("multiple must be positive").into());
+    }
+    let data_type = input.field()?.data_type().clone();
+    if data_type == DataType::Date32 && rounder.unit.nanos().is_some_and(|n| n < 86_400_000_000_000)
+    {
+        return Err(PyValueError::new_err(
+            "Date32 input can only be rounded to a multiple of a day or longer",
+        )
+        .into());
+    }
+    unary_kernel(py, input, data_type, move |array| {
+        round_temporal_array(array, &rounder)
+    })
+}
+
+/// Truncate each value to the start of its `unit`, e.g. the first instant of its month.
+///
+/// Time zone-aware timestamps are truncated in local time.
+#[pyfunction]
+#[pyo3(signature = (input, unit, *, week_starts_monday=true))]
+pub fn date_trunc<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    unit: TemporalUnit,
+    week_starts_monday: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let rounder = TemporalRounder {
+        multiple: 1,
+        unit,
+        week_starts_monday,
+        rounding: TemporalRounding::Floor,
+    };
+    round_temporal_with(py, input, rounder)
+}
+
+/// Round each value down to a multiple of `multiple` `unit`s.
+#[pyfunction]
+#[pyo3(signature = (input, multiple=1, unit=TemporalUnit::Day, *, week_starts_monday=true))]
+pub fn floor_temporal<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    multiple: i64,
+    unit: TemporalUnit,
+    week_starts_monday: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let rounder = TemporalRounder {
+        multiple,
+        unit,
+        week_starts_monday,
+        rounding: TemporalRounding::Floor,
+    };
+    round_temporal_with(py, input, rounder)
+}
+
+/// Round each value up to a multiple of `multiple` `unit`s.
+#[pyfunction]
+#[pyo3(signature = (input, multiple=1, unit=TemporalUnit::Day, *, week_starts_monday=true))]
+pub fn ceil_temporal<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    multiple: i64,
+    unit: TemporalUnit,
+    week_starts_monday: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let rounder = TemporalRounder {
+        multiple,
+        unit,
+        week_starts_monday,
+        rounding: TemporalRounding::Ceil,
+    };
+    round_temporal_with(py, input, rounder)
+}
+
+/// Round each value to the nearest multiple of `multiple` `unit`s, with ties rounding up.
+#[pyfunction]
+#[pyo3(signature = (input, multiple=1, unit=TemporalUnit::Day, *, week_starts_monday=true))]
+pub fn round_temporal<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    multiple: i64,
+    unit: TemporalUnit,
+    week_starts_monday: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let rounder = TemporalRounder {
+        multiple,
+        unit,
+        week_starts_monday,
+        rounding: TemporalRounding::Round,
+    };
+    round_temporal_with(py, input, rounder)
+}
+
+/// How to resolve a local time that occurs twice in a time zone, e.g. when clocks go back.
+#[derive(Debug, Clone, Copy)]
+pub enum AmbiguousTime {
+    Raise,
+    Earliest,
+    Latest,
+}
+
+impl<'py> FromPyObject<'_, 'py> for AmbiguousTime {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "raise" => Ok(Self::Raise),
+            "earliest" => Ok(Self::Earliest),
+            "latest" => Ok(Self::Latest),
+            _ => Err(PyValueError::new_err("Unexpected ambiguous time handling")),
+        }
+    }
+}
+
+/// How to resolve a local time that does not exist in a time zone, e.g. when clocks go forward.
+#[derive(Debug, Clone, Copy)]
+pub enum NonexistentTime {
+    Raise,
+    Earliest,
+    Latest,
+}
+
+impl<'py> FromPyObject<'_, 'py> for NonexistentTime {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "raise" => Ok(Self::Raise),
+            "earliest" => Ok(Self::Earliest),
+            "latest" => Ok(Self::Latest),
+            _ => Err(PyValueError::new_err(
+                "Unexpected nonexistent time handling",
+            )),
+        }
+    }
+}
+
+fn parse_timezone(tz: &str) -> PyResult<PyArrowTz> {
+    tz.parse()
+        .map_err(|err: ArrowError| PyValueError::new_err(err.to_string()))
+}
+
+/// The smallest step of a timestamp with the given unit.
+fn resolution(unit: &TimeUnit) -> TimeDelta {
+    match unit {
+        TimeUnit::Second => TimeDelta::seconds(1),
+        TimeUnit::Millisecond => TimeDelta::milliseconds(1),
+        TimeUnit::Microsecond => TimeDelta::microseconds(1),
+        TimeUnit::Nanosecond => TimeDelta::nanoseconds(1),
+    }
+}
+
+/// Return the UTC instant at which the offset of `tz` changes, given a local time `local` that
+/// falls in the gap skipped by that change.
+fn gap_transition(tz: PyArrowTz, local: NaiveDateTime) -> NaiveDateTime {
+    let day = TimeDelta::days(1);
+    let before = tz.offset_from_utc_datetime(&(local - day)).fix();
+    let after = tz.offset_from_utc_datetime(&(local + day)).fix();
+    // Local times in the gap are at least `before` and less than `after` ahead of the
+    // transition, so it lies in `(low, high]`
+    let mut low = local - TimeDelta::seconds(after.local_minus_utc() as i64);
+    let mut high = local - TimeDelta::seconds(before.local_minus_utc() as i64);
+    while high - low > TimeDelta::nanoseconds(1) {
+        let mid = low + (high - low) / 2;
+        if tz.offset_from_utc_datetime(&mid).fix() == after {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+    high
+}
+
+/// Interpret `local` as a wall-clock time in `tz` and return the corresponding UTC time.
+///
+/// A nonexistent local time resolves to the first instant after the gap (`Latest`) or the last
+/// instant of size `resolution` before it (`Earliest`).
+fn localize(
+    local: NaiveDateTime,
+    tz: PyArrowTz,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+    resolution: TimeDelta,
+) -> Result<NaiveDateTime, ArrowError> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(t) => Ok(t.naive_utc()),
+        LocalResult::Ambiguous(earliest, latest) => match ambiguous {
+            AmbiguousTime::Raise => Err(ArrowError::ComputeError(format!(
+                "Local time {local} is ambiguous in the time zone"
+            ))),
+            AmbiguousTime::Earliest => Ok(earliest.naive_utc()),
+            AmbiguousTime::Latest => Ok(latest.naive_utc()),
+        },
+        LocalResult::None => match nonexistent {
+            NonexistentTime::Raise => Err(ArrowError::ComputeError(format!(
+                "Local time {local} does not exist in the time zone"
+            ))),
+            NonexistentTime::Earliest => Ok(gap_transition(tz, local) - resolution),
+            NonexistentTime::Latest => Ok(gap_transition(tz, local)),
+        },
+    }
+}
+
+fn assume_timezone_array<T: ArrowTimestampType>(
+    array: &dyn Array,
+    tz_name: &Arc<str>,
+    tz: PyArrowTz,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+) -> Result<ArrayRef, ArrowError> {
+    let resolution = resolution(&T::UNIT);
+    let out = array
+        .as_primitive::<T>()
+        .try_unary::<_, T, ArrowError>(|value| {
+            let local = as_datetime::<T>(value).ok_or_else(|| {
+                ArrowError::ComputeError(format!("Timestamp {value} is out of range"))
+            })?;
+            let utc = localize(local, tz, ambiguous, nonexistent, resolution)?;
+            T::make_value(utc).ok_or_else(|| {
+                ArrowError::ComputeError(format!("Localizing {local} is out of range"))
+            })
+        })?
+        .with_timezone(tz_name.clone());
+    Ok(Arc::new(out))
+}
+
+/// Interpret naive timestamps as wall-clock times in `tz`, returning timestamps with time zone
+/// `tz`.
+#[pyfunction]
+#[pyo3(
+    signature = (
+        input,
+        tz,
+        *,
+        ambiguous = AmbiguousTime::Raise,
+        nonexistent = NonexistentTime::Raise
+    ),
+    text_signature = "(input, tz, *, ambiguous = 'raise', nonexistent = 'raise')")
+]
+pub fn assume_timezone<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    tz: String,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let parsed = parse_timezone(&tz)?;
+    let unit = match input.field()?.data_type() {
+        DataType::Timestamp(unit, None) => *unit,
+        d => {
+            return Err(PyValueError::new_err(format!(
+                "Expected timestamp input without a time zone, got {d}"
+            ))
+            .into())
+        }
+    };
+    let tz: Arc<str> = tz.into();
+    let output_type = DataType::Timestamp(unit, Some(tz.clone()));
+    unary_kernel(py, input, output_type, move |array| match unit {
+        TimeUnit::Second => {
+            assume_timezone_array::<TimestampSecondType>(array, &tz, parsed, ambiguous, nonexistent)
+        }
+        TimeUnit::Millisecond => assume_timezone_array::<TimestampMillisecondType>(
+            array,
+            &tz,
+            parsed,
+            ambiguous,
+            nonexistent,
+        ),
+        TimeUnit::Microsecond => assume_timezone_array::<TimestampMicrosecondType>(
+            array,
+            &tz,
+            parsed,
+            ambiguous,
+            nonexistent,
+        ),
+        TimeUnit::Nanosecond => assume_timezone_array::<TimestampNanosecondType>(
+            array,
+            &tz,
+            parsed,
+            ambiguous,
+            nonexistent,
+        ),
+    })
+}
+
+fn local_timestamp_array<T: ArrowTimestampType>(
+    array: &dyn Array,
+    tz: PyArrowTz,
+) -> Result<ArrayRef, ArrowError> {
+    let out = array
+        .as_primitive::<T>()
+        .try_unary::<_, T, ArrowError>(|value| {
+            let utc = as_datetime::<T>(value).ok_or_else(|| {
+                ArrowError::ComputeError(format!("Timestamp {value} is out of range"))
+            })?;
+            let local = tz.from_utc_datetime(&utc).naive_local();
+            T::make_value(local).ok_or_else(|| {
+                ArrowError::ComputeError(format!("Local time {local} is out of range"))
+            })
+        })?;
+    Ok(Arc::new(out))
+}
+
+/// Convert timestamps with a time zone to naive timestamps of their wall-clock time in that time
+/// zone.
+///
+/// Naive timestamps are returned unchanged.
+#[pyfunction]
+pub fn local_timestamp<'py>(py: Python<'py>, input: AnyArray) -> PyArrowResult<Bound<'py, PyAny>> {
+    let (unit, tz) = match input.field()?.data_type() {
+        DataType::Timestamp(unit, tz) => (*unit, tz.as_deref().map(parse_timezone).transpose()?),
+        d => {
+            return Err(PyValueError::new_err(format!("Expected timestamp input, got {d}")).into())
+        }
+    };
+    let output_type = DataType::Timestamp(unit, None);
+    unary_kernel(py, input, output_type, move |array| match (unit, tz) {
+        (_, None) => Ok(make_array(array.to_data())),
+        (TimeUnit::Second, Some(tz)) => local_timestamp_array::<TimestampSecondType>(array, tz),
+        (TimeUnit::Millisecond, Some(tz)) => {
+            local_timestamp_array::<TimestampMillisecondType>(array, tz)
+        }
+        (TimeUnit::Microsecond, Some(tz)) => {
+            local_timestamp_array::<TimestampMicrosecondType>(array, tz)
+        }
+        (TimeUnit::Nanosecond, Some(tz)) => {
+            local_timestamp_array::<TimestampNanosecondType>(array, tz)
+        }
+    })
+}
+
+/// Change the time zone of timestamps with a time zone, keeping the instants they represent.
+///
+/// Only the time zone of the data type changes, as timestamps are always stored in UTC.
+#[pyfunction]
+pub fn convert_timezone<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    tz: String,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    parse_timezone(&tz)?;
+    let unit = match input.field()?.data_type() {
+        DataType::Timestamp(unit, Some(_)) => *unit,
+        d => {
+            return Err(PyValueError::new_err(format!(
+                "Expected timestamp input with a time zone, got {d}; use assume_timezone to \
+                 localize naive timestamps"
+            ))
+            .into())
+        }
+    };
+    let output_type = DataType::Timestamp(unit, Some(tz.into()));
+    let cast_type = output_type.clone();
+    unary_kernel(py, input, output_type, move |array| {
+        arrow_cast::cast(array, &cast_type)
+    })
+}
+
+/// A timestamp unit, as one of `"s"`, `"ms"`, `"us"` or `"ns"`.
+#[derive(Debug, Clone, Copy)]
+pub struct PyTimeUnit(TimeUnit);
+
+impl<'py> FromPyObject<'_, 'py> for PyTimeUnit {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "s" => Ok(Self(TimeUnit::Second)),
+            "ms" => Ok(Self(TimeUnit::Millisecond)),
+            "us" => Ok(Self(TimeUnit::Microsecond)),
+            "ns" => Ok(Self(TimeUnit::Nanosecond)),
+            _ => Err(PyValueError::new_err("Unexpected time unit")),
+        }
+    }
+}
+
+/// How to handle a value that cannot be parsed.
+#[derive(Debug, Clone, Copy)]
+pub enum OnError {
+    Raise,
+    Null,
+}
+
+impl<'py> FromPyObject<'_, 'py> for OnError {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        let s: String = obj.extract()?;
+        match s.to_lowercase().as_str() {
+            "raise" => Ok(Self::Raise),
+            "null" => Ok(Self::Null),
+            _ => Err(PyValueError::new_err("Unexpected error handling")),
+        }
+    }
+}
+
+/// Check that `format` contains only valid strftime directives.
+fn check_format(format: &str) -> PyResult<()> {
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        Err(PyValueError::new_err(format!("Invalid format '{format}'")))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parse `value` with `format`.
+///
+/// If `has_offset`, the value must include a UTC offset, and the UTC time is returned. Otherwise
+/// the wall-clock time is returned, at midnight if `format` has no time fields.
+fn parse_datetime(
+    value: &str,
+    format: &str,
+    has_offset: bool,
+) -> Result<NaiveDateTime, chrono::ParseError> {
+    if has_offset {
+        return DateTime::parse_from_str(value, format).map(|t| t.naive_utc());
+    }
+    NaiveDateTime::parse_from_str(value, format).or_else(|err| {
+        NaiveDate::parse_from_str(value, format)
+            .map(|date| date.and_time(NaiveTime::MIN))
+            .map_err(|_| err)
+    })
+}
+
+/// Convert a UTC time to a timestamp value in `unit`.
+fn timestamp_value(t: NaiveDateTime, unit: TimeUnit) -> Option<i64> {
+    match unit {
+        TimeUnit::Second => TimestampSecondType::make_value(t),
+        TimeUnit::Millisecond => TimestampMillisecondType::make_value(t),
+        TimeUnit::Microsecond => TimestampMicrosecondType::make_value(t),
+        TimeUnit::Nanosecond => TimestampNanosecondType::make_value(t),
+    }
+}
+
+/// The options of [strptime], shared by every chunk.
+struct Strptime {
+    format: String,
+    has_offset: bool,
+    unit: TimeUnit,
+    tz: Option<PyArrowTz>,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+    error: OnError,
+    output_type: DataType,
+}
+
+impl Strptime {
+    /// Parse one value, where `row` is its index in the whole input.
+    fn parse(&self, value: &str, row: usize) -> Result<Option<i64>, ArrowError> {
+        let result = parse_datetime(value, &self.format, self.has_offset)
+            .map_err(|err| err.to_string())
+            .and_then(|t| match self.tz {
+                Some(tz) if !self.has_offset => localize(
+                    t,
+                    tz,
+                    self.ambiguous,
+                    self.nonexistent,
+                    resolution(&self.unit),
+                )
+                .map_err(|err| err.to_string()),
+                _ => Ok(t),
+            })
+            .and_then(|t| {
+                timestamp_value(t, self.unit).ok_or_else(|| format!("{t} is out of range"))
+            });
+        match (result, self.error) {
+            (Ok(value), _) => Ok(Some(value)),
+            (Err(_), OnError::Null) => Ok(None),
+            (Err(err), OnError::Raise) => Err(ArrowError::ComputeError(format!(
+                "Failed to parse '{value}' at row {row} with format '{}': {err}",
+                self.format
+            ))),
+        }
+    }
+
+    /// Parse one chunk, whose first row is at index `offset` in the whole input.
+    fn parse_array(&self, array: &dyn Array, offset: usize) -> Result<ArrayRef, ArrowError> {
+        let array = decode_strings(array)?;
+        let values = (0..array.len())
+            .map(|i| match string_value(array.as_ref(), i) {
+                Some(value) => self.parse(value, offset + i),
+                None => Ok(None),
+            })
+            .collect::<Result<Int64Array, ArrowError>>()?;
+        arrow_cast::cast(&values, &self.output_type)
+    }
+}
+
+/// Parse strings into timestamps with a strftime-style `format`.
+///
+/// If `format` includes a UTC offset (`%z`), values are converted to UTC and the output has time
+/// zone `tz`, or UTC if not given. Otherwise values are wall-clock times, which are localized to
+/// `tz` if given, resolving ambiguous and nonexistent local times as in [assume_timezone].
+#[pyfunction]
+#[pyo3(
+    signature = (
+        input,
+        format,
+        unit,
+        tz = None,
+        *,
+        ambiguous = AmbiguousTime::Raise,
+        nonexistent = NonexistentTime::Raise,
+        error = OnError::Raise
+    ),
+    text_signature = "(input, format, unit, tz = None, *, ambiguous = 'raise', nonexistent = 'raise', error = 'raise')")
+]
+#[allow(clippy::too_many_arguments)]
+pub fn strptime<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    format: String,
+    unit: PyTimeUnit,
+    tz: Option<String>,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+    error: OnError,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    check_string_type(input.field()?.data_type())?;
+    check_format(&format)?;
+    let has_offset = StrftimeItems::new(&format).any(|item| {
+        matches!(
+            item,
+            Item::Fixed(
+                Fixed::TimezoneOffset
+                    | Fixed::TimezoneOffsetColon
+                    | Fixed::TimezoneOffsetDoubleColon
+                    | Fixed::TimezoneOffsetTripleColon
+                    | Fixed::TimezoneOffsetColonZ
+                    | Fixed::TimezoneOffsetZ
+            )
+        )
+    });
+    let parsed_tz = tz.as_deref().map(parse_timezone).transpose()?;
+    let output_tz = match tz {
+        Some(tz) => Some(tz.into()),
+        None if has_offset => Some("UTC".into()),
+        None => None,
+    };
+    let strptime = Strptime {
+        format,
+        has_offset,
+        unit: unit.0,
+        tz: parsed_tz,
+        ambiguous,
+        nonexistent,
+        error,
+        output_type: DataType::Timestamp(unit.0, output_tz),
+    };
+    let mut offset = 0;
+    unary_kernel(py, input, strptime.output_type.clone(), move |array| {
+        let out = strptime.parse_array(array, offset)?;
+        offset += array.len();
+        Ok(out)
+    })
+}
+
+/// Format each value of `array`, a wall-clock time in `tz` if given, into `builder`.
+fn format_values<T: ArrowPrimitiveType>(
+    array: &dyn Array,
+    tz: Option<PyArrowTz>,
+    format: &str,
+    builder: &mut StringBuilder,
+) -> Result<(), ArrowError>
+where
+    T::Native: Into<i64>,
+{
+    let mut buffer = String::new();
+    for value in array.as_primitive::<T>().iter() {
+        let Some(value) = value else {
+            builder.append_null();
+            continue;
+        };
+        let value: i64 = value.into();
+        let t = as_datetime::<T>(value)
+            .ok_or_else(|| ArrowError::ComputeError(format!("Value {value} is out of range")))?;
+        buffer.clear();
+        let result = match tz {
+            Some(tz) => write!(buffer, "{}", tz.from_utc_datetime(&t).format(format)),
+            None => write!(buffer, "{}", t.format(format)),
+        };
+        result.map_err(|_| {
+            ArrowError::ComputeError(format!("Cannot format {t} with format '{format}'"))
+        })?;
+        builder.append_value(&buffer);
+    }
+    Ok(())
+}
+
+fn strftime_array(array: &dyn Array, format: &str) -> Result<ArrayRef, ArrowError> {
+    let mut builder = StringBuilder::with_capacity(array.len(), array.len() * format.len());
+    match array.data_type() {
+        DataType::Date32 => format_values::<Date32Type>(array, None, format, &mut builder)?,
+        DataType::Date64 => format_values::<Date64Type>(array, None, format, &mut builder)?,
+        DataType::Timestamp(unit, tz) => {
+            let tz = tz.as_deref().map(str::parse::<PyArrowTz>).transpose()?;
+            match unit {
+                TimeUnit::Second => {
+                    format_values::<TimestampSecondType>(array, tz, format, &mut builder)?
+                }
+                TimeUnit::Millisecond => {
+                    format_values::<TimestampMillisecondType>(array, tz, format, &mut builder)?
+                }
+                TimeUnit::Microsecond => {
+                    format_values::<TimestampMicrosecondType>(array, tz, format, &mut builder)?
+                }
+                TimeUnit::Nanosecond => {
+                    format_values::<TimestampNanosecondType>(array, tz, format, &mut builder)?
+                }
+            }
+        }
+        d => {
+            return Err(ArrowError::ComputeError(format!(
+                "{d} not supported in strftime"
+            )))
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Format dates and timestamps as Utf8 strings with a strftime-style `format`.
+///
+/// Time zone-aware timestamps are formatted in local time.
+#[pyfunction]
+pub fn strftime<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    format: String,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    check_format(&format)?;
+    unary_kernel(py, input, DataType::Utf8, move |array| {
+        strftime_array(array, &format)
+    })
+}
+
+fn is_date_or_timestamp(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Date32 | DataType::Date64 | DataType::Timestamp(_, _)
+    )
+}
+
+fn datetimes<T: ArrowPrimitiveType>(
+    array: &dyn Array,
+) -> Result<Vec<Option<NaiveDateTime>>, ArrowError>
+where
+    T::Native: Into<i64>,
+{
+    array
+        .as_primitive::<T>()
+        .iter()
+        .map(|value| {
+            value
+                .map(|value| {
+                    let value: i64 = value.into();
+                    as_datetime::<T>(value).ok_or_else(|| {
+                        ArrowError::ComputeError(format!("Value {value} is out of range"))
+                    })
+                })
+                .transpose()
+        })
+        .collect()
+}
+
+/// Convert a date or timestamp array to UTC times.
+fn to_datetimes(array: &dyn Array) -> Result<Vec<Option<NaiveDateTime>>, ArrowError> {
+    match array.data_type() {
+        DataType::Date32 => datetimes::<Date32Type>(array),
+        DataType::Date64 => datetimes::<Date64Type>(array),
+        DataType::Timestamp(TimeUnit::Second, _) => datetimes::<TimestampSecondType>(array),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            datetimes::<TimestampMillisecondType>(array)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            datetimes::<TimestampMicrosecondType>(array)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => datetimes::<TimestampNanosecondType>(array),
+        d => Err(ArrowError::ComputeError(format!(
+            "{d} is not a date or timestamp type"
+        ))),
+    }
+}
+
+/// Convert UTC times to a date or timestamp array of type `data_type`.
+fn from_datetimes(
+    values: &[Option<NaiveDateTime>],
+    data_type: &DataType,
+) -> Result<ArrayRef, ArrowError> {
+    let out_of_range = |t: &NaiveDateTime| {
+        ArrowError::ComputeError(format!("{t} is out of range for {data_type}"))
+    };
+    match data_type {
+        DataType::Date32 => Ok(Arc::new(
+            values
+                .iter()
+                .map(|t| t.map(|t| Date32Type::from_naive_date(t.date())))
+                .collect::<Date32Array>(),
+        )),
+        DataType::Date64 => Ok(Arc::new(
+            values
+                .iter()
+                .map(|t| t.map(|t| t.and_utc().timestamp_millis()))
+                .collect::<Date64Array>(),
+        )),
+        DataType::Timestamp(unit, _) => {
+            let values = values
+                .iter()
+                .map(|t| {
+                    t.as_ref()
+                        .map(|t| timestamp_value(*t, *unit).ok_or_else(|| out_of_range(t)))
+                        .transpose()
+                })
+                .collect::<Result<Int64Array, ArrowError>>()?;
+            arrow_cast::cast(&values, data_type)
+        }
+        d => Err(ArrowError::ComputeError(format!(
+            "{d} is not a date or timestamp type"
+        ))),
+    }
+}
+
+/// The time zone of a timestamp type, used to apply calendar arithmetic in local time.
+struct Zone {
+    tz: PyArrowTz,
+    resolution: TimeDelta,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+}
+
+/// Add an interval of `months`, `days` and `nanos` to the UTC time `t`.
+///
+/// Months are added first, clamping to the end of the month, then days, both to the wall-clock
+/// time in `zone` if given. Nanoseconds are then added as elapsed time.
+fn add_month_day_nano(
+    t: NaiveDateTime,
+    months: i32,
+    days: i32,
+    nanos: i64,
+    zone: Option<&Zone>,
+) -> Result<NaiveDateTime, ArrowError> {
+    let out_of_range =
+        || ArrowError::ComputeError(format!("Adding an interval to {t} is out of range"));
+    let shifted = if months != 0 || days != 0 {
+        let local = match zone {
+            Some(zone) => zone.tz.from_utc_datetime(&t).naive_local(),
+            None => t,
+        };
+        let shifted = if months >= 0 {
+            local.checked_add_months(Months::new(months as u32))
+        } else {
+            local.checked_sub_months(Months::new(months.unsigned_abs()))
+        }
+        .and_then(|t| t.checked_add_signed(TimeDelta::days(days as i64)))
+        .ok_or_else(out_of_range)?;
+        match zone {
+            Some(zone) => localize(
+                shifted,
+                zone.tz,
+                zone.ambiguous,
+                zone.nonexistent,
+                zone.resolution,
+            )?,
+            None => shifted,
+        }
+    } else {
+        t
+    };
+    shifted
+        .checked_add_signed(TimeDelta::nanoseconds(nanos))
+        .ok_or_else(out_of_range)
+}
+
+fn add_interval_datums(
+    input: &dyn Datum,
+    interval: &dyn Datum,
+    data_type: &DataType,
+    zone: Option<&Zone>,
+) -> Result<ArrayRef, ArrowError> {
+    let len = broadcast_len(&[input, interval]);
+    let input = broadcast(input, data_type, len)?;
+    let interval = broadcast(
+        interval,
+        &DataType::Interval(IntervalUnit::MonthDayNano),
+        len,
+    )?;
+    let values = to_datetimes(input.as_ref())?
+        .into_iter()
+        .zip(interval.as_primitive::<IntervalMonthDayNanoType>().iter())
+        .map(|pair| match pair {
+            (Some(t), Some(interval)) => {
+                let out = add_month_day_nano(
+                    t,
+                    interval.months,
+                    interval.days,
+                    interval.nanoseconds,
+                    zone,
+                )?;
+                if matches!(data_type, DataType::Date32) && out.time() != NaiveTime::MIN {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Adding an interval to {} does not give a whole date",
+                        t.date()
+                    )));
+                }
+                Ok(Some(out))
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>, ArrowError>>()?;
+    from_datetimes(&values, data_type)
+}
+
+/// Add calendar intervals or durations to dates and timestamps.
+///
+/// Months are added first, clamping to the end of the month, then days, then nanoseconds. For
+/// time zone-aware timestamps, months and days are added to the local wall-clock time, so that
+/// adding a day keeps the time of day across a DST transition.
+#[pyfunction]
+#[pyo3(
+    signature = (input, interval, *, ambiguous = AmbiguousTime::Raise, nonexistent = NonexistentTime::Raise),
+    text_signature = "(input, interval, *, ambiguous = 'raise', nonexistent = 'raise')")
+]
+pub fn add_interval<'py>(
+    py: Python<'py>,
+    input: AnyDatumOrStream,
+    interval: AnyDatumOrStream,
+    ambiguous: AmbiguousTime,
+    nonexistent: NonexistentTime,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let field = input.field()?;
+    let data_type = field.data_type().clone();
+    if !is_date_or_timestamp(&data_type) {
+        return Err(
+            PyValueError::new_err(format!("{data_type} not supported in add_interval")).into(),
+        );
+    }
+    let interval_type = interval.field()?.data_type().clone();
+    if !matches!(interval_type, DataType::Interval(_) | DataType::Duration(_)) {
+        return Err(PyValueError::new_err(format!(
+            "Expected an interval or duration, got {interval_type}"
+        ))
+        .into());
+    }
+    let zone = match &data_type {
+        DataType::Timestamp(unit, Some(tz)) => Some(Zone {
+            tz: parse_timezone(tz)?,
+            resolution: resolution(unit),
+            ambiguous,
+            nonexistent,
+        }),
+        _ => None,
+    };
+    let output_field = Arc::new(Field::new("", data_type.clone(), true));
+    binary_datum_kernel(py, input, interval, output_field, move |input, interval| {
+        add_interval_datums(input, interval, &data_type, zone.as_ref())
+    })
+}
+
+/// Return the number of `rounder` period boundaries crossed from `start` to `end`, both in UTC.
+///
+/// Calendar units, weeks and days are counted on the wall clock in `tz` if given, and shorter
+/// units on the UTC time line.
+fn periods_between(
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    tz: Option<PyArrowTz>,
+    rounder: &TemporalRounder,
+) -> Result<i64, ArrowError> {
+    let calendar = matches!(
+        rounder.unit,
+        TemporalUnit::Year
+            | TemporalUnit::Quarter
+            | TemporalUnit::Month
+            | TemporalUnit::Week
+            | TemporalUnit::Day
+    );
+    let local = |t: NaiveDateTime| match tz {
+        Some(tz) if calendar => tz.from_utc_datetime(&t).naive_local(),
+        _ => t,
+    };
+    rounder
+        .period_index(local(end))
+        .zip(rounder.period_index(local(start)))
+        .and_then(|(end, start)| i64::try_from(end - start).ok())
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Number of periods between {start} and {end} is out of range"
+            ))
+        })
+}
+
+fn units_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+    unit: TemporalUnit,
+    week_starts_monday: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let start_type = start.field()?.data_type().clone();
+    let end_type = end.field()?.data_type().clone();
+    for data_type in [&start_type, &end_type] {
+        if !is_date_or_timestamp(data_type) {
+            return Err(PyValueError::new_err(format!(
+                "Expected a date or timestamp, got {data_type}"
+            ))
+            .into());
+        }
+    }
+    let tz = match (&start_type, &end_type) {
+        (DataType::Timestamp(_, Some(tz)), DataType::Timestamp(_, Some(_))) => {
+            Some(parse_timezone(tz)?)
+        }
+        (DataType::Timestamp(_, Some(_)), _) | (_, DataType::Timestamp(_, Some(_))) => {
+            return Err(
+                PyValueError::new_err("Cannot compare times with and without a time zone").into(),
+            )
+        }
+        _ => None,
+    };
+    let rounder = TemporalRounder {
+        multiple: 1,
+        unit,
+        week_starts_monday,
+        rounding: TemporalRounding::Floor,
+    };
+    let output_field = Arc::new(Field::new("", DataType::Int64, true));
+    binary_datum_kernel(py, start, end, output_field, move |start, end| {
+        let len = broadcast_len(&[start, end]);
+        let start = to_datetimes(broadcast(start, &start_type, len)?.as_ref())?;
+        let end = to_datetimes(broadcast(end, &end_type, len)?.as_ref())?;
+        let out = start
+            .into_iter()
+            .zip(end)
+            .map(|pair| match pair {
+                (Some(start), Some(end)) => periods_between(start, end, tz, &rounder).map(Some),
+                _ => Ok(None),
+            })
+            .collect::<Result<Int64Array, ArrowError>>()?;
+        Ok(Arc::new(out))
+    })
+}
+
+/// Count the year boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn years_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Year, true)
+}
+
+/// Count the quarter boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn quarters_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Quarter, true)
+}
+
+/// Count the month boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn months_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Month, true)
+}
+
+/// Count the week boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+#[pyo3(signature = (start, end, *, week_starts_monday = true))]
+pub fn weeks_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+    week_starts_monday: bool,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Week, week_starts_monday)
+}
+
+/// Count the day boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn days_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Day, true)
+}
+
+/// Count the hour boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn hours_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Hour, true)
+}
+
+/// Count the minute boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn minutes_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Minute, true)
+}
+
+/// Count the second boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn seconds_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Second, true)
+}
+
+/// Count the millisecond boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn milliseconds_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Millisecond, true)
+}
+
+/// Count the microsecond boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn microseconds_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Microsecond, true)
+}
+
+/// Count the nanosecond boundaries crossed from `start` to `end`, as Int64.
+#[pyfunction]
+pub fn nanoseconds_between<'py>(
+    py: Python<'py>,
+    start: AnyDatumOrStream,
+    end: AnyDatumOrStream,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    units_between(py, start, end, TemporalUnit::Nanosecond, true)
+}
@@ -0,0 +1,217 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use arrow_array::{new_empty_array, Array, ArrayRef, UInt64Array};
+use arrow_row::{OwnedRow, RowConverter, SortField};
+use arrow_schema::{DataType, Field};
+use arrow_select::interleave::interleave;
+use arrow_select::take::take;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3Array, Arro3Table};
+use pyo3_arrow::input::AnyArray;
+use pyo3_arrow::PyArray;
+
+use crate::sort::{sort_columns, NullPlacement, SortKey, SortOrder};
+use crate::utils::struct_to_table;
+
+/// Selects the first `k` rows in sort order from a sequence of chunks.
+///
+/// The sort columns of each row are row-encoded, so that rows from different chunks can be
+/// compared directly. A max-heap holds the best `k` rows seen so far, with the worst of them on
+/// top, so each new row only needs to be compared against the top of the heap. The data of
+/// admitted rows is retained, and compacted to the rows still in the heap whenever more than
+/// max(2k, 1024) rows are retained.
+struct TopK {
+    k: usize,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+    converter: RowConverter,
+    /// The encoded sort key and sequence number of the best `k` rows. The sequence number breaks
+    /// ties, so that earlier rows are preferred.
+    heap: BinaryHeap<(OwnedRow, u64)>,
+    /// Admitted rows, with the sequence number of each row
+    retained: Vec<(Vec<u64>, ArrayRef)>,
+    retained_rows: usize,
+    next_seq: u64,
+}
+
+impl TopK {
+    fn try_new(
+        field: &Field,
+        k: usize,
+        sort_keys: Option<Vec<SortKey>>,
+        order: SortOrder,
+        null_placement: NullPlacement,
+    ) -> PyArrowResult<Self> {
+        let empty = new_empty_array(field.data_type());
+        let sort_fields = sort_columns(&empty, sort_keys.clone(), order, null_placement)?
+            .into_iter()
+            .map(|column| {
+                SortField::new_with_options(
+                    column.values.data_type().clone(),
+                    column.options.unwrap_or_default(),
+                )
+            })
+            .collect();
+        Ok(Self {
+            k,
+            sort_keys,
+            order,
+            null_placement,
+            converter: RowConverter::new(sort_fields)?,
+            // `k` comes from the caller and may be far larger than the input, so the heap grows
+            // as rows are admitted rather than being preallocated
+            heap: BinaryHeap::new(),
+            retained: vec![],
+            retained_rows: 0,
+            next_seq: 0,
+        })
+    }
+
+    fn update(&mut self, chunk: &ArrayRef) -> PyArrowResult<()> {
+        let columns = sort_columns(
+            chunk,
+            self.sort_keys.clone(),
+            self.order,
+            self.null_placement,
+        )?;
+        let columns = columns
+            .into_iter()
+            .map(|column| column.values)
+            .collect::<Vec<_>>();
+        let rows = self.converter.convert_columns(&columns)?;
+
+        let mut selected = vec![];
+        let mut seqs = vec![];
+        for i in 0..chunk.len() {
+            let seq = self.next_seq + i as u64;
+            let row = rows.row(i);
+            if self.heap.len() >= self.k {
+                match self.heap.peek() {
+                    Some((worst, _)) if row < worst.row() => {
+                        self.heap.pop();
+                    }
+                    _ => continue,
+                }
+            }
+            self.heap.push((row.owned(), seq));
+            selected.push(i as u64);
+            seqs.push(seq);
+        }
+        self.next_seq += chunk.len() as u64;
+
+        if !selected.is_empty() {
+            let selected = take(chunk.as_ref(), &UInt64Array::from(selected), None)?;
+            self.retained_rows += selected.len();
+            self.retained.push((seqs, selected));
+        }
+        if self.retained_rows > self.k.saturating_mul(2).max(1024) {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    /// Drop retained rows that have since been evicted from the heap.
+    fn compact(&mut self) -> PyArrowResult<()> {
+        let live = self
+            .heap
+            .iter()
+            .map(|(_, seq)| *seq)
+            .collect::<HashSet<_>>();
+        let mut retained = Vec::with_capacity(self.retained.len());
+        self.retained_rows = 0;
+        for (seqs, array) in self.retained.drain(..) {
+            let (positions, seqs): (Vec<u64>, Vec<u64>) = seqs
+                .into_iter()
+                .enumerate()
+                .filter(|(_, seq)| live.contains(seq))
+                .map(|(position, seq)| (position as u64, seq))
+                .unzip();
+            if !seqs.is_empty() {
+                let array = take(array.as_ref(), &UInt64Array::from(positions), None)?;
+                self.retained_rows += array.len();
+                retained.push((seqs, array));
+            }
+        }
+        self.retained = retained;
+        Ok(())
+    }
+
+    /// Gather the selected rows, in sort order.
+    fn finish(self, data_type: &DataType) -> PyArrowResult<ArrayRef> {
+        if self.retained.is_empty() {
+            return Ok(new_empty_array(data_type));
+        }
+        let mut locations = HashMap::with_capacity(self.retained_rows);
+        for (array_index, (seqs, _)) in self.retained.iter().enumerate() {
+            for (row_index, seq) in seqs.iter().enumerate() {
+                locations.insert(*seq, (array_index, row_index));
+            }
+        }
+        let indices = self
+            .heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(_, seq)| locations[&seq])
+            .collect::<Vec<_>>();
+        let arrays = self
+            .retained
+            .iter()
+            .map(|(_, array)| array.as_ref())
+            .collect::<Vec<_>>();
+        Ok(interleave(&arrays, &indices)?)
+    }
+}
+
+/// Select the first `k` rows of the input in the given sort order, without sorting the whole
+/// input.
+///
+/// Stream input is consumed one chunk at a time, holding at most max(2k, 1024) rows plus one
+/// chunk in memory.
+#[pyfunction]
+#[pyo3(signature = (input, k, sort_keys=None, *, order=SortOrder::Descending, null_placement=NullPlacement::AtEnd))]
+pub fn top_k<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    k: usize,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    let field = input.field()?;
+    let mut top_k = TopK::try_new(&field, k, sort_keys, order, null_placement)?;
+    match input {
+        AnyArray::Array(array) => top_k.update(array.array())?,
+        AnyArray::Stream(stream) => {
+            for chunk in stream.into_reader()? {
+                top_k.update(&chunk?)?;
+            }
+        }
+    }
+    let selected = top_k.finish(field.data_type())?;
+
+    match field.data_type() {
+        DataType::Struct(_) => {
+            Ok(Arro3Table::from(struct_to_table(&selected, &field)?).into_bound_py_any(py)?)
+        }
+        _ => Ok(Arro3Array::from(PyArray::new(selected, field)).into_bound_py_any(py)?),
+    }
+}
+
+/// Select the first `k` rows of the input in ascending order.
+///
+/// This is [top_k] with a default order of ascending.
+#[pyfunction]
+#[pyo3(signature = (input, k, sort_keys=None, *, order=SortOrder::Ascending, null_placement=NullPlacement::AtEnd))]
+pub fn bottom_k<'py>(
+    py: Python<'py>,
+    input: AnyArray,
+    k: usize,
+    sort_keys: Option<Vec<SortKey>>,
+    order: SortOrder,
+    null_placement: NullPlacement,
+) -> PyArrowResult<Bound<'py, PyAny>> {
+    top_k(py, input, k, sort_keys, order, null_placement)
+}
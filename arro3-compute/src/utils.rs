@@ -0,0 +1,578 @@
+//! Shared helpers for compute functions.
+
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::{
+    new_empty_array, Array, ArrayRef, Datum, RecordBatch, RecordBatchOptions, UInt64Array,
+};
+use arrow_cast::CastOptions;
+use arrow_schema::{
+    ArrowError, DataType, Field, FieldRef, Fields, Schema, TimeUnit, DECIMAL128_MAX_PRECISION,
+    DECIMAL256_MAX_PRECISION,
+};
+use pyo3::exceptions::{PyIndexError, PyKeyError};
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3_arrow::error::PyArrowResult;
+use pyo3_arrow::export::{Arro3Array, Arro3ArrayReader};
+use pyo3_arrow::ffi::ArrayIterator;
+use pyo3_arrow::input::{AnyArray, AnyDatum};
+use pyo3_arrow::{PyArrayReader, PyScalar, PyTable};
+
+/// A reference to a column of tabular input, either by name or by position.
+#[derive(FromPyObject, Debug, Clone)]
+pub(crate) enum ColumnRef {
+    Name(String),
+    Position(usize),
+}
+
+impl ColumnRef {
+    /// Resolve this reference to a position within `fields`.
+    ///
+    /// This will raise a KeyError if the provided name does not exist, or an IndexError if the
+    /// provided integer index is out of bounds.
+    pub(crate) fn into_position(self, fields: &Fields) -> PyResult<usize> {
+        match self {
+            Self::Name(name) => fields
+                .iter()
+                .position(|field| field.name() == &name)
+                .ok_or(PyKeyError::new_err(format!("{name} not in schema."))),
+            Self::Position(position) => {
+                if position >= fields.len() {
+                    return Err(PyIndexError::new_err("Index out of range"));
+                }
+                Ok(position)
+            }
+        }
+    }
+}
+
+/// An enum over [AnyDatum] and [PyArrayReader], used by element-wise functions that accept either
+/// a single Array or Scalar, or a stream of Arrays.
+///
+/// Length-1 arrays are interpreted as scalars, matching the extraction of [AnyDatum].
+pub(crate) enum AnyDatumOrStream {
+    /// A single Array or Scalar.
+    Datum(AnyDatum),
+    /// A stream of possibly multiple Arrays.
+    Stream(PyArrayReader),
+}
+
+impl<'py> FromPyObject<'_, 'py> for AnyDatumOrStream {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'_, 'py, PyAny>) -> Result<Self, Self::Error> {
+        match obj.extract::<AnyArray>()? {
+            AnyArray::Array(array) => {
+                if array.as_ref().len() == 1 {
+                    let (array, field) = array.into_inner();
+                    Ok(Self::Datum(AnyDatum::Scalar(PyScalar::try_new(
+                        array, field,
+                    )?)))
+                } else {
+                    Ok(Self::Datum(AnyDatum::Array(array)))
+                }
+            }
+            AnyArray::Stream(stream) => Ok(Self::Stream(stream)),
+        }
+    }
+}
+
+impl AnyDatumOrStream {
+    /// Access the field of this object.
+    pub(crate) fn field(&self) -> PyResult<FieldRef> {
+        match self {
+            Self::Datum(datum) => Ok(datum.field().clone()),
+            Self::Stream(stream) => stream.field_ref(),
+        }
+    }
+}
+
+/// Zips several streams of arrays, re-slicing their chunks to common boundaries.
+///
+/// Each item holds one array per stream, all of the same length. An error is returned if the
+/// streams have different total lengths.
+pub(crate) struct AlignedChunks<I> {
+    streams: Vec<I>,
+    /// The unconsumed rows of the current chunk of each stream, or `None` once it is exhausted
+    pending: Vec<Option<ArrayRef>>,
+    done: bool,
+}
+
+impl<I: Iterator<Item = Result<ArrayRef, ArrowError>>> AlignedChunks<I> {
+    pub(crate) fn new(streams: Vec<I>) -> Self {
+        let pending = streams.iter().map(|_| None).collect();
+        Self {
+            streams,
+            pending,
+            done: false,
+        }
+    }
+
+    fn advance(&mut self) -> Result<Option<Vec<ArrayRef>>, ArrowError> {
+        if self.done {
+            return Ok(None);
+        }
+        for (stream, pending) in self.streams.iter_mut().zip(self.pending.iter_mut()) {
+            while pending.as_ref().map_or(true, |chunk| chunk.is_empty()) {
+                match stream.next() {
+                    Some(chunk) => *pending = Some(chunk?),
+                    None => {
+                        *pending = None;
+                        break;
+                    }
+                }
+            }
+        }
+        let exhausted = self.pending.iter().filter(|chunk| chunk.is_none()).count();
+        if exhausted > 0 {
+            self.done = true;
+            if exhausted == self.pending.len() {
+                return Ok(None);
+            }
+            return Err(ArrowError::ComputeError(
+                "Expected all streams to have the same length".to_string(),
+            ));
+        }
+        let len = self
+            .pending
+            .iter()
+            .flatten()
+            .map(|chunk| chunk.len())
+            .min()
+            .unwrap_or(0);
+        let chunks = self
+            .pending
+            .iter_mut()
+            .flatten()
+            .map(|chunk| {
+                let head = chunk.slice(0, len);
+                *chunk = chunk.slice(len, chunk.len() - len);
+                head
+            })
+            .collect();
+        Ok(Some(chunks))
+    }
+}
+
+impl<I: Iterator<Item = Result<ArrayRef, ArrowError>>> Iterator for AlignedChunks<I> {
+    type Item = Result<Vec<ArrayRef>, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.advance().transpose()
+    }
+}
+
+/// Apply the binary kernel `op` to `lhs` and `rhs`.
+///
+/// If both inputs are datums, returns an Array. If either input is a stream, returns an
+/// ArrayReader with field `output_field`, where a datum is broadcast against every chunk of the
+/// stream, and two streams are re-sliced to common chunk boundaries with [AlignedChunks].
+pub(crate) fn binary_datum_kernel<'py, F>(
+    py: Python<'py>,
+    lhs: AnyDatumOrStream,
+    rhs: AnyDatumOrStream,
+    output_field: FieldRef,
+    op: F,
+) -> PyArrowResult<Bound<'py, PyAny>>
+where
+    F: Fn(&dyn Datum, &dyn Datum) -> Result<ArrayRef, ArrowError> + Send + 'static,
+{
+    let iter: Box<dyn Iterator<Item = Result<ArrayRef, ArrowError>> + Send> = match (lhs, rhs) {
+        (AnyDatumOrStream::Datum(lhs), AnyDatumOrStream::Datum(rhs)) => {
+            let out = op(&lhs, &rhs)?;
+            return Ok(Arro3Array::from(out).into_bound_py_any(py)?);
+        }
+        (AnyDatumOrStream::Stream(lhs), AnyDatumOrStream::Datum(rhs)) => {
+            Box::new(lhs.into_reader()?.map(move |lhs| op(&lhs?, &rhs)))
+        }
+        (AnyDatumOrStream::Datum(lhs), AnyDatumOrStream::Stream(rhs)) => {
+            Box::new(rhs.into_reader()?.map(move |rhs| op(&lhs, &rhs?)))
+        }
+        (AnyDatumOrStream::Stream(lhs), AnyDatumOrStream::Stream(rhs)) => Box::new(
+            AlignedChunks::new(vec![lhs.into_reader()?, rhs.into_reader()?]).map(move |chunks| {
+                let chunks = chunks?;
+                op(&chunks[0], &chunks[1])
+            }),
+        ),
+    };
+    Ok(
+        Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+            iter,
+            output_field,
+        ))))
+        .into_bound_py_any(py)?,
+    )
+}
+
+/// Apply the kernel `op` to any number of `inputs`.
+///
+/// If every input is a datum, returns an Array. Otherwise returns an ArrayReader with field
+/// `output_field`, where each datum is broadcast against every chunk, and all streams are
+/// re-sliced to common chunk boundaries with [AlignedChunks]. `op` receives one datum per input,
+/// in order.
+pub(crate) fn nary_datum_kernel<'py, F>(
+    py: Python<'py>,
+    inputs: Vec<AnyDatumOrStream>,
+    output_field: FieldRef,
+    op: F,
+) -> PyArrowResult<Bound<'py, PyAny>>
+where
+    F: Fn(&[&dyn Datum]) -> Result<ArrayRef, ArrowError> + Send + 'static,
+{
+    // The datum of each input, or `None` where the input is a stream
+    let mut datums = Vec::with_capacity(inputs.len());
+    let mut streams = vec![];
+    for input in inputs {
+        match input {
+            AnyDatumOrStream::Datum(datum) => datums.push(Some(datum)),
+            AnyDatumOrStream::Stream(stream) => {
+                datums.push(None);
+                streams.push(stream.into_reader()?);
+            }
+        }
+    }
+
+    if streams.is_empty() {
+        let datums = datums
+            .iter()
+            .flatten()
+            .map(|datum| datum as &dyn Datum)
+            .collect::<Vec<_>>();
+        let out = op(&datums)?;
+        return Ok(Arro3Array::from(out).into_bound_py_any(py)?);
+    }
+
+    let iter = AlignedChunks::new(streams).map(move |chunks| {
+        let chunks = chunks?;
+        let mut chunks = chunks.iter();
+        let datums = datums
+            .iter()
+            .map(|datum| match datum {
+                Some(datum) => datum as &dyn Datum,
+                None => chunks.next().expect("One chunk per stream") as &dyn Datum,
+            })
+            .collect::<Vec<_>>();
+        op(&datums)
+    });
+    Ok(
+        Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+            iter,
+            output_field,
+        ))))
+        .into_bound_py_any(py)?,
+    )
+}
+
+/// The [common_type] of `inputs`, or an error if they have no common type.
+///
+/// Null inputs (e.g. a Python `None` scalar) do not affect the result, so this is Null only if
+/// every input is Null.
+pub(crate) fn common_input_type<'a>(
+    inputs: impl IntoIterator<Item = &'a AnyDatumOrStream>,
+) -> PyArrowResult<DataType> {
+    let types = inputs
+        .into_iter()
+        .map(|input| Ok(input.field()?.data_type().clone()))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(common_type_of(&types)?)
+}
+
+/// The length of the output of an element-wise kernel over `values`: the length of any array
+/// among them, or 1 if every value is a scalar.
+pub(crate) fn broadcast_len(values: &[&dyn Datum]) -> usize {
+    values
+        .iter()
+        .map(|value| value.get())
+        .filter(|(_, is_scalar)| !is_scalar)
+        .map(|(array, _)| array.len())
+        .max()
+        .unwrap_or(1)
+}
+
+/// Cast `datum` to `data_type` and materialize it as an array of length `len`, repeating a
+/// scalar.
+///
+/// The cast is strict, so a value that does not fit `data_type` is an error rather than a null.
+pub(crate) fn broadcast(
+    datum: &dyn Datum,
+    data_type: &DataType,
+    len: usize,
+) -> Result<ArrayRef, ArrowError> {
+    let (array, is_scalar) = datum.get();
+    let array = cast_strict(array, data_type)?;
+    if is_scalar && len != 1 {
+        arrow_select::take::take(array.as_ref(), &UInt64Array::from(vec![0; len]), None)
+    } else if array.len() != len {
+        Err(ArrowError::ComputeError(format!(
+            "Expected all arrays to have length {len}, got {}",
+            array.len()
+        )))
+    } else {
+        Ok(array)
+    }
+}
+
+/// The bit width of an integer type and whether it is signed.
+fn integer_width(data_type: &DataType) -> Option<(u32, bool)> {
+    match data_type {
+        DataType::Int8 => Some((8, true)),
+        DataType::Int16 => Some((16, true)),
+        DataType::Int32 => Some((32, true)),
+        DataType::Int64 => Some((64, true)),
+        DataType::UInt8 => Some((8, false)),
+        DataType::UInt16 => Some((16, false)),
+        DataType::UInt32 => Some((32, false)),
+        DataType::UInt64 => Some((64, false)),
+        _ => None,
+    }
+}
+
+fn integer_type(width: u32, signed: bool) -> DataType {
+    match (width, signed) {
+        (8, true) => DataType::Int8,
+        (16, true) => DataType::Int16,
+        (32, true) => DataType::Int32,
+        (64, true) => DataType::Int64,
+        (8, false) => DataType::UInt8,
+        (16, false) => DataType::UInt16,
+        (32, false) => DataType::UInt32,
+        _ => DataType::UInt64,
+    }
+}
+
+/// The precision and scale of a decimal or integer type, as a decimal.
+fn decimal_digits(data_type: &DataType) -> Option<(u8, i8)> {
+    match data_type {
+        DataType::Decimal128(precision, scale) | DataType::Decimal256(precision, scale) => {
+            Some((*precision, *scale))
+        }
+        DataType::Int8 | DataType::UInt8 => Some((3, 0)),
+        DataType::Int16 | DataType::UInt16 => Some((5, 0)),
+        DataType::Int32 | DataType::UInt32 => Some((10, 0)),
+        DataType::Int64 => Some((19, 0)),
+        DataType::UInt64 => Some((20, 0)),
+        _ => None,
+    }
+}
+
+/// The narrowest type that can represent every value of both `lhs` and `rhs`, or `None` if the
+/// types are not comparable.
+///
+/// Integers widen to an integer type holding both ranges (or Decimal128(20, 0) for Int64 and
+/// UInt64), and mix with floats as Float64.
+/// Decimals widen to hold the integer digits and scale of both sides. Strings and binaries
+/// combine to their large or view variants, and dictionaries compare by their value types.
+pub(crate) fn common_type(lhs: &DataType, rhs: &DataType) -> Option<DataType> {
+    if lhs == rhs {
+        return Some(lhs.clone());
+    }
+    match (lhs, rhs) {
+        (DataType::Null, other) | (other, DataType::Null) => Some(other.clone()),
+        (DataType::Dictionary(_, lhs), rhs) | (rhs, DataType::Dictionary(_, lhs)) => {
+            common_type(lhs, rhs)
+        }
+        (
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _),
+            DataType::Float16 | DataType::Float32 | DataType::Float64,
+        )
+        | (
+            DataType::Float16 | DataType::Float32 | DataType::Float64,
+            DataType::Decimal128(_, _) | DataType::Decimal256(_, _),
+        ) => Some(DataType::Float64),
+        (DataType::Decimal128(_, _) | DataType::Decimal256(_, _), _)
+        | (_, DataType::Decimal128(_, _) | DataType::Decimal256(_, _)) => {
+            let (lhs_precision, lhs_scale) = decimal_digits(lhs)?;
+            let (rhs_precision, rhs_scale) = decimal_digits(rhs)?;
+            let scale = lhs_scale.max(rhs_scale);
+            let integer_digits = (lhs_precision as i16 - lhs_scale as i16)
+                .max(rhs_precision as i16 - rhs_scale as i16);
+            let precision = u8::try_from(integer_digits + scale as i16).ok()?;
+            let decimal256 = matches!(lhs, DataType::Decimal256(_, _))
+                || matches!(rhs, DataType::Decimal256(_, _))
+                || precision > DECIMAL128_MAX_PRECISION;
+            if !decimal256 {
+                Some(DataType::Decimal128(precision.max(1), scale))
+            } else if precision <= DECIMAL256_MAX_PRECISION {
+                Some(DataType::Decimal256(precision.max(1), scale))
+            } else {
+                None
+            }
+        }
+        _ if lhs.is_integer() && rhs.is_integer() => {
+            let (lhs_width, lhs_signed) = integer_width(lhs)?;
+            let (rhs_width, rhs_signed) = integer_width(rhs)?;
+            if lhs_signed == rhs_signed {
+                return Some(integer_type(lhs_width.max(rhs_width), lhs_signed));
+            }
+            let (signed_width, unsigned_width) = if lhs_signed {
+                (lhs_width, rhs_width)
+            } else {
+                (rhs_width, lhs_width)
+            };
+            let width = signed_width.max(unsigned_width * 2);
+            if width <= 64 {
+                Some(integer_type(width, true))
+            } else {
+                // No integer type holds both Int64 and UInt64
+                Some(DataType::Decimal128(20, 0))
+            }
+        }
+        _ if lhs.is_numeric() && rhs.is_numeric() => Some(DataType::Float64),
+        (
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View,
+            DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View,
+        ) => Some(
+            if matches!(lhs, DataType::LargeUtf8) || matches!(rhs, DataType::LargeUtf8) {
+                DataType::LargeUtf8
+            } else {
+                DataType::Utf8View
+            },
+        ),
+        (
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView,
+            DataType::Binary | DataType::LargeBinary | DataType::BinaryView,
+        ) => Some(
+            if matches!(lhs, DataType::LargeBinary) || matches!(rhs, DataType::LargeBinary) {
+                DataType::LargeBinary
+            } else {
+                DataType::BinaryView
+            },
+        ),
+        (DataType::Date32, DataType::Date64) | (DataType::Date64, DataType::Date32) => {
+            Some(DataType::Date64)
+        }
+        (DataType::Timestamp(lhs_unit, lhs_tz), DataType::Timestamp(rhs_unit, rhs_tz))
+            if lhs_tz == rhs_tz =>
+        {
+            let unit = if time_unit_rank(lhs_unit) >= time_unit_rank(rhs_unit) {
+                lhs_unit
+            } else {
+                rhs_unit
+            };
+            Some(DataType::Timestamp(*unit, lhs_tz.clone()))
+        }
+        (DataType::Duration(lhs_unit), DataType::Duration(rhs_unit)) => {
+            if time_unit_rank(lhs_unit) >= time_unit_rank(rhs_unit) {
+                Some(DataType::Duration(*lhs_unit))
+            } else {
+                Some(DataType::Duration(*rhs_unit))
+            }
+        }
+        _ => None,
+    }
+}
+
+fn time_unit_rank(unit: &TimeUnit) -> u8 {
+    match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 1,
+        TimeUnit::Microsecond => 2,
+        TimeUnit::Nanosecond => 3,
+    }
+}
+
+/// The [common_type] of all `types`, or an error naming the first type that does not fit.
+pub(crate) fn common_type_of<'a>(
+    types: impl IntoIterator<Item = &'a DataType>,
+) -> Result<DataType, ArrowError> {
+    let mut common = DataType::Null;
+    for data_type in types {
+        common = common_type(&common, data_type).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Cannot find a common type for {common} and {data_type}"
+            ))
+        })?;
+    }
+    Ok(common)
+}
+
+/// Cast `array` to `data_type`, returning an error instead of null for values that do not fit.
+pub(crate) fn cast_strict(array: &dyn Array, data_type: &DataType) -> Result<ArrayRef, ArrowError> {
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    arrow_cast::cast_with_options(array, data_type, &options)
+}
+
+/// Apply the unary kernel `op` to `input`.
+///
+/// Array input returns an Array. Stream input returns an ArrayReader with the given output data
+/// type, applying `op` to each chunk in order, so that `op` may carry state from one chunk to the
+/// next.
+pub(crate) fn unary_kernel<'py, F>(
+    py: Python<'py>,
+    input: AnyArray,
+    output_type: DataType,
+    mut op: F,
+) -> PyArrowResult<Bound<'py, PyAny>>
+where
+    F: FnMut(&dyn Array) -> Result<ArrayRef, ArrowError> + Send + 'static,
+{
+    match input {
+        AnyArray::Array(array) => {
+            let out = op(array.array().as_ref())?;
+            Ok(Arro3Array::from(out).into_bound_py_any(py)?)
+        }
+        AnyArray::Stream(stream) => {
+            let reader = stream.into_reader()?;
+            let output_field = Field::new("", output_type, true);
+
+            let iter = reader.into_iter().map(move |array| op(array?.as_ref()));
+            Ok(
+                Arro3ArrayReader::from(PyArrayReader::new(Box::new(ArrayIterator::new(
+                    iter,
+                    output_field.into(),
+                ))))
+                .into_bound_py_any(py)?,
+            )
+        }
+    }
+}
+
+/// Materialize `input` into a single contiguous array.
+///
+/// Chunks of a stream are concatenated, so that kernels which need to see all values at once
+/// (e.g. sorting) produce globally-correct output.
+pub(crate) fn concat_input(input: AnyArray) -> PyArrowResult<(ArrayRef, FieldRef)> {
+    match input {
+        AnyArray::Array(array) => Ok(array.into_inner()),
+        AnyArray::Stream(stream) => {
+            let reader = stream.into_reader()?;
+            let field = reader.field();
+            let chunks = reader.collect::<Result<Vec<_>, ArrowError>>()?;
+            let array = if chunks.is_empty() {
+                new_empty_array(field.data_type())
+            } else {
+                let refs = chunks.iter().map(|x| x.as_ref()).collect::<Vec<_>>();
+                arrow_select::concat::concat(refs.as_slice())?
+            };
+            Ok((array, field))
+        }
+    }
+}
+
+/// Convert a struct array materialized from tabular input back into a [RecordBatch].
+///
+/// Schema-level metadata is carried over from `field`. Top-level struct nulls are ignored, as a
+/// RecordBatch has no notion of a null row.
+pub(crate) fn struct_to_record_batch(
+    array: &dyn Array,
+    field: &Field,
+) -> Result<RecordBatch, ArrowError> {
+    let (fields, columns, _nulls) = array.as_struct().clone().into_parts();
+    let schema = Arc::new(Schema::new_with_metadata(fields, field.metadata().clone()));
+    let options = RecordBatchOptions::new().with_row_count(Some(array.len()));
+    RecordBatch::try_new_with_options(schema, columns, &options)
+}
+
+/// Convert a struct array materialized from tabular input back into a single-batch [PyTable].
+///
+/// See [struct_to_record_batch].
+pub(crate) fn struct_to_table(array: &ArrayRef, field: &Field) -> PyArrowResult<PyTable> {
+    let batch = struct_to_record_batch(array.as_ref(), field)?;
+    let schema = batch.schema();
+    Ok(PyTable::try_new(vec![batch], schema)?)
+}
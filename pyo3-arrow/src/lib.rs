@@ -27,6 +27,6 @@ pub use datatypes::PyDataType;
 pub use field::PyField;
 pub use record_batch::PyRecordBatch;
 pub use record_batch_reader::PyRecordBatchReader;
-pub use scalar::PyScalar;
+pub use scalar::{PyArrowTz, PyScalar};
 pub use schema::PySchema;
 pub use table::PyTable;
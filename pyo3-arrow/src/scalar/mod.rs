@@ -1,5 +1,7 @@
 mod temporal;
 
+pub use temporal::PyArrowTz;
+
 use std::fmt::Display;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -18,7 +20,7 @@ use pyo3::{intern, IntoPyObjectExt};
 use crate::error::PyArrowResult;
 use crate::export::{Arro3DataType, Arro3Field, Arro3Scalar};
 use crate::ffi::to_array_pycapsules;
-use crate::scalar::temporal::{as_datetime_with_timezone, PyArrowTz};
+use crate::scalar::temporal::as_datetime_with_timezone;
 use crate::utils::default_repr_options;
 use crate::{PyArray, PyField};
 
@@ -10,7 +10,7 @@ use pyo3::prelude::*;
 
 /// An [`Offset`] for [`PyArrowTz`]
 #[derive(Debug, Copy, Clone)]
-pub(crate) struct PyArrowTzOffset {
+pub struct PyArrowTzOffset {
     tz: PyArrowTz,
     offset: FixedOffset,
 }
@@ -26,17 +26,18 @@ impl Offset for PyArrowTzOffset {
         self.offset
     }
 }
-/// An Arrow [`TimeZone`]
+/// An Arrow [`TimeZone`], parsed from the time zone string of a Timestamp data type with
+/// [`FromStr`].
 ///
 /// This is vendored from upstream so we can implement `IntoPyObject`, while also needing to
 /// implement chrono::TimeZone
 ///
 /// <https://github.com/apache/arrow-rs/blob/77df2ee42d8ca1d1557a64681b240b8409deef01/arrow-array/src/timezone.rs#L78-L80>
 #[derive(Debug, Clone, Copy, IntoPyObject)]
-pub(crate) struct PyArrowTz(TzInner);
+pub struct PyArrowTz(TzInner);
 
 #[derive(Debug, Copy, Clone, IntoPyObject)]
-pub(crate) enum TzInner {
+pub enum TzInner {
     Timezone(chrono_tz::Tz),
     Offset(FixedOffset),
 }
@@ -1,7 +1,10 @@
 from datetime import datetime, timezone
+from decimal import Decimal
 
 import arro3.compute as ac
 import pyarrow as pa
+import pyarrow.compute as pc
+import pytest
 from arro3.core import Array, ChunkedArray, DataType
 
 
@@ -66,3 +69,71 @@ def test_min_max_datetime_with_timezone():
     assert ac.min(arr).type.tz == "UTC"
     assert ac.max(arr).as_py() == dt3
     assert ac.max(arr).type.tz == "UTC"
+
+
+def test_mean_variance_stddev():
+    arr1 = Array([1, 2, 3], DataType.int32())
+    arr2 = Array([4, 5], DataType.int32())
+    ca = ChunkedArray([arr1, arr2])
+    assert ac.mean(ca).as_py() == 3.0
+    assert ac.variance(ca).as_py() == pytest.approx(2.0)
+    assert ac.variance(ca, ddof=1).as_py() == pytest.approx(2.5)
+    assert ac.stddev(ca, ddof=1).as_py() == pytest.approx(2.5**0.5)
+    assert ac.variance(Array([1], DataType.int32()), ddof=1).as_py() is None
+
+
+def test_mean_decimal():
+    arr = pa.array([Decimal("1.10"), Decimal("2.21"), None], pa.decimal128(5, 2))
+    out = ac.mean(arr)
+    assert out.type == DataType.decimal128(38, 2)
+    assert out.as_py() == Decimal("1.66")
+
+
+def test_product():
+    ca = ChunkedArray([Array([2, 3], DataType.int8()), Array([4], DataType.int8())])
+    out = ac.product(ca)
+    assert out.type == DataType.int64()
+    assert out.as_py() == 24
+    assert ac.product(Array([None], DataType.float32())).as_py() is None
+
+
+def test_count():
+    arr = Array([1, None, 3, None], DataType.int16())
+    assert ac.count(arr).as_py() == 2
+    assert ac.count(arr, mode="null").as_py() == 2
+    assert ac.count(arr, mode="all").as_py() == 4
+
+
+def test_quantile_exact():
+    arr = Array([4, 1, None, 3, 2], DataType.int64())
+    assert ac.median(arr).as_py() == 2.5
+    assert ac.quantile(arr, 0.25).as_py() == 1.75
+    out = ac.quantile(arr, [0.0, 0.5, 1.0])
+    assert pa.array(out).to_pylist() == [1.0, 2.5, 4.0]
+
+
+def test_quantile_exact_non_finite():
+    arr = Array([1.0, float("nan"), 3.0, 2.0], DataType.float64())
+    out = ac.quantile(arr, [0.0, 0.5, 1.0])
+    assert pa.array(out).to_pylist() == [1.0, 2.0, 3.0]
+
+    arr = Array([1.0, float("inf")], DataType.float64())
+    out = ac.quantile(arr, [0.0, 0.5, 1.0])
+    assert pa.array(out).to_pylist() == [1.0, float("inf"), float("inf")]
+
+
+def test_quantile_tdigest_stream():
+    chunks = [
+        Array([float(x) for x in range(i, i + 1000)], DataType.float64())
+        for i in range(0, 10000, 1000)
+    ]
+    ca = ChunkedArray(chunks)
+    out = pa.array(ac.quantile(ca, [0.5, 0.95, 0.99], method="tdigest"))
+    expected = pc.quantile(pa.chunked_array(ca), [0.5, 0.95, 0.99]).to_pylist()
+    for actual, exp in zip(out.to_pylist(), expected):
+        assert actual == pytest.approx(exp, rel=0.01)
+
+
+def test_quantile_invalid():
+    with pytest.raises(ValueError):
+        ac.quantile(Array([1.0], DataType.float64()), 1.5)
@@ -0,0 +1,56 @@
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+from arro3.core import Array, DataType
+
+
+def test_bitwise_scalar_mask():
+    flags = Array([0b1010, 0b0110, None], DataType.uint8())
+    mask = Array([0b0010], DataType.uint8())[0]
+    assert pa.array(ac.bitwise_and(flags, mask)).to_pylist() == [2, 2, None]
+    assert pa.array(ac.bitwise_or(flags, mask)).to_pylist() == [10, 6, None]
+    assert pa.array(ac.bitwise_xor(flags, mask)).to_pylist() == [8, 4, None]
+
+
+def test_bitwise_arrays():
+    lhs = Array([0b1100, 0b1010], DataType.int32())
+    rhs = Array([0b1010, 0b0101], DataType.int64())
+    out = pa.array(ac.bitwise_and(lhs, rhs))
+    assert out.type == pa.int32()
+    assert out.to_pylist() == [0b1000, 0]
+
+
+def test_bitwise_not():
+    arr = Array([0, -1, 5], DataType.int16())
+    assert pa.array(ac.bitwise_not(arr)).to_pylist() == [-1, 0, -6]
+
+
+def test_shift():
+    arr = Array([1, 4, 16], DataType.int32())
+    out = ac.shift_left(arr, Array([2], DataType.int32()))
+    assert pa.array(out).to_pylist() == [4, 16, 64]
+    shifts = Array([0, 1, 2], DataType.int32())
+    assert pa.array(ac.shift_right(arr, shifts)).to_pylist() == [1, 2, 4]
+
+
+def test_bit_count():
+    arr = Array([0, 7, -1, None], DataType.int8())
+    out = pa.array(ac.bit_count(arr))
+    assert out.type == pa.uint32()
+    assert out.to_pylist() == [0, 3, 8, None]
+
+
+def test_bitwise_rejects_floats():
+    arr = Array([1.0], DataType.float64())
+    with pytest.raises(ValueError):
+        ac.bitwise_and(arr, arr)
+
+
+def test_bitwise_mask_out_of_range():
+    flags = Array([1, 2, 3], DataType.uint8())
+    with pytest.raises(Exception):
+        ac.bitwise_and(flags, Array([256], DataType.int64()))
+
+    flags = Array([1, 2, 3], DataType.uint32())
+    with pytest.raises(Exception):
+        ac.bitwise_or(flags, Array([-1], DataType.int64()))
@@ -0,0 +1,43 @@
+import arro3.compute as ac
+import pyarrow as pa
+import pyarrow.compute as pc
+from arro3.core import Array, ChunkedArray, DataType
+
+LHS = [True, True, True, False, False, False, None, None, None]
+RHS = [True, False, None, True, False, None, True, False, None]
+
+
+def test_and_or():
+    lhs = Array(LHS, DataType.bool())
+    rhs = Array(RHS, DataType.bool())
+    assert pa.array(ac.and_(lhs, rhs)).to_pylist() == pc.and_(
+        pa.array(LHS), pa.array(RHS)
+    ).to_pylist()
+    assert pa.array(ac.or_kleene(lhs, rhs)).to_pylist() == pc.or_kleene(
+        pa.array(LHS), pa.array(RHS)
+    ).to_pylist()
+    assert pa.array(
+        ac.and_not_kleene(lhs, rhs)
+    ).to_pylist() == pc.and_not_kleene(pa.array(LHS), pa.array(RHS)).to_pylist()
+    assert pa.array(ac.xor(lhs, rhs)).to_pylist() == pc.xor(
+        pa.array(LHS), pa.array(RHS)
+    ).to_pylist()
+
+
+def test_invert_stream():
+    ca = ChunkedArray(
+        [Array([True, None], DataType.bool()), Array([False], DataType.bool())]
+    )
+    out = ac.invert(ca).read_all()
+    assert pa.chunked_array(out).to_pylist() == [False, None, True]
+
+
+def test_and_streams_with_different_chunks():
+    lhs = ChunkedArray(
+        [Array([True, True], DataType.bool()), Array([False], DataType.bool())]
+    )
+    rhs = ChunkedArray(
+        [Array([True], DataType.bool()), Array([False, True], DataType.bool())]
+    )
+    out = pa.chunked_array(ac.and_(lhs, rhs).read_all())
+    assert out.to_pylist() == [True, False, False]
@@ -0,0 +1,42 @@
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+from arro3.core import Array, ArrayReader, ChunkedArray, DataType
+
+
+def test_compare_arrays():
+    arr1 = Array([1, 2, 3], DataType.int16())
+    arr2 = Array([3, 2, 1], DataType.int16())
+    assert pa.array(ac.eq(arr1, arr2)).to_pylist() == [False, True, False]
+    assert pa.array(ac.lt(arr1, arr2)).to_pylist() == [True, False, False]
+    assert pa.array(ac.gt_eq(arr1, arr2)).to_pylist() == [False, True, True]
+
+
+def test_compare_scalar():
+    arr = Array(["a", "b", "c"], DataType.string())
+    assert pa.array(ac.neq(arr, arr[1])).to_pylist() == [True, False, True]
+
+
+def test_compare_stream():
+    ca = ChunkedArray(
+        [Array([1, 2], DataType.int64()), Array([3, 4], DataType.int64())]
+    )
+    s = Array([2], DataType.int64())[0]
+    out = ac.gt(ca, s)
+    assert isinstance(out, ArrayReader)
+    assert pa.chunked_array(out.read_all()).to_pylist() == [False, False, True, True]
+
+
+def test_compare_streams_with_different_chunks():
+    lhs = ChunkedArray(
+        [Array([1, 2, 3], DataType.int64()), Array([4], DataType.int64())]
+    )
+    rhs = ChunkedArray(
+        [Array([1], DataType.int64()), Array([0, 3, 4], DataType.int64())]
+    )
+    out = pa.chunked_array(ac.eq(lhs, rhs).read_all())
+    assert out.to_pylist() == [True, False, True, True]
+
+    short = ChunkedArray([Array([1, 2], DataType.int64())])
+    with pytest.raises(Exception, match="same length"):
+        ac.eq(lhs, short).read_all()
@@ -0,0 +1,95 @@
+import arro3.compute as ac
+import pyarrow as pa
+import pyarrow.compute as pc
+import pytest
+from arro3.core import Array, ChunkedArray, DataType
+
+
+def test_if_else():
+    cond = Array([True, False, None, True], DataType.bool())
+    left = Array([1, 2, 3, 4], DataType.int64())
+    right = Array([10, 20, 30, 40], DataType.int64())
+    assert pa.array(ac.if_else(cond, left, right)).to_pylist() == [1, 20, None, 4]
+
+    zero = Array([0], DataType.int64())
+    assert pa.array(ac.if_else(cond, left, zero)).to_pylist() == [1, 0, None, 4]
+
+
+def test_if_else_nested():
+    cond = pa.array([True, False])
+    left = pa.array([[1, 2], [3]])
+    right = pa.array([[4], [5, 6]])
+    assert pa.array(ac.if_else(cond, left, right)).to_pylist() == [[1, 2], [5, 6]]
+
+
+def test_if_else_stream():
+    cond = ChunkedArray(
+        [Array([True, False], DataType.bool()), Array([False], DataType.bool())]
+    )
+    left = ChunkedArray(
+        [Array(["a", "b"], DataType.string()), Array(["c"], DataType.string())]
+    )
+    out = ac.if_else(cond, left, Array(["z"], DataType.string()))
+    assert pa.chunked_array(out).to_pylist() == ["a", "z", "z"]
+
+
+def test_case_when():
+    x = pa.array([1, 5, 10, None])
+    conditions = [pc.less(x, 3), pc.less(x, 8)]
+    values = [pa.array(["small"] * 4), pa.array(["medium"] * 4)]
+    out = ac.case_when(conditions, values, Array(["large"], DataType.string()))
+    assert pa.array(out).to_pylist() == ["small", "medium", "large", "large"]
+
+    out = ac.case_when(conditions, values)
+    assert pa.array(out).to_pylist() == ["small", "medium", None, None]
+
+    with pytest.raises(ValueError):
+        ac.case_when(conditions, values[:1])
+
+
+def test_choose():
+    indices = Array([0, 1, None, 2], DataType.int8())
+    a = Array([1, 2, 3, 4], DataType.int64())
+    b = Array([10, 20, 30, 40], DataType.int64())
+    c = Array([-1], DataType.int64())
+    assert pa.array(ac.choose(indices, a, b, c)).to_pylist() == [1, 20, None, -1]
+
+    with pytest.raises(Exception):
+        ac.choose(Array([3], DataType.int8()), a, b)
+
+
+def test_conditional_common_type():
+    cond = Array([True, False], DataType.bool())
+    small = Array([1, 2], DataType.int8())
+    large = Array([1000, 2000], DataType.int64())
+    out = pa.array(ac.if_else(cond, small, large))
+    assert out.type == pa.int64()
+    assert out.to_pylist() == [1, 2000]
+
+    out = pa.array(ac.case_when([cond], [small], Array([300], DataType.int16())))
+    assert out.type == pa.int16()
+    assert out.to_pylist() == [1, 300]
+
+    indices = Array([0, 1], DataType.int8())
+    out = pa.array(ac.choose(indices, small, Array([1.5, 2.5], DataType.float64())))
+    assert out.type == pa.float64()
+    assert out.to_pylist() == [1.0, 2.5]
+
+    with pytest.raises(Exception, match="common type"):
+        ac.if_else(cond, small, Array(["a", "b"], DataType.string()))
+
+
+def test_if_else_streams_with_different_chunks():
+    cond = ChunkedArray(
+        [Array([True], DataType.bool()), Array([False, True], DataType.bool())]
+    )
+    left = ChunkedArray([Array([1, 2, 3], DataType.int64())])
+    right = ChunkedArray(
+        [Array([10, 20], DataType.int64()), Array([30], DataType.int64())]
+    )
+    out = pa.chunked_array(ac.if_else(cond, left, right))
+    assert out.to_pylist() == [1, 20, 3]
+
+    with pytest.raises(Exception, match="same length"):
+        short = ChunkedArray([Array([1, 2], DataType.int64())])
+        pa.chunked_array(ac.if_else(cond, short, right))
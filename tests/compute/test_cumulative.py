@@ -0,0 +1,72 @@
+from datetime import date
+from decimal import Decimal
+
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+from arro3.core import Array, ChunkedArray, DataType
+
+
+def test_cumulative_sum_chunked():
+    ca = ChunkedArray(
+        [Array([1, 2], DataType.int32()), Array([3, 4], DataType.int32())]
+    )
+    out = ac.cumulative_sum(ca).read_all()
+    assert pa.chunked_array(out).to_pylist() == [1, 3, 6, 10]
+
+
+def test_cumulative_nulls():
+    arr = Array([1, None, 2], DataType.int64())
+    assert pa.array(ac.cumulative_sum(arr)).to_pylist() == [1, None, None]
+    out = ac.cumulative_sum(arr, skip_nulls=True)
+    assert pa.array(out).to_pylist() == [1, None, 3]
+
+
+def test_cumulative_overflow():
+    arr = Array([100, 100], DataType.int8())
+    with pytest.raises(Exception):
+        ac.cumulative_sum(arr)
+    assert pa.array(ac.cumulative_sum_wrapping(arr)).to_pylist() == [100, -56]
+
+
+def test_cumulative_prod_min_max():
+    ca = ChunkedArray(
+        [Array([3, 1], DataType.int64()), Array([4, 2], DataType.int64())]
+    )
+    assert pa.chunked_array(ac.cumulative_prod(ca).read_all()).to_pylist() == [
+        3,
+        3,
+        12,
+        24,
+    ]
+    assert pa.chunked_array(ac.cumulative_min(ca).read_all()).to_pylist() == [
+        3,
+        1,
+        1,
+        1,
+    ]
+    assert pa.chunked_array(ac.cumulative_max(ca).read_all()).to_pylist() == [
+        3,
+        3,
+        4,
+        4,
+    ]
+
+
+def test_cumulative_decimal():
+    arr = pa.array([Decimal("1.50"), Decimal("2.00")], pa.decimal128(4, 2))
+    out = pa.array(ac.cumulative_sum(arr))
+    assert out.type == pa.decimal128(4, 2)
+    assert out.to_pylist() == [Decimal("1.50"), Decimal("3.50")]
+
+    with pytest.raises(Exception):
+        ac.cumulative_prod(arr)
+    with pytest.raises(Exception):
+        ac.cumulative_sum(pa.array([Decimal("99.00")] * 2, pa.decimal128(4, 2)))
+
+
+def test_cumulative_temporal():
+    arr = pa.array([date(2024, 1, 2), date(2024, 1, 1)])
+    assert pa.array(ac.cumulative_max(arr)).to_pylist() == [date(2024, 1, 2)] * 2
+    with pytest.raises(Exception):
+        ac.cumulative_sum(arr)
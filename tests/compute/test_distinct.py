@@ -0,0 +1,47 @@
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import ChunkedArray
+
+
+def test_unique():
+    arr = pa.array([3, 1, 3, None, 1, None])
+    assert pa.array(ac.unique(arr)).to_pylist() == [3, 1, None]
+
+
+def test_unique_chunked_dictionary():
+    chunks = [
+        pa.array(["a", "b", "a"]).dictionary_encode(),
+        pa.array(["c", None, "b"]).dictionary_encode(),
+    ]
+    ca = ChunkedArray(chunks)
+    out = pa.array(ac.unique(ca))
+    assert out.type == chunks[0].type
+    assert out.to_pylist() == ["a", "b", "c", None]
+
+
+def test_value_counts():
+    arr = pa.array(["x", "y", "x", None, "x"])
+    out = pa.array(ac.value_counts(arr))
+    assert out.type == pa.struct([("values", pa.utf8()), ("counts", pa.int64())])
+    assert out.to_pylist() == [
+        {"values": "x", "counts": 3},
+        {"values": "y", "counts": 1},
+        {"values": None, "counts": 1},
+    ]
+
+
+def test_count_distinct():
+    arr = pa.chunked_array([[1, 2, None], [2, 3]])
+    assert ac.count_distinct(ChunkedArray(arr)).as_py() == 3
+
+
+def test_dictionary_order_of_first_appearance():
+    arr = pa.DictionaryArray.from_arrays(
+        pa.array([1, None, 0, 1], pa.int32()), pa.array(["b", "a"])
+    )
+    assert pa.array(ac.unique(arr)).to_pylist() == ["a", None, "b"]
+    assert pa.array(ac.value_counts(arr)).to_pylist() == [
+        {"values": "a", "counts": 2},
+        {"values": None, "counts": 1},
+        {"values": "b", "counts": 1},
+    ]
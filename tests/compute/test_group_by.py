@@ -0,0 +1,85 @@
+from decimal import Decimal
+
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+from arro3.core import Table
+
+
+def test_group_by():
+    table = pa.table(
+        {
+            "key": ["a", "b", "a", None, "b"],
+            "value": [1, 2, 3, 4, None],
+        }
+    )
+    out = pa.table(
+        ac.group_by(table, ["key"], {"value": ["sum", "mean", "count", "count_all"]})
+    )
+    assert out.column_names == [
+        "key",
+        "value_sum",
+        "value_mean",
+        "value_count",
+        "value_count_all",
+    ]
+    assert out.to_pydict() == {
+        "key": ["a", "b", None],
+        "value_sum": [4, 2, 4],
+        "value_mean": [2.0, 2.0, 4.0],
+        "value_count": [2, 1, 1],
+        "value_count_all": [2, 2, 1],
+    }
+
+
+def test_group_by_multiple_keys_min_max():
+    table = pa.table(
+        {
+            "k1": [1, 1, 2, 1],
+            "k2": ["x", "y", "x", "x"],
+            "value": ["c", "a", "b", "d"],
+        }
+    )
+    out = pa.table(ac.group_by(table, ["k1", "k2"], {"value": ["min", "max"]}))
+    assert out.to_pydict() == {
+        "k1": [1, 1, 2],
+        "k2": ["x", "y", "x"],
+        "value_min": ["c", "a", "b"],
+        "value_max": ["d", "a", "b"],
+    }
+
+
+def test_group_by_dictionary_key():
+    key = pa.array(["a", "b", "a"]).dictionary_encode()
+    table = pa.table({"key": key, "value": [1.5, 2.0, 3.0]})
+    out = pa.table(ac.group_by(table, ["key"], {"value": "sum"}))
+    assert out.schema.field("key").type == key.type
+    assert out.column("key").to_pylist() == ["a", "b"]
+    assert out.column("value_sum").to_pylist() == [4.5, 2.0]
+
+
+def test_group_by_stream():
+    table = pa.table({"key": [1, 2, 1, 2], "value": [1, 2, 3, 4]})
+    reader = pa.RecordBatchReader.from_batches(table.schema, table.to_batches(2))
+    out = ac.group_by(reader, ["key"], {"value": "sum"})
+    assert isinstance(out, Table)
+    assert pa.table(out).to_pydict() == {"key": [1, 2], "value_sum": [4, 6]}
+
+
+def test_group_by_invalid_aggregation():
+    table = pa.table({"key": [1], "value": ["a"]})
+    with pytest.raises(Exception):
+        ac.group_by(table, ["key"], {"value": "mean"})
+
+
+def test_group_by_sum_decimal256():
+    values = [Decimal("1" * 40 + ".5"), Decimal("0.25"), Decimal("2.25")]
+    table = pa.table(
+        {"key": ["a", "a", "b"], "value": pa.array(values, pa.decimal256(50, 2))}
+    )
+    out = pa.table(ac.group_by(table, ["key"], {"value": "sum"}))
+    assert out.schema.field("value_sum").type == pa.decimal256(76, 2)
+    assert out.column("value_sum").to_pylist() == [
+        Decimal("1" * 40 + ".75"),
+        Decimal("2.25"),
+    ]
@@ -0,0 +1,91 @@
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+from arro3.core import RecordBatchReader, Table
+
+LEFT = pa.table({"id": [1, 2, 3, None], "name": ["a", "b", "c", "d"]})
+RIGHT = pa.table({"id": [2, 3, 3, 4], "name": ["x", "y", "z", "w"]})
+
+
+def test_inner_join():
+    out = ac.join(LEFT, RIGHT, "id")
+    assert isinstance(out, Table)
+    assert pa.table(out).to_pydict() == {
+        "id": [2, 3, 3],
+        "name": ["b", "c", "c"],
+        "name_right": ["x", "y", "z"],
+    }
+
+
+def test_left_join():
+    out = pa.table(ac.join(LEFT, RIGHT, "id", "left"))
+    assert out.to_pydict() == {
+        "id": [1, 2, 3, 3, None],
+        "name": ["a", "b", "c", "c", "d"],
+        "name_right": [None, "x", "y", "z", None],
+    }
+
+
+def test_full_join():
+    out = pa.table(ac.join(LEFT, RIGHT, "id", "full", suffixes=("_l", "_r")))
+    assert out.to_pydict() == {
+        "id": [1, 2, 3, 3, None, 4],
+        "name_l": ["a", "b", "c", "c", "d", None],
+        "name_r": [None, "x", "y", "z", None, "w"],
+    }
+
+
+def test_semi_anti_join():
+    semi = pa.table(ac.join(LEFT, RIGHT, "id", "semi"))
+    assert semi.to_pydict() == {"id": [2, 3], "name": ["b", "c"]}
+    anti = pa.table(ac.join(LEFT, RIGHT, "id", "anti"))
+    assert anti.to_pydict() == {"id": [1, None], "name": ["a", "d"]}
+
+
+def test_multi_key_lazy():
+    left = pa.table({"a": [1, 1, 2], "b": ["x", "y", "x"], "v": [10, 20, 30]})
+    right = pa.table({"a2": [1, 2], "b2": ["y", "x"], "w": [True, False]})
+    reader = pa.RecordBatchReader.from_batches(left.schema, left.to_batches(1))
+    out = ac.join(reader, right, ["a", "b"], right_on=["a2", "b2"], lazy=True)
+    assert isinstance(out, RecordBatchReader)
+    assert pa.table(out.read_all()).to_pydict() == {
+        "a": [1, 2],
+        "b": ["y", "x"],
+        "v": [20, 30],
+        "w": [True, False],
+    }
+
+
+def test_join_keys_of_different_types():
+    left = pa.table({"id": pa.array([1, 2, 100], pa.int8()), "a": ["x", "y", "z"]})
+    right = pa.table({"id": pa.array([1.5, 2.0, 356.0]), "b": [10, 20, 30]})
+    out = pa.table(ac.join(left, right, "id"))
+    assert out.to_pydict() == {"id": [2], "a": ["y"], "b": [20]}
+    assert out.schema.field("id").type == pa.int8()
+
+    # Keys are compared as int64, so 356 does not need to fit in int8
+    right = pa.table({"id": pa.array([356, 1], pa.int64()), "b": [1, 2]})
+    out = pa.table(ac.join(left, right, "id"))
+    assert out.to_pydict() == {"id": [1], "a": ["x"], "b": [2]}
+
+    right = pa.table({"id": ["1"], "b": [1]})
+    with pytest.raises(Exception, match="Cannot join"):
+        ac.join(left, right, "id")
+
+
+def test_right_and_full_join_keys_of_different_types():
+    left = pa.table({"id": pa.array([1, 2], pa.int8()), "a": ["x", "y"]})
+    right = pa.table({"id": pa.array([2, 356], pa.int64()), "b": [20, 30]})
+    out = pa.table(ac.join(left, right, "id", "right"))
+    assert out.schema.field("id").type == pa.int64()
+    assert out.to_pydict() == {"id": [2, 356], "a": ["y", None], "b": [20, 30]}
+
+    # An unmatched 1.5 must not be truncated to the left key 1
+    right = pa.table({"id": pa.array([1.5, 2.0]), "b": [15, 20]})
+    out = pa.table(ac.join(left, right, "id", "full"))
+    assert out.schema.field("id").type == pa.float64()
+    assert out.to_pydict() == {
+        "id": [1.0, 2.0, 1.5],
+        "a": ["x", "y", None],
+        "b": [None, 20, 15],
+    }
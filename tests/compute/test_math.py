@@ -0,0 +1,133 @@
+import math
+from decimal import Decimal
+
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+from arro3.core import Array, ChunkedArray, DataType
+
+
+def test_round_modes():
+    arr = Array([-2.5, -1.5, -0.5, 0.5, 1.5, 2.5, 2.4, None], DataType.float64())
+    out = pa.array(ac.round(arr))
+    assert out.to_pylist() == [-2.0, -2.0, -0.0, 0.0, 2.0, 2.0, 2.0, None]
+    out = pa.array(ac.round(arr, mode="half_towards_infinity"))
+    assert out.to_pylist() == [-3.0, -2.0, -1.0, 1.0, 2.0, 3.0, 2.0, None]
+    out = pa.array(ac.round(arr, mode="half_down"))
+    assert out.to_pylist() == [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 2.0, None]
+    out = pa.array(ac.round(arr, mode=ac.enums.RoundMode.HalfToOdd))
+    assert out.to_pylist() == [-3.0, -1.0, -1.0, 1.0, 1.0, 3.0, 2.0, None]
+
+
+def test_round_ndigits():
+    arr = Array([1.2345, 1234.5], DataType.float64())
+    assert pa.array(ac.round(arr, 2)).to_pylist() == [1.23, 1234.5]
+    assert pa.array(ac.round(arr, -2)).to_pylist() == [0.0, 1200.0]
+
+    arr = Array([149, 150, -251], DataType.int32())
+    out = pa.array(ac.round(arr, -2))
+    assert out.type == pa.int32()
+    assert out.to_pylist() == [100, 200, -300]
+
+    # Rounding to a power of ten beyond the float range gives a signed zero
+    arr = Array([1.0, -1.0], DataType.float64())
+    out = pa.array(ac.round(arr, -400)).to_pylist()
+    assert out == [0.0, 0.0]
+    assert [math.copysign(1.0, x) for x in out] == [1.0, -1.0]
+
+
+def test_round_decimal_keeps_precision_and_scale():
+    arr = pa.array(
+        [Decimal("1.235"), Decimal("-1.245"), None], type=pa.decimal128(6, 3)
+    )
+    out = pa.array(ac.round(arr, 2))
+    assert out.type == pa.decimal128(6, 3)
+    assert out.to_pylist() == [Decimal("1.240"), Decimal("-1.240"), None]
+
+    arr = pa.array([Decimal("12.5")], type=pa.decimal256(40, 1))
+    out = pa.array(ac.round(arr, mode="up"))
+    assert out.type == pa.decimal256(40, 1)
+    assert out.to_pylist() == [Decimal("13.0")]
+
+
+def test_round_decimal_overflow():
+    arr = pa.array([Decimal("99.9")], type=pa.decimal128(3, 1))
+    with pytest.raises(Exception):
+        ac.round(arr)
+
+
+def test_floor_ceil_trunc():
+    arr = Array([-1.5, 1.5], DataType.float32())
+    assert pa.array(ac.floor(arr)).to_pylist() == [-2.0, 1.0]
+    assert pa.array(ac.ceil(arr)).to_pylist() == [-1.0, 2.0]
+    assert pa.array(ac.trunc(arr)).to_pylist() == [-1.0, 1.0]
+    assert pa.array(ac.floor(arr)).type == pa.float32()
+
+
+def test_abs_and_sign():
+    arr = Array([-3, 0, 2, None], DataType.int64())
+    assert pa.array(ac.abs(arr)).to_pylist() == [3, 0, 2, None]
+    out = pa.array(ac.sign(arr))
+    assert out.type == pa.int8()
+    assert out.to_pylist() == [-1, 0, 1, None]
+
+    with pytest.raises(Exception):
+        ac.abs(Array([-128], DataType.int8()))
+
+    arr = pa.array([Decimal("-1.50")], type=pa.decimal128(4, 2))
+    out = pa.array(ac.abs(arr))
+    assert out.type == pa.decimal128(4, 2)
+    assert out.to_pylist() == [Decimal("1.50")]
+
+
+def test_float_functions():
+    arr = Array([1, 4, None], DataType.int64())
+    out = pa.array(ac.sqrt(arr))
+    assert out.type == pa.float64()
+    assert out.to_pylist() == [1.0, 2.0, None]
+
+    arr = Array([1.0, 100.0], DataType.float32())
+    out = pa.array(ac.log10(arr))
+    assert out.type == pa.float32()
+    assert out.to_pylist() == [0.0, 2.0]
+
+    assert pa.array(ac.log2(Array([8.0], DataType.float64()))).to_pylist() == [3.0]
+    assert pa.array(ac.ln(Array([1.0], DataType.float64()))).to_pylist() == [0.0]
+    assert math.isnan(pa.array(ac.sqrt(Array([-1.0], DataType.float64())))[0].as_py())
+
+
+def test_trig_functions():
+    arr = Array([0.0, 1.0], DataType.float64())
+    assert pa.array(ac.sin(arr)).to_pylist() == [0.0, math.sin(1.0)]
+    assert pa.array(ac.cos(arr)).to_pylist() == [1.0, math.cos(1.0)]
+    assert pa.array(ac.atan(arr)).to_pylist() == [0.0, math.atan(1.0)]
+    assert pa.array(ac.acos(arr)).to_pylist() == [math.acos(0.0), 0.0]
+
+
+def test_pow():
+    base = Array([2, 3, None], DataType.int64())
+    out = pa.array(ac.pow(base, Array([3], DataType.int32())))
+    assert out.type == pa.int64()
+    assert out.to_pylist() == [8, 27, None]
+
+    out = pa.array(ac.pow(base, Array([0.5, 2.0, 1.0], DataType.float64())))
+    assert out.type == pa.float64()
+    assert out.to_pylist() == [2**0.5, 9.0, None]
+
+    with pytest.raises(Exception):
+        ac.pow(base, Array([-1], DataType.int64()))
+
+    # A uint64 base beyond the int64 range raises rather than becoming null
+    with pytest.raises(Exception):
+        ac.pow(Array([2**63, 2], DataType.uint64()), Array([1], DataType.int64()))
+
+
+def test_math_streams():
+    ca = ChunkedArray(
+        [Array([1.25, 2.5], DataType.float64()), Array([3.75], DataType.float64())]
+    )
+    out = pa.chunked_array(ac.round(ca, 1))
+    assert out.to_pylist() == [1.2, 2.5, 3.8]
+
+    out = pa.chunked_array(ac.pow(ca, Array([2], DataType.int64())))
+    assert out.to_pylist() == [1.5625, 6.25, 14.0625]
@@ -0,0 +1,121 @@
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+from arro3.core import Array, ChunkedArray, DataType, RecordBatch, Table
+
+
+def test_fill_null_scalar():
+    arr = Array([1, None, 3, None], DataType.int64())
+    out = ac.fill_null(arr, Array([0], DataType.int32()))
+    assert pa.array(out).to_pylist() == [1, 0, 3, 0]
+    out = ac.fill_null(arr, Array([9], DataType.int64())[0])
+    assert pa.array(out).to_pylist() == [1, 9, 3, 9]
+    assert pa.array(out).type == pa.int64()
+
+
+def test_fill_null_array():
+    arr = Array([1, None, 3, None], DataType.int64())
+    fill = Array([10, 20, 30, 40], DataType.int64())
+    assert pa.array(ac.fill_null(arr, fill)).to_pylist() == [1, 20, 3, 40]
+
+
+def test_fill_null_out_of_range():
+    arr = Array([1, None], DataType.int8())
+    with pytest.raises(Exception):
+        ac.fill_null(arr, Array([1000], DataType.int64()))
+    with pytest.raises(Exception, match="losing precision"):
+        ac.fill_null(arr, Array([1.5], DataType.float64()))
+    out = ac.fill_null(arr, Array([2.0], DataType.float64()))
+    assert pa.array(out).to_pylist() == [1, 2]
+
+
+def test_fill_null_forward_across_chunks():
+    ca = ChunkedArray(
+        [
+            Array([None, 1, None], DataType.int64()),
+            Array([None, None], DataType.int64()),
+            Array([2, None], DataType.int64()),
+        ]
+    )
+    out = pa.chunked_array(ac.fill_null_forward(ca))
+    assert out.to_pylist() == [None, 1, 1, 1, 1, 2, 2]
+
+
+def test_fill_null_backward_across_chunks():
+    ca = ChunkedArray(
+        [
+            Array([None, 1, None], DataType.int64()),
+            Array([None, None], DataType.int64()),
+            Array([2, None], DataType.int64()),
+        ]
+    )
+    out = pa.chunked_array(ac.fill_null_backward(ca))
+    assert out.to_pylist() == [1, 1, 2, 2, 2, 2, None]
+    assert [len(chunk) for chunk in out.chunks] == [3, 2, 2]
+
+    arr = Array(["a", None, None, "b", None], DataType.string())
+    assert pa.array(ac.fill_null_backward(arr)).to_pylist() == [
+        "a",
+        "b",
+        "b",
+        "b",
+        None,
+    ]
+
+
+def test_coalesce():
+    a = Array([None, 2, None, None], DataType.int64())
+    b = Array([1, None, None, 4], DataType.int64())
+    out = ac.coalesce(a, b, Array([0], DataType.int64())[0])
+    assert pa.array(out).to_pylist() == [1, 2, 0, 4]
+
+    ca = ChunkedArray(
+        [Array([None, 5], DataType.int64()), Array([None], DataType.int64())]
+    )
+    out = pa.chunked_array(ac.coalesce(ca, Array([-1], DataType.int64())))
+    assert out.to_pylist() == [-1, 5, -1]
+
+
+def test_drop_null_array():
+    arr = Array([1, None, 3], DataType.int64())
+    assert pa.array(ac.drop_null(arr)).to_pylist() == [1, 3]
+
+
+def test_drop_null_tabular():
+    table = pa.table({"a": [1, None, None, 4], "b": ["w", "x", None, None]})
+    out = ac.drop_null(table)
+    assert isinstance(out, Table)
+    assert pa.table(out).to_pydict() == {"a": [1], "b": ["w"]}
+
+    out = ac.drop_null(table, how="all")
+    assert pa.table(out).to_pydict() == {"a": [1, None, 4], "b": ["w", "x", None]}
+
+    out = ac.drop_null(table.to_batches()[0], ["a"])
+    assert isinstance(out, RecordBatch)
+    assert pa.record_batch(out).to_pydict() == {"a": [1, 4], "b": ["w", None]}
+
+    with pytest.raises(KeyError):
+        ac.drop_null(table, ["c"])
+
+
+def test_coalesce_common_type():
+    a = Array([None, None], DataType.int8())
+    out = pa.array(ac.coalesce(a, Array([1000, 1], DataType.int64())))
+    assert out.type == pa.int64()
+    assert out.to_pylist() == [1000, 1]
+
+    out = pa.array(ac.coalesce(a, Array([1.5, 2.0], DataType.float64())))
+    assert out.type == pa.float64()
+    assert out.to_pylist() == [1.5, 2.0]
+
+    with pytest.raises(Exception, match="common type"):
+        ac.coalesce(a, Array(["x", "y"], DataType.string()))
+
+
+def test_coalesce_streams_with_different_chunks():
+    a = ChunkedArray([Array([None, 2, None], DataType.int64())])
+    b = ChunkedArray(
+        [Array([1], DataType.int64()), Array([None, 3], DataType.int64())]
+    )
+    out = pa.chunked_array(ac.coalesce(a, b))
+    assert out.to_pylist() == [1, 2, 3]
@@ -0,0 +1,41 @@
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import Array, ChunkedArray, DataType
+
+
+def test_predicates_literal_pattern():
+    arr = Array(["apple", "banana", None], DataType.string())
+    assert pa.array(ac.starts_with(arr, "ap")).to_pylist() == [True, False, None]
+    assert pa.array(ac.ends_with(arr, "na")).to_pylist() == [False, True, None]
+    assert pa.array(ac.contains(arr, "nan")).to_pylist() == [False, True, None]
+    assert pa.array(ac.like(arr, "b%a")).to_pylist() == [False, True, None]
+    assert pa.array(ac.ilike(arr, "APP%")).to_pylist() == [True, False, None]
+
+
+def test_predicates_large_and_binary():
+    arr = Array(["abc", "xyz"], DataType.large_string())
+    assert pa.array(ac.starts_with(arr, "a")).to_pylist() == [True, False]
+
+    arr = Array([b"abc", b"xyz"], DataType.binary())
+    assert pa.array(ac.ends_with(arr, "yz")).to_pylist() == [False, True]
+
+
+def test_regex_match():
+    arr = Array(["error: x", "INFO", "Error: y"], DataType.string())
+    assert pa.array(ac.regex_match(arr, "^error")).to_pylist() == [True, False, False]
+    assert pa.array(ac.regex_match(arr, "^error", ignore_case=True)).to_pylist() == [
+        True,
+        False,
+        True,
+    ]
+
+    patterns = Array(["x$", "^I", "z"], DataType.string())
+    assert pa.array(ac.regex_match(arr, patterns)).to_pylist() == [True, True, False]
+
+
+def test_regex_match_stream():
+    ca = ChunkedArray(
+        [Array(["a1", "b"], DataType.string()), Array(["c2"], DataType.string())]
+    )
+    out = ac.regex_match(ca, r"\d").read_all()
+    assert pa.chunked_array(out).to_pylist() == [True, False, True]
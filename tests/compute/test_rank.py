@@ -0,0 +1,37 @@
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import Array, ChunkedArray, DataType
+
+
+def test_rank_tiebreakers():
+    arr = Array([30, 10, 30, None, 20], DataType.int64())
+    assert pa.array(ac.rank(arr)).to_pylist() == [3, 1, 3, 5, 2]
+    assert pa.array(ac.rank(arr, tiebreaker="max")).to_pylist() == [4, 1, 4, 5, 2]
+    assert pa.array(ac.row_number(arr)).to_pylist() == [3, 1, 4, 5, 2]
+    assert pa.array(ac.dense_rank(arr)).to_pylist() == [3, 1, 3, 4, 2]
+    out = ac.rank(arr, order="descending", null_placement="at_start")
+    assert pa.array(out).to_pylist() == [2, 5, 2, 1, 4]
+    assert pa.array(out).type == pa.uint64()
+
+
+def test_rank_max_ties():
+    arr = Array([1, 1, 2], DataType.int64())
+    assert pa.array(ac.rank(arr, tiebreaker="max")).to_pylist() == [2, 2, 3]
+
+
+def test_rank_chunked_global():
+    ca = ChunkedArray(
+        [Array([3, 1], DataType.int64()), Array([2, 1], DataType.int64())]
+    )
+    assert pa.array(ac.rank(ca)).to_pylist() == [4, 1, 3, 1]
+
+
+def test_rank_table():
+    table = pa.table({"a": [1, 1, 2], "b": ["y", "x", "x"]})
+    assert pa.array(ac.rank(table, ["a", "b"])).to_pylist() == [2, 1, 3]
+
+
+def test_percent_rank():
+    arr = Array([10, 20, 20, 30], DataType.int64())
+    out = pa.array(ac.percent_rank(arr)).to_pylist()
+    assert out == [0.0, 1 / 3, 1 / 3, 1.0]
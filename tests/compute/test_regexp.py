@@ -0,0 +1,33 @@
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import Array, DataType
+
+
+def test_regex_extract():
+    arr = Array(["id=12", "none", None], DataType.string())
+    assert pa.array(ac.regex_extract(arr, r"id=(\d+)", 1)).to_pylist() == [
+        "12",
+        None,
+        None,
+    ]
+
+
+def test_regex_extract_all():
+    arr = Array(["a1b22", "x"], DataType.string())
+    out = pa.array(ac.regex_extract_all(arr, r"\d+"))
+    assert out.type == pa.list_(pa.utf8())
+    assert out.to_pylist() == [["1", "22"], []]
+
+
+def test_regex_extract_groups():
+    arr = Array(["k=v", "nope"], DataType.string())
+    out = pa.array(ac.regex_extract_groups(arr, r"(?P<key>\w+)=(?P<value>\w+)"))
+    assert out.to_pylist() == [{"key": "k", "value": "v"}, None]
+
+
+def test_regex_replace():
+    arr = Array(["aaa", "bab"], DataType.large_string())
+    assert pa.array(ac.regex_replace(arr, "a", "x")).to_pylist() == ["xxx", "bxb"]
+    assert pa.array(
+        ac.regex_replace(arr, "a", "x", max_replacements=1)
+    ).to_pylist() == ["xaa", "bxb"]
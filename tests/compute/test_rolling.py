@@ -0,0 +1,84 @@
+import math
+from datetime import datetime
+
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import Array, ChunkedArray, DataType
+
+
+def test_rolling_rows_chunked():
+    ca = ChunkedArray(
+        [Array([1, 2, 3], DataType.int64()), Array([4, 5], DataType.int64())]
+    )
+    out = pa.chunked_array(ac.rolling_sum(ca, 2).read_all())
+    assert out.to_pylist() == [None, 3.0, 5.0, 7.0, 9.0]
+    assert [len(chunk) for chunk in out.chunks] == [3, 2]
+
+    out = pa.chunked_array(ac.rolling_max(ca, 3, min_periods=1).read_all())
+    assert out.to_pylist() == [1.0, 2.0, 3.0, 4.0, 5.0]
+
+
+def test_rolling_nulls():
+    arr = Array([1.0, None, 3.0, 5.0], DataType.float64())
+    out = ac.rolling_mean(arr, 2, min_periods=1)
+    assert pa.array(out).to_pylist() == [1.0, 1.0, 3.0, 4.0]
+    out = ac.rolling_min(arr, 2)
+    assert pa.array(out).to_pylist() == [None, None, None, 3.0]
+
+
+def test_rolling_time_window():
+    times = pa.array(
+        [
+            datetime(2024, 1, 1, 0, 0),
+            datetime(2024, 1, 1, 0, 2),
+            datetime(2024, 1, 1, 0, 5),
+            datetime(2024, 1, 1, 0, 9),
+        ],
+        pa.timestamp("us"),
+    )
+    values = pa.array([1, 2, 3, 4])
+    out = ac.rolling_sum(values, "5m", by=times)
+    assert pa.array(out).to_pylist() == [1.0, 3.0, 5.0, 7.0]
+
+
+def test_rolling_time_window_streams_with_different_chunks():
+    times = pa.array(
+        [
+            datetime(2024, 1, 1, 0, 0),
+            datetime(2024, 1, 1, 0, 2),
+            datetime(2024, 1, 1, 0, 5),
+            datetime(2024, 1, 1, 0, 9),
+        ],
+        pa.timestamp("us"),
+    )
+    times = pa.chunked_array([times[:1], times[1:]])
+    values = pa.chunked_array([pa.array([1, 2, 3]), pa.array([4])])
+    out = pa.chunked_array(ac.rolling_sum(values, "5m", by=times).read_all())
+    assert out.to_pylist() == [1.0, 3.0, 5.0, 7.0]
+
+
+def test_rolling_sum_non_finite():
+    arr = Array([float("inf"), 1.0, 2.0, float("nan"), 3.0, 4.0], DataType.float64())
+    out = pa.array(ac.rolling_sum(arr, 2, min_periods=1)).to_pylist()
+    assert out[:3] == [float("inf"), float("inf"), 3.0]
+    assert math.isnan(out[3]) and math.isnan(out[4])
+    assert out[5] == 7.0
+
+
+def test_rolling_min_max_nan():
+    nan = float("nan")
+    arr = Array([5.0, nan, 1.0, 2.0, 3.0, 4.0], DataType.float64())
+    out = pa.array(ac.rolling_min(arr, 3)).to_pylist()
+    assert out[:2] == [None, None]
+    assert math.isnan(out[2]) and math.isnan(out[3])
+    assert out[4:] == [1.0, 2.0]
+    out = pa.array(ac.rolling_max(arr, 3)).to_pylist()
+    assert math.isnan(out[2]) and math.isnan(out[3])
+    assert out[4:] == [3.0, 4.0]
+
+
+def test_rolling_sum_precision():
+    # Large values leaving the window must not leave rounding error behind
+    arr = Array([1e16, 1.0, 1.0, 1.0], DataType.float64())
+    out = pa.array(ac.rolling_sum(arr, 2, min_periods=1)).to_pylist()
+    assert out[2:] == [2.0, 2.0]
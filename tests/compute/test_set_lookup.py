@@ -0,0 +1,64 @@
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+from arro3.core import Array, DataType
+
+
+def test_is_in_index_in():
+    values = Array([1, 5, None, 3, 1], DataType.int64())
+    value_set = Array([3, 1, 3], DataType.int32())
+    assert pa.array(ac.is_in(values, value_set)).to_pylist() == [
+        True,
+        False,
+        False,
+        True,
+        True,
+    ]
+    out = pa.array(ac.index_in(values, value_set))
+    assert out.to_pylist() == [1, None, None, 0, 1]
+    assert out.type == pa.int32()
+
+
+def test_is_in_nulls():
+    values = pa.array(["a", None, "c"])
+    value_set = pa.array([None, "a"])
+    assert pa.array(ac.is_in(values, value_set)).to_pylist() == [True, True, False]
+    assert pa.array(ac.index_in(values, value_set)).to_pylist() == [1, 0, None]
+
+    out = ac.is_in(values, value_set, skip_nulls=True)
+    assert pa.array(out).to_pylist() == [True, False, False]
+
+
+def test_is_in_dictionary_and_chunked():
+    values = pa.chunked_array(
+        [
+            pa.array(["x", "y", None]).dictionary_encode(),
+            pa.array(["z", "x"]).dictionary_encode(),
+        ]
+    )
+    value_set = pa.array(["x", "z"])
+    out = pa.chunked_array(ac.index_in(values, value_set))
+    assert out.to_pylist() == [0, None, None, 1, 0]
+
+    value_set = pa.array(["y"]).dictionary_encode()
+    out = pa.chunked_array(ac.is_in(values, value_set))
+    assert out.to_pylist() == [False, True, False, False, False]
+
+
+def test_is_in_binary():
+    values = pa.array([b"a", b"b"])
+    assert pa.array(ac.is_in(values, pa.array([b"b"]))).to_pylist() == [False, True]
+
+
+def test_is_in_common_type():
+    values = Array([1.5, 1.0], DataType.float64())
+    value_set = Array([1, 2], DataType.int64())
+    assert pa.array(ac.is_in(values, value_set)).to_pylist() == [False, True]
+
+    # Values that do not fit the value set type must not become nulls that match a null
+    values = Array([1, 300], DataType.int64())
+    value_set = Array([1, None], DataType.int8())
+    assert pa.array(ac.index_in(values, value_set)).to_pylist() == [0, None]
+
+    with pytest.raises(Exception, match="Cannot look up"):
+        ac.is_in(Array(["1"], DataType.string()), Array([1], DataType.int64()))
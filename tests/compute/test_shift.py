@@ -0,0 +1,70 @@
+from datetime import datetime, timedelta
+
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import Array, ChunkedArray, DataType
+
+
+def chunked(*chunks):
+    return ChunkedArray([Array(chunk, DataType.int64()) for chunk in chunks])
+
+
+def test_shift_array():
+    arr = Array([1, 2, 3, 4], DataType.int64())
+    assert pa.array(ac.shift(arr)).to_pylist() == [None, 1, 2, 3]
+    assert pa.array(ac.shift(arr, -2)).to_pylist() == [3, 4, None, None]
+    fill = Array([0], DataType.int64())[0]
+    assert pa.array(ac.shift(arr, 2, fill)).to_pylist() == [0, 0, 1, 2]
+
+
+def test_shift_across_chunks():
+    ca = chunked([1], [2, 3], [4, 5, 6])
+    out = pa.chunked_array(ac.shift(ca, 2))
+    assert [len(chunk) for chunk in out.chunks] == [1, 2, 3]
+    assert out.to_pylist() == [None, None, 1, 2, 3, 4]
+
+    out = pa.chunked_array(ac.shift(ca, -2))
+    assert [len(chunk) for chunk in out.chunks] == [1, 2, 3]
+    assert out.to_pylist() == [3, 4, 5, 6, None, None]
+
+
+def test_shift_longer_than_input():
+    ca = chunked([1], [2])
+    assert pa.chunked_array(ac.shift(ca, 5)).to_pylist() == [None, None]
+    assert pa.chunked_array(ac.shift(ca, -5)).to_pylist() == [None, None]
+
+
+def test_shift_huge_periods():
+    # The fill values are not sized by periods, so this does not try to allocate them
+    arr = Array([1, 2, 3], DataType.int64())
+    fill = Array([0], DataType.int64())[0]
+    assert pa.array(ac.shift(arr, 2**62, fill)).to_pylist() == [0, 0, 0]
+    assert pa.array(ac.shift(arr, -(2**62))).to_pylist() == [None, None, None]
+
+    ca = chunked([1], [2, 3])
+    out = pa.chunked_array(ac.shift(ca, 2**62, fill))
+    assert [len(chunk) for chunk in out.chunks] == [1, 2]
+    assert out.to_pylist() == [0, 0, 0]
+    out = pa.chunked_array(ac.diff(ca, -(2**62)))
+    assert out.to_pylist() == [None, None, None]
+
+
+def test_diff_across_chunks():
+    ca = chunked([1, 4], [9], [16, 25])
+    assert pa.chunked_array(ac.diff(ca)).to_pylist() == [None, 3, 5, 7, 9]
+    assert pa.chunked_array(ac.diff(ca, -1)).to_pylist() == [-3, -5, -7, -9, None]
+
+
+def test_diff_timestamp():
+    start = datetime(2024, 1, 1)
+    arr = pa.array([start, start + timedelta(seconds=90)], pa.timestamp("s"))
+    out = pa.array(ac.diff(arr))
+    assert out.type == pa.duration("s")
+    assert out.to_pylist() == [None, timedelta(seconds=90)]
+
+
+def test_pct_change():
+    ca = chunked([100, 110], [99, 0])
+    out = pa.chunked_array(ac.pct_change(ca))
+    assert out.type == pa.float64()
+    assert out.to_pylist() == [None, 0.1, -0.1, -1.0]
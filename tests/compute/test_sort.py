@@ -0,0 +1,32 @@
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import Array, ChunkedArray, DataType, Table
+
+
+def test_sort_indices_array():
+    arr = Array([3, 1, None, 2], DataType.int32())
+    assert pa.array(ac.sort_indices(arr)).to_pylist() == [1, 3, 0, 2]
+    assert pa.array(
+        ac.sort_indices(arr, order="descending", null_placement="at_start")
+    ).to_pylist() == [2, 0, 3, 1]
+
+
+def test_sort_chunked_is_global():
+    ca = ChunkedArray(
+        [Array([5, 1], DataType.int64()), Array([3, 0], DataType.int64())]
+    )
+    assert pa.array(ac.sort_indices(ca)).to_pylist() == [3, 1, 2, 0]
+    assert pa.array(ac.sort(ca)).to_pylist() == [0, 1, 3, 5]
+
+
+def test_sort_table_multi_key():
+    pa_table = pa.table(
+        {"a": [1, 2, 1, 2], "b": ["x", "y", "z", "w"]},
+    )
+    table = Table.from_arrow(pa_table)
+    out = pa.table(ac.sort(table, [("a", "descending"), "b"]))
+    assert out.column("a").to_pylist() == [2, 2, 1, 1]
+    assert out.column("b").to_pylist() == ["w", "y", "x", "z"]
+
+    indices = ac.sort_indices(table, [1])
+    assert pa.array(indices).to_pylist() == [3, 0, 1, 2]
@@ -0,0 +1,43 @@
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import Array, ChunkedArray, DataType
+
+
+def test_case_and_length():
+    arr = Array(["Hello", None, "wörld"], DataType.string())
+    assert pa.array(ac.upper(arr)).to_pylist() == ["HELLO", None, "WÖRLD"]
+    assert pa.array(ac.utf8_length(arr)).to_pylist() == [5, None, 5]
+
+    large = Array(["ab"], DataType.large_string())
+    assert pa.array(ac.utf8_length(large)).type == pa.int64()
+
+
+def test_substring_strip_pad():
+    arr = Array(["  abc  ", "xyz"], DataType.string_view())
+    assert pa.array(ac.strip(arr)).to_pylist() == ["abc", "xyz"]
+    assert pa.array(ac.substring(arr, -2)).to_pylist() == ["  ", "yz"]
+    padded = ac.pad(Array(["a"], DataType.string()), 3, side="both", padding="*")
+    assert pa.array(padded).to_pylist() == ["*a*"]
+
+
+def test_dictionary_not_decoded():
+    arr = pa.array(["a", "b", "a"]).dictionary_encode()
+    out = pa.array(ac.upper(arr))
+    assert pa.types.is_dictionary(out.type)
+    assert out.to_pylist() == ["A", "B", "A"]
+
+
+def test_concat_ws_stream():
+    a = ChunkedArray([Array(["a", None], DataType.string())])
+    b = ChunkedArray([Array(["b", "c"], DataType.string())])
+    out = ac.concat_ws("-", a, b).read_all()
+    assert pa.chunked_array(out).to_pylist() == ["a-b", "c"]
+
+
+def test_concat_ws_streams_with_different_chunks():
+    a = ChunkedArray([Array(["a", "b", "c"], DataType.string())])
+    b = ChunkedArray(
+        [Array(["x"], DataType.string()), Array(["y", "z"], DataType.string())]
+    )
+    out = ac.concat_ws("-", a, b).read_all()
+    assert pa.chunked_array(out).to_pylist() == ["a-x", "b-y", "c-z"]
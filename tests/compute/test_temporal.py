@@ -0,0 +1,301 @@
+from datetime import date, datetime, timezone
+
+import arro3.compute as ac
+import pyarrow as pa
+import pytest
+
+
+def test_floor_ceil_round_minutes():
+    arr = pa.array(
+        [datetime(2024, 1, 1, 10, 7), datetime(2024, 1, 1, 10, 7, 30), None],
+        pa.timestamp("s"),
+    )
+    out = pa.array(ac.floor_temporal(arr, 15, "minute"))
+    assert out.type == pa.timestamp("s")
+    assert out.to_pylist() == [
+        datetime(2024, 1, 1, 10, 0),
+        datetime(2024, 1, 1, 10, 0),
+        None,
+    ]
+    out = pa.array(ac.ceil_temporal(arr, 15, "minute"))
+    assert out.to_pylist() == [
+        datetime(2024, 1, 1, 10, 15),
+        datetime(2024, 1, 1, 10, 15),
+        None,
+    ]
+    out = pa.array(ac.round_temporal(arr, 15, "minute"))
+    assert out.to_pylist() == [
+        datetime(2024, 1, 1, 10, 0),
+        datetime(2024, 1, 1, 10, 15),
+        None,
+    ]
+
+
+def test_ceil_on_boundary():
+    arr = pa.array([datetime(2024, 1, 1, 10, 15)], pa.timestamp("ms"))
+    out = pa.array(ac.ceil_temporal(arr, 15, "minute"))
+    assert out.to_pylist() == [datetime(2024, 1, 1, 10, 15)]
+
+
+def test_date_trunc_week():
+    # 2024-01-03 is a Wednesday
+    arr = pa.array([date(2024, 1, 3)], pa.date32())
+    assert pa.array(ac.date_trunc(arr, "week")).to_pylist() == [date(2024, 1, 1)]
+    out = ac.date_trunc(arr, "week", week_starts_monday=False)
+    assert pa.array(out).to_pylist() == [date(2023, 12, 31)]
+
+
+def test_calendar_units():
+    arr = pa.array([date(2024, 5, 17)], pa.date32())
+    assert pa.array(ac.date_trunc(arr, "month")).to_pylist() == [date(2024, 5, 1)]
+    assert pa.array(ac.date_trunc(arr, "quarter")).to_pylist() == [date(2024, 4, 1)]
+    assert pa.array(ac.date_trunc(arr, "year")).to_pylist() == [date(2024, 1, 1)]
+    out = pa.array(ac.ceil_temporal(arr, 1, "month"))
+    assert out.type == pa.date32()
+    assert out.to_pylist() == [date(2024, 6, 1)]
+
+    with pytest.raises(ValueError):
+        ac.date_trunc(arr, "hour")
+
+
+def test_date_trunc_timezone():
+    arr = pa.array(
+        [datetime(2024, 3, 10, 12, 30, tzinfo=timezone.utc)],
+        pa.timestamp("us", tz="America/New_York"),
+    )
+    out = pa.array(ac.date_trunc(arr, "day"))
+    assert out.type == pa.timestamp("us", tz="America/New_York")
+    # Midnight on 2024-03-10 in New York is still EST (UTC-5)
+    assert out.to_pylist() == [datetime(2024, 3, 10, 5, tzinfo=timezone.utc)]
+
+
+def test_date_trunc_repeated_hour():
+    # 01:30 occurs twice in New York on 2024-11-03, as EDT (05:30Z) and EST (06:30Z)
+    arr = pa.array(
+        [
+            datetime(2024, 11, 3, 5, 30, 15, tzinfo=timezone.utc),
+            datetime(2024, 11, 3, 6, 30, 15, tzinfo=timezone.utc),
+        ],
+        pa.timestamp("us", tz="America/New_York"),
+    )
+    out = pa.array(ac.date_trunc(arr, "minute"))
+    assert out.to_pylist() == [
+        datetime(2024, 11, 3, 5, 30, tzinfo=timezone.utc),
+        datetime(2024, 11, 3, 6, 30, tzinfo=timezone.utc),
+    ]
+    out = pa.array(ac.floor_temporal(arr, 1, "hour"))
+    assert out.to_pylist() == [
+        datetime(2024, 11, 3, 5, tzinfo=timezone.utc),
+        datetime(2024, 11, 3, 6, tzinfo=timezone.utc),
+    ]
+
+
+def test_temporal_rounding_stream():
+    chunks = [
+        pa.array([datetime(2024, 1, 1, 1, 59)], pa.timestamp("s")),
+        pa.array([datetime(2024, 1, 1, 3, 1)], pa.timestamp("s")),
+    ]
+    ca = pa.chunked_array(chunks)
+    out = pa.chunked_array(ac.floor_temporal(ca, 2, "hour"))
+    assert out.type == pa.timestamp("s")
+    assert out.to_pylist() == [datetime(2024, 1, 1, 0), datetime(2024, 1, 1, 2)]
+
+
+def test_assume_timezone():
+    arr = pa.array([datetime(2024, 1, 15, 12), None], pa.timestamp("us"))
+    out = pa.array(ac.assume_timezone(arr, "America/New_York"))
+    assert out.type == pa.timestamp("us", tz="America/New_York")
+    assert out.to_pylist() == [datetime(2024, 1, 15, 17, tzinfo=timezone.utc), None]
+
+    out = pa.array(ac.assume_timezone(arr, "+05:30"))
+    assert out.to_pylist() == [datetime(2024, 1, 15, 6, 30, tzinfo=timezone.utc), None]
+
+
+def test_assume_timezone_ambiguous():
+    # Clocks in New York went back from 02:00 EDT to 01:00 EST on 2024-11-03
+    arr = pa.array([datetime(2024, 11, 3, 1, 30)], pa.timestamp("s"))
+    with pytest.raises(Exception, match="ambiguous"):
+        ac.assume_timezone(arr, "America/New_York")
+    out = ac.assume_timezone(arr, "America/New_York", ambiguous="earliest")
+    assert pa.array(out).to_pylist() == [
+        datetime(2024, 11, 3, 5, 30, tzinfo=timezone.utc)
+    ]
+    out = ac.assume_timezone(arr, "America/New_York", ambiguous="latest")
+    assert pa.array(out).to_pylist() == [
+        datetime(2024, 11, 3, 6, 30, tzinfo=timezone.utc)
+    ]
+
+
+def test_assume_timezone_nonexistent():
+    # Clocks in New York went forward from 02:00 EST to 03:00 EDT on 2024-03-10
+    arr = pa.array([datetime(2024, 3, 10, 2, 30)], pa.timestamp("s"))
+    with pytest.raises(Exception, match="does not exist"):
+        ac.assume_timezone(arr, "America/New_York")
+    out = ac.assume_timezone(arr, "America/New_York", nonexistent="latest")
+    assert pa.array(out).to_pylist() == [datetime(2024, 3, 10, 7, tzinfo=timezone.utc)]
+    out = ac.assume_timezone(arr, "America/New_York", nonexistent="earliest")
+    assert pa.array(out).to_pylist() == [
+        datetime(2024, 3, 10, 6, 59, 59, tzinfo=timezone.utc)
+    ]
+
+
+def test_local_timestamp_roundtrip():
+    naive = pa.chunked_array(
+        [
+            pa.array([datetime(2024, 7, 1, 9)], pa.timestamp("ms")),
+            pa.array([datetime(2024, 12, 1, 9)], pa.timestamp("ms")),
+        ]
+    )
+    zoned = pa.chunked_array(ac.assume_timezone(naive, "Europe/Berlin"))
+    out = pa.chunked_array(ac.local_timestamp(zoned))
+    assert out.type == pa.timestamp("ms")
+    assert out.to_pylist() == naive.to_pylist()
+
+
+def test_convert_timezone():
+    arr = pa.array(
+        [datetime(2024, 1, 1, tzinfo=timezone.utc)], pa.timestamp("s", tz="UTC")
+    )
+    out = pa.array(ac.convert_timezone(arr, "Asia/Tokyo"))
+    assert out.type == pa.timestamp("s", tz="Asia/Tokyo")
+    assert out.to_pylist() == arr.to_pylist()
+
+    with pytest.raises(ValueError):
+        ac.convert_timezone(pa.array([datetime(2024, 1, 1)]), "Asia/Tokyo")
+
+
+@pytest.mark.parametrize("string_type", [pa.utf8(), pa.large_utf8(), pa.string_view()])
+def test_strptime(string_type):
+    arr = pa.array(["2024-01-15 12:30:00", None], string_type)
+    out = pa.array(ac.strptime(arr, "%Y-%m-%d %H:%M:%S", "ms"))
+    assert out.type == pa.timestamp("ms")
+    assert out.to_pylist() == [datetime(2024, 1, 15, 12, 30), None]
+
+
+def test_strptime_timezone():
+    arr = pa.array(["2024-01-15"])
+    out = pa.array(ac.strptime(arr, "%Y-%m-%d", "s", "America/New_York"))
+    assert out.type == pa.timestamp("s", tz="America/New_York")
+    assert out.to_pylist() == [datetime(2024, 1, 15, 5, tzinfo=timezone.utc)]
+
+    arr = pa.array(["2024-01-15 12:00:00 +0200"])
+    out = pa.array(ac.strptime(arr, "%Y-%m-%d %H:%M:%S %z", "s"))
+    assert out.type == pa.timestamp("s", tz="UTC")
+    assert out.to_pylist() == [datetime(2024, 1, 15, 10, tzinfo=timezone.utc)]
+
+
+def test_strptime_ambiguous_nonexistent():
+    fmt = "%Y-%m-%d %H:%M"
+    tz = "America/New_York"
+    ambiguous = pa.array(["2024-11-03 01:30"])
+    with pytest.raises(Exception, match="ambiguous"):
+        ac.strptime(ambiguous, fmt, "s", tz)
+    out = pa.array(ac.strptime(ambiguous, fmt, "s", tz, ambiguous="latest"))
+    assert out.to_pylist() == [datetime(2024, 11, 3, 6, 30, tzinfo=timezone.utc)]
+
+    nonexistent = pa.array(["2024-03-10 02:30"])
+    with pytest.raises(Exception, match="does not exist"):
+        ac.strptime(nonexistent, fmt, "s", tz)
+    out = pa.array(ac.strptime(nonexistent, fmt, "s", tz, nonexistent="latest"))
+    assert out.to_pylist() == [datetime(2024, 3, 10, 7, tzinfo=timezone.utc)]
+
+
+def test_strptime_error():
+    ca = pa.chunked_array([["2024-01-01", "2024-01-02"], ["2024-01-03", "bad"]])
+    with pytest.raises(Exception, match="'bad' at row 3"):
+        pa.chunked_array(ac.strptime(ca, "%Y-%m-%d", "s"))
+
+    out = pa.chunked_array(ac.strptime(ca, "%Y-%m-%d", "s", error="null"))
+    assert out.to_pylist() == [
+        datetime(2024, 1, 1),
+        datetime(2024, 1, 2),
+        datetime(2024, 1, 3),
+        None,
+    ]
+
+
+def test_strftime():
+    arr = pa.array([datetime(2024, 1, 15, 12, 30), None], pa.timestamp("us"))
+    out = pa.array(ac.strftime(arr, "%Y-%m-%dT%H:%M"))
+    assert out.type == pa.utf8()
+    assert out.to_pylist() == ["2024-01-15T12:30", None]
+
+    arr = pa.array([date(2024, 1, 15)], pa.date32())
+    assert pa.array(ac.strftime(arr, "%d/%m/%Y")).to_pylist() == ["15/01/2024"]
+
+    arr = pa.array(
+        [datetime(2024, 1, 15, 12, tzinfo=timezone.utc)],
+        pa.timestamp("s", tz="Asia/Tokyo"),
+    )
+    out = pa.array(ac.strftime(arr, "%H:%M %z"))
+    assert out.to_pylist() == ["21:00 +0900"]
+
+    with pytest.raises(ValueError):
+        ac.strftime(arr, "%Q")
+
+
+def test_add_interval_month_end():
+    arr = pa.array([date(2024, 1, 31), date(2023, 3, 31), None], pa.date32())
+    months = pa.array([(1, 0, 0), (-1, 1, 0), (1, 0, 0)], pa.month_day_nano_interval())
+    out = pa.array(ac.add_interval(arr, months))
+    assert out.type == pa.date32()
+    assert out.to_pylist() == [date(2024, 2, 29), date(2023, 3, 1), None]
+
+    hour = pa.array([(0, 0, 3_600_000_000_000)] * 3, pa.month_day_nano_interval())
+    with pytest.raises(Exception, match="whole date"):
+        ac.add_interval(arr, hour)
+
+
+def test_add_interval_dst():
+    # Clocks in New York went forward from 02:00 EST to 03:00 EDT on 2024-03-10
+    arr = pa.array(
+        [datetime(2024, 3, 9, 17, tzinfo=timezone.utc)],
+        pa.timestamp("s", tz="America/New_York"),
+    )
+    day = pa.array([(0, 1, 0)], pa.month_day_nano_interval())
+    out = pa.array(ac.add_interval(arr, day))
+    assert out.type == pa.timestamp("s", tz="America/New_York")
+    # Noon local time is kept, now in EDT (UTC-4)
+    assert out.to_pylist() == [datetime(2024, 3, 10, 16, tzinfo=timezone.utc)]
+
+    # A duration is elapsed time
+    out = pa.array(ac.add_interval(arr, pa.array([86400], pa.duration("s"))))
+    assert out.to_pylist() == [datetime(2024, 3, 10, 17, tzinfo=timezone.utc)]
+
+    arr = pa.array(
+        [datetime(2024, 3, 9, 7, 30, tzinfo=timezone.utc)],
+        pa.timestamp("s", tz="America/New_York"),
+    )
+    with pytest.raises(Exception, match="does not exist"):
+        ac.add_interval(arr, day)
+    out = pa.array(ac.add_interval(arr, day, nonexistent="latest"))
+    assert out.to_pylist() == [datetime(2024, 3, 10, 7, tzinfo=timezone.utc)]
+
+
+def test_calendar_between():
+    start = pa.array([date(2023, 12, 31), date(2024, 1, 31), date(2024, 5, 1)])
+    end = pa.array([date(2024, 1, 1), date(2024, 2, 1), date(2024, 1, 1)])
+    assert pa.array(ac.years_between(start, end)).to_pylist() == [1, 0, 0]
+    assert pa.array(ac.quarters_between(start, end)).to_pylist() == [1, 0, -1]
+    assert pa.array(ac.months_between(start, end)).to_pylist() == [1, 1, -4]
+    out = pa.array(ac.days_between(start, end))
+    assert out.type == pa.int64()
+    assert out.to_pylist() == [1, 1, -121]
+
+
+def test_between_dst():
+    # Midnight on 2024-03-10 and 2024-03-11 in New York, 23 hours apart
+    start = pa.array(
+        [datetime(2024, 3, 10, 5, tzinfo=timezone.utc)],
+        pa.timestamp("us", tz="America/New_York"),
+    )
+    end = pa.array(
+        [datetime(2024, 3, 11, 4, tzinfo=timezone.utc)],
+        pa.timestamp("us", tz="America/New_York"),
+    )
+    assert pa.array(ac.days_between(start, end)).to_pylist() == [1]
+    assert pa.array(ac.hours_between(start, end)).to_pylist() == [23]
+    assert pa.array(ac.minutes_between(start, end)).to_pylist() == [23 * 60]
+
+    with pytest.raises(ValueError):
+        ac.days_between(start, pa.array([datetime(2024, 3, 11)]))
@@ -0,0 +1,58 @@
+import arro3.compute as ac
+import pyarrow as pa
+from arro3.core import Array, ChunkedArray, DataType, Table
+
+
+def test_top_k_array():
+    arr = Array([3, None, 5, 1, 4], DataType.int64())
+    assert pa.array(ac.top_k(arr, 2)).to_pylist() == [5, 4]
+    assert pa.array(ac.bottom_k(arr, 2)).to_pylist() == [1, 3]
+    assert pa.array(ac.bottom_k(arr, 10)).to_pylist() == [1, 3, 4, 5, None]
+    assert pa.array(ac.top_k(arr, 0)).to_pylist() == []
+
+
+def test_top_k_chunked():
+    ca = ChunkedArray(
+        [
+            Array([1, 9, 2], DataType.int64()),
+            Array([8, 3], DataType.int64()),
+            Array([7], DataType.int64()),
+        ]
+    )
+    assert pa.array(ac.top_k(ca, 3)).to_pylist() == [9, 8, 7]
+
+
+def test_top_k_larger_than_input():
+    arr = Array([3, None, 5, 1, 4], DataType.int64())
+    assert pa.array(ac.top_k(arr, 2**64 - 1)).to_pylist() == [5, 4, 3, 1, None]
+    ca = ChunkedArray(
+        [Array([1, 9, 2], DataType.int64()), Array([8, 3], DataType.int64())]
+    )
+    out = ac.bottom_k(ca, 2**63)
+    assert pa.array(out).to_pylist() == [1, 2, 3, 8, 9]
+
+
+def test_top_k_stream_table():
+    table = pa.table({"a": [1, 5, 3, 5, 2, 4], "b": list("uvwxyz")})
+    reader = pa.RecordBatchReader.from_batches(table.schema, table.to_batches(2))
+    out = ac.top_k(reader, 3, ["a"])
+    assert isinstance(out, Table)
+    assert pa.table(out).to_pydict() == {"a": [5, 5, 4], "b": ["v", "x", "z"]}
+
+    out = ac.top_k(table, 2, [("a", "ascending"), ("b", "descending")])
+    assert pa.table(out).to_pydict() == {"a": [1, 2], "b": ["u", "y"]}
+
+
+def test_arg_min_max():
+    arr = Array([3, None, 1, 5, 1, 5], DataType.int64())
+    assert ac.arg_min(arr).as_py() == 2
+    assert ac.arg_max(arr).as_py() == 3
+    assert ac.arg_min(Array([None], DataType.int64())).as_py() is None
+    assert ac.arg_min(pa.nulls(3)).as_py() is None
+    assert ac.arg_max(pa.nulls(3)).as_py() is None
+
+    ca = ChunkedArray(
+        [Array(["b", "a"], DataType.string()), Array(["c", "a"], DataType.string())]
+    )
+    assert ac.arg_min(ca).as_py() == 1
+    assert ac.arg_max(ca).as_py() == 2